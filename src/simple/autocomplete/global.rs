@@ -1,12 +1,8 @@
 #![allow(unused_mut)]
 
-use crate::simple::internal::string_keywords::SplitContext;
-use crate::simple::search_index::SearchIndex;
+use crate::simple::{search_index::SearchIndex, FuzzyStrategy};
 use kstring::KString;
-use std::hash::Hash;
-
-#[cfg(any(feature = "strsim", feature = "eddie", feature = "rapidfuzz"))]
-use crate::simple::internal::fuzzers::Fuzzy;
+use std::{collections::BTreeSet, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -105,12 +101,9 @@ impl<K: Hash + Ord> SearchIndex<K> {
     pub(crate) fn autocomplete_global(
         &self,
         maximum_autocomplete_options: usize,
-        string: &str,
+        mut keywords: Vec<KString>,
+        strategy: Option<&FuzzyStrategy>,
     ) -> Vec<String> {
-        // Split search `String` into keywords according to the `SearchIndex`
-        // settings. Force "use entire string as a keyword" option off:
-        let mut keywords: Vec<KString> = self.string_keywords(string, &SplitContext::Searching);
-
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!("autocompleting: {:?}", keywords);
@@ -120,18 +113,12 @@ impl<K: Hash + Ord> SearchIndex<K> {
         keywords.pop().map_or_else(Vec::new, |last_keyword| {
             // Autocomplete the last keyword:
             let mut autocompletions: Vec<&KString> = self
-                .b_tree_map
-                // Get matching keywords starting with (partial) keyword string:
-                .range(KString::from_ref(&last_keyword)..)
-                // `range` returns a key-value pair. We're autocompleting the
-                // key (keyword), so discard the value (record key):
+                // Get matching keywords starting with (partial) keyword string,
+                // followed by keywords containing it if infix matching is on:
+                .partial_keywords(&last_keyword)
+                // We're autocompleting the key (keyword), so discard the value
+                // (record key):
                 .map(|(key, _value)| key)
-                // We did not specify an end bound for our `range` function (see
-                // above.) `range` will return _every_ keyword greater than the
-                // supplied keyword. The below `take_while` will effectively
-                // break iteration when we reach a keyword that does not start
-                // with our supplied (partial) keyword.
-                .take_while(|autocompletion| autocompletion.starts_with(&*last_keyword))
                 // If the index's keyword matches the user's keyword, don't
                 // return it as a result. For example, if the user's keyword was
                 // "new" (as in New York), do not return "new" as an
@@ -151,34 +138,23 @@ impl<K: Hash + Ord> SearchIndex<K> {
                 // Collect all keyword autocompletions into a `Vec`:
                 .collect();
 
-            // If `rapidfuzz` fuzzy matching enabled, this will examine the
-            // search results. If the search results are empty, it will use
-            // fuzzy matching to find closest alternatives:
-            #[cfg(feature = "rapidfuzz")]
-            crate::simple::internal::fuzzers::Rapidfuzz::autocomplete_global(
-                self,
-                &keywords,
-                &mut autocompletions,
-                &last_keyword,
-            );
-
-            // If `strsim` fuzzy matching enabled, this will examine the
-            // search results. If the search results are empty, it will use
-            // fuzzy matching to find closest alternatives:
-            #[cfg(feature = "strsim")]
-            crate::simple::internal::fuzzers::Strsim::autocomplete_global(
-                self,
-                &keywords,
-                &mut autocompletions,
-                &last_keyword,
-            );
+            // If phonetic matching is enabled and there are no autocompletions,
+            // use the keywords that sound like the last keyword:
+            if autocompletions.is_empty() {
+                autocompletions = self
+                    .phonetic_keywords(&keywords, &BTreeSet::new(), &last_keyword)
+                    .into_iter()
+                    .take(maximum_autocomplete_options)
+                    .map(|(keyword, _keys)| keyword)
+                    .collect();
+            } // if
 
-            // If `eddie` fuzzy matching enabled, this will examine the
-            // search results. If the search results are empty, it will use
-            // fuzzy matching to find closest alternatives:
-            #[cfg(feature = "eddie")]
-            crate::simple::internal::fuzzers::Eddie::autocomplete_global(
-                self,
+            // If fuzzy matching is enabled, this will examine the search results.
+            // If the search results are empty, it will use fuzzy matching to find
+            // closest alternatives:
+            #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+            self.fuzzy_autocomplete_global(
+                strategy,
                 &keywords,
                 &mut autocompletions,
                 &last_keyword,
@@ -202,8 +178,8 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     // Add current autocompleted last keyword to end of list:
                     keywords.push(autocompletion.clone());
                     // Join all keywords together into a single `String` using a
-                    // space delimiter:
-                    keywords.join(" ").trim_end().to_string()
+                    // space delimiter, or the tokenizer's:
+                    self.join_keywords(&keywords).trim_end().to_string()
                 })
                 // Collect all string autocompletions into a `Vec`:
                 .collect()
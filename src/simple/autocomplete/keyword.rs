@@ -1,7 +1,6 @@
+use crate::simple::FuzzyStrategy;
 use kstring::KStringBase;
-
-#[cfg(any(feature = "strsim", feature = "eddie", feature = "rapidfuzz"))]
-use crate::simple::internal::fuzzers::Fuzzy;
+use std::collections::BTreeSet;
 
 // -----------------------------------------------------------------------------
 
@@ -87,6 +86,7 @@ impl<K: std::hash::Hash + Ord> crate::simple::SearchIndex<K> {
         &self,
         maximum_autocomplete_options: usize,
         keyword: &str,
+        strategy: Option<&FuzzyStrategy>,
     ) -> Vec<&str> {
         // If the search index is set to be case insensitive, normalize the
         // keyword to lower-case:
@@ -97,20 +97,13 @@ impl<K: std::hash::Hash + Ord> crate::simple::SearchIndex<K> {
         tracing::debug!("autocompleting: {:?}", keyword);
 
         // Attempt to get matching keywords from `BTreeMap`:
-        #[allow(unused_mut, reason = "compiler complains when fuzzy matching is off")]
         let mut autocomplete_options: Vec<&str> = self
-            .b_tree_map
-            // Get matching keywords starting with (partial) keyword string:
-            .range(kstring::KString::from_ref(&keyword)..)
-            // `range` returns a key-value pair. We're autocompleting the
-            // key (keyword), so discard the value (record key):
+            // Get matching keywords starting with (partial) keyword string,
+            // followed by keywords containing it if infix matching is on:
+            .partial_keywords(&keyword)
+            // We're autocompleting the key (keyword), so discard the value
+            // (record key):
             .map(|(key, _value)| key)
-            // We did not specify an end bound for our `range` function (see
-            // above.) `range` will return _every_ keyword greater than the
-            // supplied keyword. The below `take_while` will effectively break
-            // iteration when we reach a keyword that does not start with our
-            // supplied (partial) keyword.
-            .take_while(|autocompletion| autocompletion.starts_with(keyword.as_ref()))
             // If the index's keyword matches the user's keyword, don't return
             // it as a result. For example, if the user's keyword was "new" (as
             // in New York), do not return "new" as an auto-completed keyword:
@@ -122,35 +115,22 @@ impl<K: std::hash::Hash + Ord> crate::simple::SearchIndex<K> {
             // Collect all keyword autocompletions into a `Vec`:
             .collect();
 
-        // If `rapidfuzz` fuzzy matching enabled, this will examine the
-        // autocompletion results. If the autocompletion results are empty, it
-        // will use fuzzy matching to find closest possibilities:
-        #[cfg(feature = "rapidfuzz")]
-        crate::simple::internal::fuzzers::Rapidfuzz::autocomplete_keyword(
-            self,
-            &mut autocomplete_options,
-            &keyword,
-        );
-
-        // If `strsim` fuzzy matching enabled, this will examine the
-        // autocompletion results. If the autocompletion results are empty, it
-        // will use fuzzy matching to find closest possibilities:
-        #[cfg(feature = "strsim")]
-        crate::simple::internal::fuzzers::Strsim::autocomplete_keyword(
-            self,
-            &mut autocomplete_options,
-            &keyword,
-        );
+        // If phonetic matching is enabled and there are no autocompletion
+        // results, use the keywords that sound like the user's keyword:
+        if autocomplete_options.is_empty() {
+            autocomplete_options = self
+                .phonetic_keywords(&[], &BTreeSet::new(), &keyword)
+                .into_iter()
+                .take(maximum_autocomplete_options)
+                .map(|(keyword, _keys)| keyword.as_str())
+                .collect();
+        } // if
 
-        // If `eddie` fuzzy matching enabled, this will examine the
-        // autocompletion results. If the autocompletion results are empty, it
-        // will use fuzzy matching to find closest possibilities:
-        #[cfg(feature = "eddie")]
-        crate::simple::internal::fuzzers::Eddie::autocomplete_keyword(
-            self,
-            &mut autocomplete_options,
-            &keyword,
-        );
+        // If fuzzy matching is enabled, this will examine the autocompletion
+        // results. If the autocompletion results are empty, it will use fuzzy
+        // matching to find closest possibilities:
+        #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+        self.fuzzy_autocomplete_keyword(strategy, &mut autocomplete_options, &keyword);
 
         autocomplete_options
     }
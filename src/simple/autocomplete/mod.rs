@@ -2,8 +2,9 @@ mod context;
 mod global;
 mod keyword;
 
-use crate::simple::AutocompleteType;
-use std::hash::Hash;
+use crate::simple::{internal::string_keywords::SplitContext, AutocompleteType, FuzzyStrategy};
+use kstring::KString;
+use std::{collections::BTreeSet, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -85,19 +86,12 @@ impl<K: Hash + Ord> crate::simple::SearchIndex<K>{
     /// ```
     #[tracing::instrument(level = "trace", name = "autocomplete", skip(self))]
     pub fn autocomplete(&self, string: &str) -> Vec<String> {
-        let autocomplete_options: Vec<String> = match &self.autocomplete_type {
-            AutocompleteType::Context => {
-                self.autocomplete_context(self.maximum_autocomplete_options, string)
-            }
-            AutocompleteType::Global => {
-                self.autocomplete_global(self.maximum_autocomplete_options, string)
-            }
-            AutocompleteType::Keyword => self
-                .autocomplete_keyword(self.maximum_autocomplete_options, string)
-                .into_iter()
-                .map(std::string::ToString::to_string)
-                .collect(),
-        };
+        let autocomplete_options: Vec<String> = self.synonym_autocomplete(
+            &self.autocomplete_type,
+            self.autocomplete_strategy.as_ref(),
+            self.maximum_autocomplete_options,
+            string,
+        ); // synonym_autocomplete
 
         // For debug builds:
         #[cfg(debug_assertions)]
@@ -198,19 +192,12 @@ impl<K: Hash + Ord> crate::simple::SearchIndex<K>{
         autocomplete_type: &AutocompleteType,
         string: &str,
     ) -> Vec<String> {
-        let autocomplete_options: Vec<String> = match autocomplete_type {
-            AutocompleteType::Context => {
-                self.autocomplete_context(self.maximum_autocomplete_options, string)
-            }
-            AutocompleteType::Global => {
-                self.autocomplete_global(self.maximum_autocomplete_options, string)
-            }
-            AutocompleteType::Keyword => self
-                .autocomplete_keyword(self.maximum_autocomplete_options, string)
-                .into_iter()
-                .map(std::string::ToString::to_string)
-                .collect(),
-        };
+        let autocomplete_options: Vec<String> = self.synonym_autocomplete(
+            autocomplete_type,
+            self.autocomplete_strategy.as_ref(),
+            self.maximum_autocomplete_options,
+            string,
+        ); // synonym_autocomplete
 
         // For debug builds:
         #[cfg(debug_assertions)]
@@ -316,19 +303,72 @@ impl<K: Hash + Ord> crate::simple::SearchIndex<K>{
         maximum_autocomplete_options: &usize,
         string: &str,
     ) -> Vec<String> {
-        let autocomplete_options: Vec<String> = match autocomplete_type {
-            AutocompleteType::Context => {
-                self.autocomplete_context(*maximum_autocomplete_options, string)
-            }
-            AutocompleteType::Global => {
-                self.autocomplete_global(*maximum_autocomplete_options, string)
-            }
-            AutocompleteType::Keyword => self
-                .autocomplete_keyword(*maximum_autocomplete_options, string)
-                .into_iter()
-                .map(std::string::ToString::to_string)
-                .collect(),
-        };
+        let autocomplete_options: Vec<String> = self.synonym_autocomplete(
+            autocomplete_type,
+            self.autocomplete_strategy.as_ref(),
+            *maximum_autocomplete_options,
+            string,
+        ); // synonym_autocomplete
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!(
+            "{} autocomplete options for \"{}\".",
+            autocomplete_options.len(),
+            string,
+        );
+
+        autocomplete_options
+    }
+
+    // -------------------------------------------------------------------------
+    //
+    /// This autocomplete method works like `autocomplete_type`, but keywords
+    /// are fuzzy matched with the given strategy rather than the index's
+    /// `autocomplete_strategy`. This allows a different string similarity
+    /// metric (or combination of metrics) to be used for a single call. See
+    /// [`FuzzyStrategy`] for more information.
+    ///
+    /// [`FuzzyStrategy`]: struct.FuzzyStrategy.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rapidfuzz")] {
+    /// # use indicium::simple::{AutocompleteType, FuzzyStrategy, RapidfuzzMetric, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"William Rufus");
+    ///
+    /// let autocomplete_options = search_index.autocomplete_with_strategy(
+    ///     &AutocompleteType::Keyword,
+    ///     &FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler),
+    ///     "harry",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     autocomplete_options,
+    ///     vec!["harold".to_string(), "harold godwinson".to_string()]
+    /// );
+    /// # }
+    /// ```
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[tracing::instrument(level = "trace", name = "autocomplete", skip(self))]
+    pub fn autocomplete_with_strategy(
+        &self,
+        autocomplete_type: &AutocompleteType,
+        strategy: &FuzzyStrategy,
+        string: &str,
+    ) -> Vec<String> {
+        let autocomplete_options: Vec<String> = self.synonym_autocomplete(
+            autocomplete_type,
+            Some(strategy),
+            self.maximum_autocomplete_options,
+            string,
+        ); // synonym_autocomplete
 
         // For debug builds:
         #[cfg(debug_assertions)]
@@ -340,4 +380,82 @@ impl<K: Hash + Ord> crate::simple::SearchIndex<K>{
 
         autocomplete_options
     }
-}
\ No newline at end of file
+
+    // -------------------------------------------------------------------------
+    //
+    /// Dispatches the search string to the autocomplete method for the given
+    /// `AutocompleteType`. If the search string contains phrases that have
+    /// synonyms, each variation of the search string is also autocompleted.
+    /// Options for the search string itself are returned first, followed by
+    /// the options for each variation.
+    fn synonym_autocomplete(
+        &self,
+        autocomplete_type: &AutocompleteType,
+        strategy: Option<&FuzzyStrategy>,
+        maximum_autocomplete_options: usize,
+        string: &str,
+    ) -> Vec<String> {
+        // The `Keyword` autocomplete type uses the entire string as its
+        // keyword:
+        let keywords: Vec<KString> = if *autocomplete_type == AutocompleteType::Keyword {
+            vec![KString::from(self.normalize(string).into_owned())]
+        } else {
+            self.string_keywords(string, &SplitContext::Searching)
+        }; // if
+
+        let variants: Vec<Vec<KString>> = self.synonym_variants(&keywords);
+
+        let mut autocomplete_options: Vec<String> =
+            self.keyword_autocomplete_type(autocomplete_type, strategy, maximum_autocomplete_options, keywords);
+
+        // If there are no synonyms for the search string, we're done:
+        if variants.is_empty() {
+            return autocomplete_options;
+        } // if
+
+        let mut found: BTreeSet<String> = autocomplete_options.iter().cloned().collect();
+
+        for keywords in variants {
+            if autocomplete_options.len() >= maximum_autocomplete_options {
+                break;
+            } // if
+
+            // Only keep the options that weren't found by an earlier
+            // variation:
+            autocomplete_options.extend(
+                self.keyword_autocomplete_type(autocomplete_type, strategy, maximum_autocomplete_options, keywords)
+                    .into_iter()
+                    .filter(|option| found.insert(option.clone()))
+            ); // extend
+        } // for
+
+        autocomplete_options.truncate(maximum_autocomplete_options);
+        autocomplete_options
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Autocompletes keywords that have already been split from the search
+    /// string, using the autocomplete method for the given `AutocompleteType`.
+    fn keyword_autocomplete_type(
+        &self,
+        autocomplete_type: &AutocompleteType,
+        strategy: Option<&FuzzyStrategy>,
+        maximum_autocomplete_options: usize,
+        keywords: Vec<KString>,
+    ) -> Vec<String> {
+        match autocomplete_type {
+            AutocompleteType::Context => {
+                self.autocomplete_context(maximum_autocomplete_options, keywords, strategy)
+            }
+            AutocompleteType::Global => {
+                self.autocomplete_global(maximum_autocomplete_options, keywords, strategy)
+            }
+            AutocompleteType::Keyword => self
+                .autocomplete_keyword(maximum_autocomplete_options, &keywords.join(" "), strategy)
+                .into_iter()
+                .map(std::string::ToString::to_string)
+                .collect(),
+        } // match
+    } // fn
+}
@@ -1,6 +1,25 @@
-use crate::simple::{AutocompleteType, EddieMetric, RapidfuzzMetric, SearchIndex, SearchType, StrsimMetric};
+// Conditionally select hash set type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashSet<T> = std::collections::HashSet<T, gxhash::GxBuildHasher>;
+
+#[cfg(feature = "ahash")]
+use ahash::HashSet;
+
+#[cfg(feature = "rustc-hash")]
+use rustc_hash::FxHashSet as HashSet;
+
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash"), not(feature = "rustc-hash")))]
+use std::collections::HashSet;
+
+// Static dependencies:
+use crate::simple::{AutocompleteType, CaseFolding, EddieMetric, FuzzyCandidates, FuzzyStrategy, Language, NormalizationForm, PhoneticAlgorithm, RapidfuzzMetric, ScoringType, SearchIndex, SearchType, StrsimMetric, Synonyms, TokenFilter, Tokenizer};
+use crate::simple::internal::{deletions::DeletionIndex, phonetics::PhoneticIndex, positions::PositionalIndex, scoring::ScoringStatistics, trigrams::TrigramIndex};
 use kstring::KString;
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+#[cfg(feature = "rayon")]
+use crate::simple::Indexable;
 
 // -----------------------------------------------------------------------------
 //
@@ -11,25 +30,51 @@ use std::collections::{BTreeMap, BTreeSet};
 ///
 /// If you're in a hurry, you can instantiate your search index with
 /// `SearchIndex::default()` instead.
+#[allow(clippy::struct_excessive_bools)] // The settings are independent
 pub struct SearchIndexBuilder<K> {
     b_tree_map: BTreeMap<KString, BTreeSet<K>>,
+    field_b_tree_maps: BTreeMap<KString, BTreeMap<KString, BTreeSet<K>>>,
+    scoring_statistics: ScoringStatistics<K>,
+    positional_index: PositionalIndex<K>,
     search_type: SearchType,
     autocomplete_type: AutocompleteType,
+    scoring_type: ScoringType,
+    bm25_k1: f64,
+    bm25_b: f64,
+    index_positions: bool,
+    infix_matching: bool,
+    pattern_matching: bool,
+    maximum_pattern_expansions: usize,
     eddie_metric: Option<EddieMetric>,
     rapidfuzz_metric: Option<RapidfuzzMetric>,
     strsim_metric: Option<StrsimMetric>,
     fuzzy_length: usize,
     fuzzy_minimum_score: f64,
+    fuzzy_candidates: FuzzyCandidates,
+    fuzzy_distance: usize,
+    fuzzy_fallback: bool,
+    maximum_fuzzy_expansions: usize,
+    autocomplete_strategy: Option<FuzzyStrategy>,
+    search_strategy: Option<FuzzyStrategy>,
+    phonetic_matching: Option<PhoneticAlgorithm>,
     split_pattern: Option<Vec<char>>,
+    tokenizer: Option<Arc<dyn Tokenizer>>,
+    token_filters: Vec<Arc<dyn TokenFilter>>,
     case_sensitive: bool,
+    normalization_form: NormalizationForm,
+    case_folding: CaseFolding,
+    remove_combining_marks: bool,
     minimum_keyword_length: usize,
     maximum_keyword_length: usize,
     maximum_string_length: Option<usize>,
-    exclude_keywords: Option<Vec<KString>>,
+    exclude_keywords: Option<HashSet<KString>>,
     maximum_autocomplete_options: usize,
     maximum_search_results: usize,
     maximum_keys_per_keyword: usize,
     dump_keyword: Option<KString>,
+    field_weights: Option<Vec<(KString, f64)>>,
+    synonyms: BTreeMap<KString, Vec<Vec<KString>>>,
+    synonym_entries: Synonyms,
     empty_b_tree_set: BTreeSet<K>,
 } // SearchIndexBuilder
 
@@ -40,15 +85,37 @@ impl<K: Clone + Ord> From<SearchIndex<K>> for SearchIndexBuilder<K> {
     fn from(search_index: SearchIndex<K>) -> Self {
         Self {
             b_tree_map: search_index.b_tree_map,
+            field_b_tree_maps: search_index.field_b_tree_maps,
+            scoring_statistics: search_index.scoring_statistics,
+            positional_index: search_index.positional_index,
             search_type: search_index.search_type,
             autocomplete_type: search_index.autocomplete_type,
+            scoring_type: search_index.scoring_type,
+            bm25_k1: search_index.bm25_k1,
+            bm25_b: search_index.bm25_b,
+            index_positions: search_index.index_positions,
+            infix_matching: search_index.infix_matching,
+            pattern_matching: search_index.pattern_matching,
+            maximum_pattern_expansions: search_index.maximum_pattern_expansions,
             eddie_metric: search_index.eddie_metric,
             rapidfuzz_metric: search_index.rapidfuzz_metric,
             strsim_metric: search_index.strsim_metric,
             fuzzy_length: search_index.fuzzy_length,
             fuzzy_minimum_score: search_index.fuzzy_minimum_score,
+            fuzzy_candidates: search_index.fuzzy_candidates,
+            fuzzy_distance: search_index.fuzzy_distance,
+            fuzzy_fallback: search_index.fuzzy_fallback,
+            maximum_fuzzy_expansions: search_index.maximum_fuzzy_expansions,
+            autocomplete_strategy: search_index.autocomplete_strategy,
+            search_strategy: search_index.search_strategy,
+            phonetic_matching: search_index.phonetic_matching,
             split_pattern: search_index.split_pattern,
+            tokenizer: search_index.tokenizer,
+            token_filters: search_index.token_filters,
             case_sensitive: search_index.case_sensitive,
+            normalization_form: search_index.normalization_form,
+            case_folding: search_index.case_folding,
+            remove_combining_marks: search_index.remove_combining_marks,
             minimum_keyword_length: search_index.minimum_keyword_length,
             maximum_keyword_length: search_index.maximum_keyword_length,
             maximum_string_length: search_index.maximum_string_length,
@@ -57,6 +124,10 @@ impl<K: Clone + Ord> From<SearchIndex<K>> for SearchIndexBuilder<K> {
             maximum_search_results: search_index.maximum_search_results,
             maximum_keys_per_keyword: search_index.maximum_keys_per_keyword,
             dump_keyword: search_index.dump_keyword,
+            field_weights: search_index.field_weights
+                .map(|field_weights| field_weights.into_iter().collect()),
+            synonyms: search_index.synonyms,
+            synonym_entries: Synonyms::new(),
             empty_b_tree_set: search_index.empty_b_tree_set,
         } // SearchIndexBuilder
     } // fn
@@ -67,29 +138,107 @@ impl<K: Clone + Ord> From<SearchIndex<K>> for SearchIndexBuilder<K> {
 impl<K: Clone + Ord> From<SearchIndexBuilder<K>> for SearchIndex<K> {
     /// Convert to `SearchIndex<K>` struct from `SearchIndexBuilder<K>` struct.
     fn from(search_index: SearchIndexBuilder<K>) -> Self {
-        Self {
+        let synonym_entries = search_index.synonym_entries;
+        let field_weights = search_index.field_weights;
+        let exclude_keywords = search_index.exclude_keywords;
+
+        let mut search_index = Self {
             b_tree_map: search_index.b_tree_map,
+            field_b_tree_maps: search_index.field_b_tree_maps,
+            scoring_statistics: search_index.scoring_statistics,
+            positional_index: search_index.positional_index,
+            trigram_index: TrigramIndex::default(),
+            deletion_index: DeletionIndex::default(),
+            phonetic_index: PhoneticIndex::default(),
             search_type: search_index.search_type,
             autocomplete_type: search_index.autocomplete_type,
+            scoring_type: search_index.scoring_type,
+            bm25_k1: search_index.bm25_k1,
+            bm25_b: search_index.bm25_b,
+            index_positions: search_index.index_positions,
+            infix_matching: search_index.infix_matching,
+            pattern_matching: search_index.pattern_matching,
+            maximum_pattern_expansions: search_index.maximum_pattern_expansions,
             eddie_metric: search_index.eddie_metric,
             rapidfuzz_metric: search_index.rapidfuzz_metric,
             strsim_metric: search_index.strsim_metric,
             fuzzy_length: search_index.fuzzy_length,
             fuzzy_minimum_score: search_index.fuzzy_minimum_score,
+            fuzzy_candidates: search_index.fuzzy_candidates,
+            fuzzy_distance: search_index.fuzzy_distance,
+            fuzzy_fallback: search_index.fuzzy_fallback,
+            maximum_fuzzy_expansions: search_index.maximum_fuzzy_expansions,
+            autocomplete_strategy: search_index.autocomplete_strategy,
+            search_strategy: search_index.search_strategy,
+            phonetic_matching: search_index.phonetic_matching,
             split_pattern: search_index.split_pattern,
+            tokenizer: search_index.tokenizer,
+            token_filters: search_index.token_filters,
             case_sensitive: search_index.case_sensitive,
+            normalization_form: search_index.normalization_form,
+            case_folding: search_index.case_folding,
+            remove_combining_marks: search_index.remove_combining_marks,
             minimum_keyword_length: search_index.minimum_keyword_length,
             maximum_keyword_length: search_index.maximum_keyword_length,
             maximum_string_length: search_index.maximum_string_length,
-            exclude_keywords: search_index.exclude_keywords,
+            exclude_keywords: None,
             maximum_autocomplete_options: search_index.maximum_autocomplete_options,
             maximum_search_results: search_index.maximum_search_results,
             maximum_keys_per_keyword: search_index.maximum_keys_per_keyword,
             dump_keyword: search_index.dump_keyword,
+            field_weights: None,
+            synonyms: search_index.synonyms,
             empty_b_tree_set: search_index.empty_b_tree_set,
             #[cfg(feature = "icu_normalizer")]
             icu_normalizer: icu_normalizer::ComposingNormalizer::new_nfkc(),
-        } // SearchIndexBuilder
+        }; // SearchIndex
+
+        // Excluded keywords are normalized the same way keywords are, so that
+        // they still match when the normalization form, case folding, or
+        // combining mark removal changes how a keyword is written:
+        search_index.exclude_keywords = exclude_keywords.map(|exclude_keywords| exclude_keywords
+            .iter()
+            .map(|keyword| KString::from(search_index.normalize(keyword).into_owned()))
+            .collect()
+        ); // map
+
+        // Synonyms are split into keywords and normalized with the search
+        // index's settings, so they can only be added once it's built:
+        search_index.add_synonyms(&synonym_entries);
+
+        // Field names are normalized the same way they are for indexing, so
+        // that they can be looked up directly when searching:
+        search_index.field_weights = field_weights.map(|field_weights| field_weights
+            .into_iter()
+            .map(|(field, weight)| (KString::from_ref(&search_index.normalize(&field)), weight))
+            .collect()
+        ); // map
+
+        // The trigram index is built from the keywords that are already in
+        // the search index, in case infix matching has just been turned on:
+        if search_index.infix_matching {
+            search_index.trigram_index = TrigramIndex::from_keywords(search_index.b_tree_map.keys());
+        } // if
+
+        // The same goes for the deletion index, in case deletion candidates
+        // have just been turned on or the fuzzy distance has changed:
+        if search_index.fuzzy_candidates == FuzzyCandidates::Deletions {
+            search_index.deletion_index = DeletionIndex::from_keywords(
+                search_index.b_tree_map.keys(),
+                search_index.fuzzy_distance,
+            ); // from_keywords
+        } // if
+
+        // And for the phonetic index, in case phonetic matching has just been
+        // turned on or its algorithm has changed:
+        if let Some(algorithm) = &search_index.phonetic_matching {
+            search_index.phonetic_index = PhoneticIndex::from_keywords(
+                search_index.b_tree_map.keys(),
+                algorithm,
+            ); // from_keywords
+        } // if
+
+        search_index
     } // fn
 } // impl
 
@@ -206,6 +355,9 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
     ///   is OK (or even desirable) if the search index is small, however, this
     ///   will be crippling slow on very large search indicies.
     ///
+    /// The fuzzy length isn't used when `fuzzy_candidates` is set to
+    /// `FuzzyCandidates::Deletions`.
+    ///
     /// **Default:** `2` characters
     #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
     #[must_use]
@@ -234,6 +386,197 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// How the keywords that a user's keyword is fuzzy matched against are
+    /// chosen. By default, only keywords that start with the first
+    /// `fuzzy_length` characters are compared, so `fhone` will never find
+    /// `phone`. With `FuzzyCandidates::Deletions`, every keyword within
+    /// `fuzzy_distance` edits is compared, wherever the typos are. See
+    /// [`FuzzyCandidates`] for more information.
+    ///
+    /// Either way, the candidates are scored with the selected string
+    /// similarity metric and must meet the `fuzzy_minimum_score`.
+    ///
+    /// **Default:** `FuzzyCandidates::Prefix`
+    ///
+    /// [`FuzzyCandidates`]: enum.FuzzyCandidates.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{FuzzyCandidates, SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::Live)
+    ///     .fuzzy_candidates(FuzzyCandidates::Deletions)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Phone charger");
+    /// search_index.insert(&1, &"Laptop charger");
+    ///
+    /// assert_eq!(search_index.search("fhone"), vec![&0]);
+    /// ```
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[must_use]
+    pub const fn fuzzy_candidates(mut self, fuzzy_candidates: FuzzyCandidates) -> Self {
+        self.fuzzy_candidates = fuzzy_candidates;
+        self
+    } // fn
+
+    /// The maximum number of edits (insertions, deletions, substitutions, or
+    /// transpositions) between a user's keyword and a fuzzy matched keyword,
+    /// for `FuzzyCandidates::Deletions`. Larger distances find more typos, but
+    /// the deletion index grows quickly with the distance.
+    ///
+    /// **Default:** `2`
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[must_use]
+    pub const fn fuzzy_distance(mut self, fuzzy_distance: usize) -> Self {
+        self.fuzzy_distance = fuzzy_distance;
+        self
+    } // fn
+
+    /// Normally, `And` searches return nothing if any keyword isn't in the
+    /// search index, `Or` searches ignore it, and `Live` searches only fuzzy
+    /// match the last (partial) keyword. If this is set, every keyword that
+    /// isn't in the search index falls back to its closest fuzzy matches, so
+    /// a typo anywhere in the search string is tolerated.
+    ///
+    /// A record matches a substituted keyword if it contains any of its fuzzy
+    /// matches. The number of substitutions in one search is limited by
+    /// `max_fuzzy_expansions`.
+    ///
+    /// **Default:** `false`
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::And)
+    ///     .fuzzy_fallback(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Silver thread");
+    /// search_index.insert(&1, &"Golden thread");
+    ///
+    /// assert_eq!(search_index.search("slver thread"), vec![&0]);
+    /// ```
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[must_use]
+    pub const fn fuzzy_fallback(mut self, fuzzy_fallback: bool) -> Self {
+        self.fuzzy_fallback = fuzzy_fallback;
+        self
+    } // fn
+
+    /// Maximum number of fuzzy matched keywords that may be substituted for
+    /// the keywords in one search, when `fuzzy_fallback` is set. Each missing
+    /// keyword takes its closest matches first, until the limit is reached.
+    /// Keywords after that are treated as if there was no fallback.
+    ///
+    /// **Default:** `10`
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[must_use]
+    pub const fn max_fuzzy_expansions(mut self, maximum_fuzzy_expansions: usize) -> Self {
+        self.maximum_fuzzy_expansions = maximum_fuzzy_expansions;
+        self
+    } // fn
+
+    /// The fuzzy matching strategy used for autocompletion. A strategy may
+    /// use a metric from any of the enabled string similarity crates, or a
+    /// weighted combination of several metrics. See [`FuzzyStrategy`] for
+    /// more information.
+    ///
+    /// If `None`, the `eddie_metric`, `rapidfuzz_metric` and `strsim_metric`
+    /// settings are used.
+    ///
+    /// **Default:** `None`
+    ///
+    /// [`FuzzyStrategy`]: struct.FuzzyStrategy.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rapidfuzz")] {
+    /// # use indicium::simple::{FuzzyStrategy, RapidfuzzMetric, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .autocomplete_strategy(Some(FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler)))
+    ///     .search_strategy(Some(FuzzyStrategy::new(RapidfuzzMetric::Osa)))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"William Rufus");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("harry"),
+    ///     vec!["harold".to_string(), "harold godwinson".to_string()]
+    /// );
+    /// # }
+    /// ```
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[must_use]
+    pub fn autocomplete_strategy(mut self, autocomplete_strategy: Option<FuzzyStrategy>) -> Self {
+        self.autocomplete_strategy = autocomplete_strategy;
+        self
+    } // fn
+
+    /// The fuzzy matching strategy used for substituting search keywords:
+    /// the last keyword of a `Live` search, the keywords of a
+    /// `fuzzy_fallback`, and the fuzzy matches of a scored search. See
+    /// [`FuzzyStrategy`] for more information.
+    ///
+    /// If `None`, the `eddie_metric`, `rapidfuzz_metric` and `strsim_metric`
+    /// settings are used.
+    ///
+    /// **Default:** `None`
+    ///
+    /// [`FuzzyStrategy`]: struct.FuzzyStrategy.html
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[must_use]
+    pub fn search_strategy(mut self, search_strategy: Option<FuzzyStrategy>) -> Self {
+        self.search_strategy = search_strategy;
+        self
+    } // fn
+
+    /// Matches keywords that sound alike, using the given phonetic algorithm.
+    /// A keyword with no exact or prefix matches is substituted with the
+    /// keywords that sound like it, before any fuzzy matching is tried. This
+    /// applies to autocompletion, `Live` searches, scored searches, and the
+    /// keywords of `And` and `Or` searches. See [`PhoneticAlgorithm`] for
+    /// more information.
+    ///
+    /// Phonetic matching doesn't require a fuzzy matching feature. The phonetic
+    /// codes of every keyword are kept in memory while it's set.
+    ///
+    /// **Default:** `None`
+    ///
+    /// [`PhoneticAlgorithm`]: enum.PhoneticAlgorithm.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{PhoneticAlgorithm, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .phonetic_matching(Some(PhoneticAlgorithm::Metaphone))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Catherine Smith");
+    /// search_index.insert(&1, &"Margaret Jones");
+    ///
+    /// assert_eq!(search_index.search("kathryn smyth"), vec![&0]);
+    /// ```
+    #[must_use]
+    pub const fn phonetic_matching(mut self, phonetic_matching: Option<PhoneticAlgorithm>) -> Self {
+        self.phonetic_matching = phonetic_matching;
+        self
+    } // fn
+
     /// Characters used to split strings into keywords.
     ///
     /// **Default:** [ `tab`, `new line`, `carrier return`, `space`, `!`, `"`,
@@ -246,8 +589,41 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// Splits strings into keywords using a `Tokenizer`, rather than the
+    /// `split_pattern`. The same tokenizer is used for indexing records and
+    /// for splitting search strings. See the [`Tokenizer`] trait for the
+    /// built-in tokenizers.
+    ///
+    /// The tokenizer is given strings before they're normalized. Each token is
+    /// then normalized, and filtered by keyword length and the exclusion list
+    /// as usual.
+    ///
+    /// **Default:** none. The `split_pattern` is used.
+    #[must_use]
+    pub fn tokenizer(mut self, tokenizer: impl Tokenizer + 'static) -> Self {
+        self.tokenizer = Some(Arc::new(tokenizer));
+        self
+    } // fn
+
+    /// Adds a `TokenFilter` to the end of the token filter chain. Token
+    /// filters run in the order they're added, on every keyword after strings
+    /// are split and normalized, both when indexing records and when splitting
+    /// search strings. See the [`TokenFilter`] trait for the built-in token
+    /// filters. Whole strings that are used as keywords (see `max_string_len`)
+    /// aren't filtered.
+    ///
+    /// Token filters should be added before the search index is populated.
+    ///
+    /// **Default:** none
+    #[must_use]
+    pub fn token_filter(mut self, token_filter: impl TokenFilter + 'static) -> Self {
+        self.token_filters.push(Arc::new(token_filter));
+        self
+    } // fn
+
     /// Indicates whether the search index is case sensitive or not. If set to
     /// false (case insensitive), all keywords will be normalized to lower case.
+    /// See also: `case_folding`.
     ///
     /// **Default:** `false`
     #[must_use]
@@ -256,6 +632,72 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// The Unicode normalization form that is applied to keywords, when
+    /// indexing records and when searching. See [`NormalizationForm`] for
+    /// more information.
+    ///
+    /// Normalization requires the `icu_normalizer` or `unicode-normalization`
+    /// feature. This setting has no effect if neither feature is enabled.
+    ///
+    /// The normalization form should be set before the search index is
+    /// populated.
+    ///
+    /// **Default:** `NormalizationForm::Nfkc`
+    ///
+    /// [`NormalizationForm`]: enum.NormalizationForm.html
+    #[must_use]
+    pub const fn normalization_form(mut self, normalization_form: NormalizationForm) -> Self {
+        self.normalization_form = normalization_form;
+        self
+    } // fn
+
+    /// How keywords are converted to a common case in a case insensitive
+    /// search index. Full case folding allows `ß` to match `ss`, and the
+    /// `Turkic` setting handles the Turkish dotted and dotless `i`. See
+    /// [`CaseFolding`] for more information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{CaseFolding, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .case_folding(CaseFolding::Full)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Straße");
+    ///
+    /// assert_eq!(search_index.search("STRASSE"), vec![&0]);
+    /// ```
+    ///
+    /// The case folding should be set before the search index is populated.
+    ///
+    /// **Default:** `CaseFolding::Lowercase`
+    ///
+    /// [`CaseFolding`]: enum.CaseFolding.html
+    #[must_use]
+    pub const fn case_folding(mut self, case_folding: CaseFolding) -> Self {
+        self.case_folding = case_folding;
+        self
+    } // fn
+
+    /// Removes combining marks, such as accents, from keywords when indexing
+    /// records and when searching. This allows `café` to match `cafe`.
+    ///
+    /// Precomposed characters (i.e. `é`) are decomposed before their marks
+    /// are removed, which requires the `icu_normalizer` or
+    /// `unicode-normalization` feature.
+    ///
+    /// This setting should be set before the search index is populated.
+    ///
+    /// **Default:** `false`
+    #[must_use]
+    pub const fn remove_combining_marks(mut self, remove_combining_marks: bool) -> Self {
+        self.remove_combining_marks = remove_combining_marks;
+        self
+    } // fn
+
     /// Minimum keyword length (in chars or codepoints) to be indexed. If the
     /// keyword is shorter the keyword will not be indexed.
     ///
@@ -292,6 +734,9 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
     /// prepositions from your search index. For example, words such as `and`,
     /// `as`, `a`, `as`, `at`, etc. See also: the [`profile`] utility method.
     ///
+    /// Excluded keywords are normalized like any other keyword when the search
+    /// index is built.
+    ///
     /// [`profile`]: struct.SearchIndex.html#method.profile
     #[must_use]
     pub fn exclude_keywords(mut self, exclude_keywords: Option<Vec<String>>) -> Self {
@@ -300,6 +745,65 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// Replaces the list of excluded keywords with the stop words for the
+    /// given languages. Languages are selected by their ISO 639-1 language
+    /// code (i.e. `"de"` for German, or `"pt"` for Portuguese.) See
+    /// [`Language`] for the supported languages.
+    ///
+    /// Unsupported language codes are skipped, with a warning in debug
+    /// builds. If none of the language codes are supported, no keywords are
+    /// excluded at all.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::And)
+    ///     .stop_words(["de", "pt"])
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Der Hund und die Katze");
+    /// search_index.insert(&1, &"O cão e o gato");
+    ///
+    /// assert_eq!(search_index.search("der"), Vec::<&usize>::new());
+    /// assert_eq!(search_index.search("o gato"), vec![&1]);
+    /// ```
+    ///
+    /// **Default:** A short list of English, French, and Spanish stop words,
+    /// which is smaller than these languages' sets.
+    ///
+    /// [`Language`]: enum.Language.html
+    #[must_use]
+    pub fn stop_words<S: AsRef<str>>(mut self, language_codes: impl IntoIterator<Item = S>) -> Self {
+        let stop_words: HashSet<KString> = language_codes
+            .into_iter()
+            .filter_map(|code| {
+                let language = Language::from_code(code.as_ref());
+
+                // For debug builds:
+                #[cfg(debug_assertions)]
+                if language.is_none() {
+                    tracing::warn!(
+                        "Unsupported stop word language code `{}` was skipped.",
+                        code.as_ref(),
+                    ); // warn!
+                } // if
+
+                language
+            }) // filter_map
+            .flat_map(Language::stop_words)
+            .map(|stop_word| KString::from_static(stop_word))
+            .collect();
+
+        // If none of the language codes were supported, there's nothing to
+        // exclude:
+        self.exclude_keywords = (!stop_words.is_empty()).then_some(stop_words);
+        self
+    } // fn
+
     /// Maximum number of auto-complete options to return. This setting can be
     /// overidden by some function arguments.
     ///
@@ -343,9 +847,254 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// Relevance weights for named fields, as returned by
+    /// `Indexable::fields`. `Or` and `Live` searches rank records by the sum
+    /// of their keyword weights. A keyword's weight is the highest weight of
+    /// the fields it was found in. Fields that aren't listed, and strings
+    /// without a field name, have a weight of `1.0`.
+    ///
+    /// For example, to rank title matches above body matches:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .field_weights(Some(vec![
+    ///         ("title".to_string(), 3.0),
+    ///         ("body".to_string(), 1.0),
+    ///     ]))
+    ///     .build();
+    /// ```
+    ///
+    /// **Default:** `None`
+    #[must_use]
+    pub fn field_weights(mut self, field_weights: Option<Vec<(String, f64)>>) -> Self {
+        self.field_weights = field_weights.map(|vec| vec
+            .into_iter()
+            .map(|(field, weight)| (field.into(), weight))
+            .collect()
+        );
+        self
+    } // fn
+
+    /// Scoring type. Used to rank search results for relevance. See
+    /// [`ScoringType`] for more information. The `Bm25` and `TfIdf` scoring
+    /// types gather statistics as records are inserted, so the scoring type
+    /// should be set before the search index is populated.
+    ///
+    /// **Default:** `ScoringType::HitCount`
+    ///
+    /// [`ScoringType`]: enum.ScoringType.html
+    #[must_use]
+    pub const fn scoring_type(mut self, scoring_type: ScoringType) -> Self {
+        self.scoring_type = scoring_type;
+        self
+    } // fn
+
+    /// The `k1` and `b` parameters for the `Bm25` scoring type. `k1` controls
+    /// how quickly repeated keywords in a record stop adding to its score.
+    /// `b` controls how much longer records are penalized, from `0.0` (no
+    /// penalty) to `1.0` (full penalty).
+    ///
+    /// **Default:** `1.2` and `0.75`
+    #[must_use]
+    pub const fn bm25_parameters(mut self, k1: f64, b: f64) -> Self {
+        self.bm25_k1 = k1;
+        self.bm25_b = b;
+        self
+    } // fn
+
+    /// Index keyword positions. When turned on, the position of every keyword
+    /// in a record is recorded as it's inserted. This allows quoted phrases
+    /// in `search_query` to match keywords in order (i.e. `"new york"` won't
+    /// match `york new`), and enables `NEAR/n` proximity searches.
+    ///
+    /// Positions use more memory, and should be turned on before the search
+    /// index is populated.
+    ///
+    /// **Default:** `false`
+    #[must_use]
+    pub const fn index_positions(mut self, index_positions: bool) -> Self {
+        self.index_positions = index_positions;
+        self
+    } // fn
+
+    /// Infix and suffix matching. When turned on, `Live` searches and every
+    /// autocomplete type also match keywords that contain the last (partial)
+    /// keyword anywhere, rather than only keywords that start with it. For
+    /// example, `phone` will find `smartphone` and `headphones`.
+    ///
+    /// Keywords that start with the partial keyword are returned first. A
+    /// partial keyword that's shorter than three chars is only matched by
+    /// prefix. A trigram index of every keyword is kept for this, which uses
+    /// more memory. It's built when the search index is built, if it already
+    /// contains records.
+    ///
+    /// **Default:** `false`
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::Live)
+    ///     .autocomplete_type(AutocompleteType::Keyword)
+    ///     .infix_matching(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Smartphone");
+    /// search_index.insert(&1, &"Headphones");
+    /// search_index.insert(&2, &"Phonebook");
+    ///
+    /// assert_eq!(search_index.search("phone"), vec![&0, &1, &2]);
+    /// assert_eq!(
+    ///     search_index.autocomplete("phone"),
+    ///     vec!["phonebook", "headphones", "smartphone"],
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn infix_matching(mut self, infix_matching: bool) -> Self {
+        self.infix_matching = infix_matching;
+        self
+    } // fn
+
+    /// Wildcard and regular expression terms. When turned on, `And` and `Or`
+    /// searches treat a term that contains a `?` or `*` as a wildcard
+    /// pattern, where `?` matches any one character and `*` matches any
+    /// number of characters. For example, `colo?r` matches `colour`, `colo*r`
+    /// matches `color` and `colour`, and `inv-2024-*` matches every keyword
+    /// that begins with `inv-2024-`. With the `regex` feature, a term between slashes is a
+    /// regular expression, i.e. `/^ab\d{3}$/`.
+    ///
+    /// Patterns are matched against whole keywords, and the records for every
+    /// matching keyword are returned. Only keywords that start with a
+    /// pattern's literal beginning are checked. Patterns aren't split into
+    /// keywords, so a regular expression can't contain spaces. `search_query`
+    /// always recognizes patterns, regardless of this setting.
+    ///
+    /// **Default:** `false`
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::Or)
+    ///     .pattern_matching(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Red color");
+    /// search_index.insert(&1, &"Blue colour");
+    /// search_index.insert(&2, &"Red collar");
+    ///
+    /// assert_eq!(search_index.search("colo?r"), vec![&1]);
+    /// assert_eq!(search_index.search("colo*r"), vec![&0, &1]);
+    /// ```
+    #[must_use]
+    pub const fn pattern_matching(mut self, pattern_matching: bool) -> Self {
+        self.pattern_matching = pattern_matching;
+        self
+    } // fn
+
+    /// Maximum number of keywords that a single wildcard or regular
+    /// expression term may match. Matching keywords are taken in
+    /// lexicographic order, and the rest are ignored. This keeps a broad
+    /// pattern like `a*` from visiting every record in the search index.
+    ///
+    /// **Default:** `100`
+    #[must_use]
+    pub const fn max_pattern_expansions(mut self, maximum_pattern_expansions: usize) -> Self {
+        self.maximum_pattern_expansions = maximum_pattern_expansions;
+        self
+    } // fn
+
+    /// Synonyms that are added to search strings, so that searches for a
+    /// phrase will also find records that contain its synonyms. Records that
+    /// match the search string itself are returned first. Synonyms apply to
+    /// every `SearchType` and `AutocompleteType`. See [`Synonyms`] for more
+    /// information.
+    ///
+    /// Synonyms are split into keywords using the search index's other
+    /// settings, in the same way as search strings.
+    ///
+    /// **Default:** none
+    #[must_use]
+    pub fn synonyms(mut self, synonyms: Synonyms) -> Self {
+        self.synonym_entries.entries.extend(synonyms.entries);
+        self
+    } // fn
+
     /// Build `SearchIndex` from the settings given to the `SearchIndexBuilder`.
     #[must_use]
     pub fn build(self) -> SearchIndex<K> {
         SearchIndex::from(self)
     } // fn
+
+    /// Build `SearchIndex` from the settings given to the `SearchIndexBuilder`,
+    /// and load it with `(key, value)` pairs. This is much faster than
+    /// calling `insert` for each record. See the `Extend` implementation for
+    /// `SearchIndex` for more information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::And)
+    ///     .build_from([
+    ///         (0, "Harold Godwinson"),
+    ///         (1, "Edgar Ætheling"),
+    ///     ]);
+    ///
+    /// assert_eq!(search_index.search("harold godwinson"), vec![&0]);
+    /// ```
+    #[must_use]
+    pub fn build_from<I>(self, records: impl IntoIterator<Item = I>) -> SearchIndex<K>
+    where
+        SearchIndex<K>: Extend<I>,
+    {
+        let mut search_index = self.build();
+        search_index.extend(records);
+        search_index
+    } // fn
+
+    /// Build `SearchIndex` from the settings given to the `SearchIndexBuilder`,
+    /// and load it with `(key, value)` pairs, splitting the records into
+    /// keywords in parallel. See `SearchIndex::par_extend` for more
+    /// information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::And)
+    ///     .build_from_par([
+    ///         (0, "Harold Godwinson"),
+    ///         (1, "Edgar Ætheling"),
+    ///     ]);
+    ///
+    /// assert_eq!(search_index.search("harold godwinson"), vec![&0]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn build_from_par<I: Indexable + Send>(
+        self,
+        records: impl IntoIterator<Item = (K, I)>,
+    ) -> SearchIndex<K>
+    where
+        K: Send + Sync,
+    {
+        let mut search_index = self.build();
+        search_index.par_extend(records);
+        search_index
+    } // fn
 } // impl
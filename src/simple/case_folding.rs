@@ -0,0 +1,28 @@
+// -----------------------------------------------------------------------------
+//
+/// How keywords are converted to a common case in a case insensitive search
+/// index. If the search index has been set to be case sensitive, this setting
+/// has no effect.
+///
+/// For more information on the setting the case folding in a `SearchIndex`
+/// type see: [`SearchIndexBuilder`].
+///
+/// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum CaseFolding {
+    /// Keywords are converted to lower case.
+    Lowercase = 0,
+
+    /// Full Unicode [case folding](https://www.unicode.org/reports/tr44/#CaseFolding.txt).
+    /// This goes further than lower case, so that more keywords match
+    /// regardless of case. For example, `ß` matches `ss`, and the Greek final
+    /// sigma `ς` matches `σ`.
+    Full = 1,
+
+    /// Full Unicode case folding, with the Turkish and Azerbaijani rules for
+    /// the letter `i`. The dotted and dotless forms are kept apart, so `I`
+    /// matches `ı` and `İ` matches `i`.
+    Turkic = 2,
+}
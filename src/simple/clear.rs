@@ -2,5 +2,11 @@ impl<K: Ord> crate::simple::SearchIndex<K> {
     /// Clears the search index, removing all elements.
     pub fn clear(&mut self) {
         self.b_tree_map.clear();
+        self.field_b_tree_maps.clear();
+        self.scoring_statistics = crate::simple::internal::scoring::ScoringStatistics::default();
+        self.positional_index = crate::simple::internal::positions::PositionalIndex::default();
+        self.trigram_index = crate::simple::internal::trigrams::TrigramIndex::default();
+        self.deletion_index = crate::simple::internal::deletions::DeletionIndex::default();
+        self.phonetic_index = crate::simple::internal::phonetics::PhoneticIndex::default();
     } // fn
 } // impl
\ No newline at end of file
@@ -0,0 +1,254 @@
+use crate::simple::{Indexable, SearchIndex};
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+// -----------------------------------------------------------------------------
+//
+/// A record's strings, copied out of an `Indexable` value so that the
+/// operation can be applied to the other copy of the search index later.
+struct Record(Vec<(String, String)>);
+
+impl Indexable for Record {
+    fn strings(&self) -> Vec<String> {
+        self.0.iter().map(|(_field, string)| string.clone()).collect()
+    } // fn
+
+    fn fields(&self) -> Vec<(String, String)> {
+        self.0.clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A write operation that has been applied to one copy of the search index,
+/// and may still need to be applied to the other copy.
+enum Operation<K> {
+    Insert(K, Record),
+    Remove(K, Record),
+    Clear,
+} // Operation
+
+impl<K: Clone + Ord> Operation<K> {
+    /// Applies the operation to a copy of the search index.
+    fn apply(&self, search_index: &mut SearchIndex<K>) {
+        match self {
+            Self::Insert(key, record) => search_index.insert(key, record),
+            Self::Remove(key, record) => search_index.remove(key, record),
+            Self::Clear => search_index.clear(),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The writer's side of the `ConcurrentSearchIndex`.
+struct Writer<K: Ord> {
+    /// The copy of the search index that readers aren't using.
+    standby: Arc<SearchIndex<K>>,
+
+    /// Operations that have been published, but haven't been applied to the
+    /// standby copy yet.
+    catch_up: Vec<Operation<K>>,
+} // Writer
+
+// -----------------------------------------------------------------------------
+//
+/// A search index that can be shared between threads, where writes don't
+/// block searches. This is meant for read-heavy services, such as a web
+/// server that shares one search index between all of its requests.
+///
+/// Two copies of the search index are kept. Readers use the published copy,
+/// while a writer updates the standby copy. The writer then publishes the
+/// standby copy by swapping a pointer, and applies the same change to the
+/// previously published copy once readers are finished with it. This means
+/// that:
+///
+/// * Searches never wait for a write to be applied. A read only takes a lock
+///   long enough to clone an `Arc`.
+///
+/// * Each read sees a consistent version of the search index, for as long as
+///   the returned `Arc` is held.
+///
+/// * Writes are applied twice, and the search index uses twice the memory. If
+///   a reader is still holding the previous version when the next write
+///   arrives, that version is cloned rather than waited for. Readers should
+///   release their `Arc` once they're done.
+///
+/// * Writers are serialized with each other.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{ConcurrentSearchIndex, SearchIndex};
+/// # use pretty_assertions::assert_eq;
+/// # use std::sync::Arc;
+/// #
+/// let search_index: Arc<ConcurrentSearchIndex<usize>> =
+///     Arc::new(ConcurrentSearchIndex::new(SearchIndex::default()));
+///
+/// let writer = Arc::clone(&search_index);
+/// std::thread::spawn(move || {
+///     writer.insert(&0, &"Harold Godwinson");
+///     writer.insert(&1, &"Edgar Ætheling");
+/// }).join().unwrap();
+///
+/// // `read` returns the current version of the search index. Every
+/// // `SearchIndex` method can be used on it:
+/// let current = search_index.read();
+/// assert_eq!(current.search("edgar"), vec![&1]);
+/// assert_eq!(current.autocomplete("edg"), vec!["edgar".to_string(), "edgar ætheling".to_string()]);
+///
+/// // Or, for convenience, search and receive owned keys:
+/// assert_eq!(search_index.search("harold"), vec![0]);
+/// ```
+pub struct ConcurrentSearchIndex<K: Ord> {
+    /// The copy of the search index that readers use.
+    published: RwLock<Arc<SearchIndex<K>>>,
+
+    /// The copy of the search index that the writer updates.
+    writer: Mutex<Writer<K>>,
+} // ConcurrentSearchIndex
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> ConcurrentSearchIndex<K> {
+    /// Makes a search index available for concurrent use. The search index is
+    /// cloned once, for the standby copy.
+    #[must_use]
+    pub fn new(search_index: SearchIndex<K>) -> Self {
+        Self {
+            writer: Mutex::new(Writer {
+                standby: Arc::new(search_index.clone()),
+                catch_up: Vec::new(),
+            }), // Writer
+            published: RwLock::new(Arc::new(search_index)),
+        } // ConcurrentSearchIndex
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the current version of the search index. Writes that happen
+    /// after this call are not visible through the returned `Arc`, so a
+    /// sequence of searches on it will see consistent results.
+    #[must_use]
+    pub fn read(&self) -> Arc<SearchIndex<K>> {
+        // The lock is only held by writers for as long as it takes to swap a
+        // pointer. A poisoned lock still holds a valid pointer:
+        Arc::clone(&self.published.read().unwrap_or_else(PoisonError::into_inner))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a record into the search index, and publishes the change. See
+    /// `SearchIndex::insert` for more information.
+    pub fn insert(&self, key: &K, value: &dyn Indexable) {
+        self.write(Operation::Insert(key.clone(), Record(value.fields())));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes a record from the search index, and publishes the change. See
+    /// `SearchIndex::remove` for more information.
+    pub fn remove(&self, key: &K, value: &dyn Indexable) {
+        self.write(Operation::Remove(key.clone(), Record(value.fields())));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces a record in the search index, and publishes the change.
+    /// Readers will either see the old record or the new record, never
+    /// neither. See `SearchIndex::replace` for more information.
+    pub fn replace(&self, key: &K, before: &dyn Indexable, after: &dyn Indexable) {
+        self.write_all(vec![
+            Operation::Remove(key.clone(), Record(before.fields())),
+            Operation::Insert(key.clone(), Record(after.fields())),
+        ]); // write_all
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes every record from the search index, and publishes the change.
+    /// The search index's settings are kept.
+    pub fn clear(&self) {
+        self.write(Operation::Clear);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Applies a write operation and publishes it.
+    fn write(&self, operation: Operation<K>) {
+        self.write_all(vec![operation]);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Applies write operations to the standby copy of the search index,
+    /// publishes it, and keeps the operations so that they can be applied to
+    /// the previously published copy on the next write.
+    fn write_all(&self, operations: Vec<Operation<K>>) {
+        let mut guard = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        let writer = &mut *guard;
+
+        // Get exclusive access to the standby copy. If a reader is still
+        // holding it from before the last write, it's cloned instead:
+        let standby = Arc::make_mut(&mut writer.standby);
+
+        // Bring the standby copy up to date with the published copy, and then
+        // apply the new operations:
+        writer.catch_up
+            .iter()
+            .chain(&operations)
+            .for_each(|operation| operation.apply(standby));
+
+        // Publish the standby copy. The previously published copy becomes the
+        // standby copy, and will need to catch up on these operations:
+        std::mem::swap(
+            &mut *self.published.write().unwrap_or_else(PoisonError::into_inner),
+            &mut writer.standby,
+        ); // swap
+
+        writer.catch_up = operations;
+        drop(guard);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Hash + Ord> ConcurrentSearchIndex<K> {
+    /// Searches the current version of the search index. This is the same as
+    /// `read().search(string)`, but the keys are cloned so that the search
+    /// index doesn't need to be held by the caller.
+    #[must_use]
+    pub fn search(&self, string: &str) -> Vec<K> {
+        self.read()
+            .search(string)
+            .into_iter()
+            .cloned()
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns autocompletion options from the current version of the search
+    /// index. This is the same as `read().autocomplete(string)`.
+    #[must_use]
+    pub fn autocomplete(&self, string: &str) -> Vec<String> {
+        self.read().autocomplete(string)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> From<SearchIndex<K>> for ConcurrentSearchIndex<K> {
+    fn from(search_index: SearchIndex<K>) -> Self {
+        Self::new(search_index)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> Default for ConcurrentSearchIndex<K> {
+    fn default() -> Self {
+        Self::new(SearchIndex::default())
+    } // fn
+} // impl
@@ -0,0 +1,362 @@
+use crate::simple::{
+    indexable::Indexable,
+    internal::indexable_keywords::RecordKeywords,
+    search_index::SearchIndex,
+    FuzzyCandidates,
+    ScoringType,
+};
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+// -----------------------------------------------------------------------------
+//
+/// A `(keyword, record number)` pair. Record numbers are the position of the
+/// record in the batch being loaded.
+type Posting = (KString, usize);
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns all keywords for the given `Indexable` record, much like
+    /// `indexable_keywords`. The keywords are normalized and include the
+    /// `dump_keyword`, so that they can be added to the search index as-is.
+    fn bulk_keywords(&self, value: &dyn Indexable) -> RecordKeywords {
+        let mut record_keywords = self.indexable_keywords(value);
+
+        let mut keywords: Vec<KString> = record_keywords
+            .keywords
+            .drain()
+            .map(|keyword| self.normalize(&keyword).to_string().into())
+            .collect();
+
+        // If `dump_keyword` feature is turned on, ensure that all records are
+        // attached to this special keyword:
+        if let Some(dump_keyword) = &self.dump_keyword {
+            keywords.push(self.normalize(dump_keyword).to_string().into());
+        } // if
+
+        record_keywords.keywords.extend(keywords);
+        record_keywords
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a batch of records that have already been split into keywords
+    /// by `bulk_keywords`. The result is the same as inserting each record in
+    /// order with `insert`.
+    ///
+    /// Rather than inserting one key at a time, the postings for every record
+    /// are gathered and sorted by keyword. Each keyword's keys are then added
+    /// in one step, and any new keywords are bulk-loaded into the `BTreeMap`.
+    #[tracing::instrument(level = "trace", name = "search index bulk insert", skip(self, records))]
+    fn insert_record_keywords(&mut self, records: Vec<(K, RecordKeywords)>) {
+        let mut postings: Vec<Posting> = Vec::new();
+        let mut field_postings: BTreeMap<KString, Vec<Posting>> = BTreeMap::new();
+        let mut keys: Vec<K> = Vec::with_capacity(records.len());
+
+        // Keys that appear more than once in the batch. Their keywords are
+        // tracked, so that the scoring statistics don't count a keyword twice
+        // for the same record:
+        let repeated_keys: BTreeSet<K> = if self.scoring_type == ScoringType::HitCount {
+            BTreeSet::new()
+        } else {
+            let mut seen: BTreeSet<&K> = BTreeSet::new();
+            records
+                .iter()
+                .filter(|(key, _record_keywords)| !seen.insert(key))
+                .map(|(key, _record_keywords)| key.clone())
+                .collect()
+        }; // if
+        let mut repeated_keywords: BTreeMap<K, BTreeSet<KString>> = BTreeMap::new();
+
+        for (record, (key, record_keywords)) in records.into_iter().enumerate() {
+            postings.extend(
+                record_keywords
+                    .keywords
+                    .into_iter()
+                    .map(|keyword| (keyword, record))
+            ); // extend
+
+            for (field, keywords) in record_keywords.fields {
+                field_postings
+                    .entry(field)
+                    .or_default()
+                    .extend(keywords.into_iter().map(|keyword| (keyword, record)));
+            } // for
+
+            // Record the statistics used for relevance scoring, if required:
+            if self.scoring_type != ScoringType::HitCount {
+                let b_tree_map = &self.b_tree_map;
+                let batch_keywords = repeated_keywords.get(&key);
+                self.scoring_statistics.insert(
+                    &key,
+                    record_keywords.frequencies.iter(),
+                    |keyword| b_tree_map.get(keyword).is_some_and(|keys| keys.contains(&key))
+                        || batch_keywords.is_some_and(|keywords| keywords.contains(keyword)),
+                ); // insert
+
+                if repeated_keys.contains(&key) {
+                    repeated_keywords
+                        .entry(key.clone())
+                        .or_default()
+                        .extend(record_keywords.frequencies.keys().cloned());
+                } // if
+            } // if
+
+            // Record the keyword positions used for phrase and proximity
+            // searches, if required:
+            if self.index_positions {
+                self.positional_index.insert(None, &key, record_keywords.positions.iter());
+                for (field, positions) in &record_keywords.field_positions {
+                    self.positional_index.insert(Some(field), &key, positions.iter());
+                } // for
+            } // if
+
+            keys.push(key);
+        } // for
+
+        // Note that the `dump_keyword` does not observe the keys per keyword
+        // limit:
+        let new_keywords = insert_postings(
+            &mut self.b_tree_map,
+            postings,
+            &keys,
+            self.maximum_keys_per_keyword,
+            self.dump_keyword.as_ref(),
+        ); // insert_postings
+
+        // Add the new keywords to the indexes that are kept for infix, fuzzy,
+        // and phonetic matching, if required:
+        for keyword in &new_keywords {
+            if self.infix_matching {
+                self.trigram_index.insert(keyword);
+            } // if
+            if self.fuzzy_candidates == FuzzyCandidates::Deletions {
+                self.deletion_index.insert(keyword, self.fuzzy_distance);
+            } // if
+            if let Some(algorithm) = &self.phonetic_matching {
+                self.phonetic_index.insert(keyword, algorithm);
+            } // if
+        } // for
+
+        for (field, postings) in field_postings {
+            insert_postings(
+                self.field_b_tree_maps.entry(field).or_default(),
+                postings,
+                &keys,
+                self.maximum_keys_per_keyword,
+                None,
+            ); // insert_postings
+        } // for
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Adds postings to a keyword map, observing the keys per keyword limit the
+/// same way `insert` does: keys are taken in record order until a keyword's
+/// limit is reached. Returns the keywords that weren't in the map before.
+fn insert_postings<K: Clone + Ord>(
+    b_tree_map: &mut BTreeMap<KString, BTreeSet<K>>,
+    mut postings: Vec<Posting>,
+    keys: &[K],
+    maximum_keys_per_keyword: usize,
+    unlimited_keyword: Option<&KString>,
+) -> Vec<KString> {
+    // Group the postings by keyword. Within a keyword, postings are kept in
+    // record order:
+    #[cfg(feature = "rayon")]
+    postings.par_sort_unstable();
+    #[cfg(not(feature = "rayon"))]
+    postings.sort_unstable();
+
+    let mut new_keywords: Vec<(KString, BTreeSet<K>)> = Vec::new();
+
+    for group in postings.chunk_by(|(a, _), (b, _)| a == b) {
+        let keyword = &group[0].0;
+
+        let limit = if unlimited_keyword == Some(keyword) {
+            usize::MAX
+        } else {
+            maximum_keys_per_keyword
+        }; // if
+
+        let group_keys = group.iter().map(|(_keyword, record)| &keys[*record]);
+
+        // If the keyword is already in the search index, add to its keys.
+        // Otherwise, build its keys all at once:
+        if let Some(existing) = b_tree_map.get_mut(keyword) {
+            extend_keys(existing, group_keys, limit);
+        } else if group.len() <= limit {
+            new_keywords.push((keyword.clone(), group_keys.cloned().collect()));
+        } else {
+            let mut new_keys = BTreeSet::new();
+            extend_keys(&mut new_keys, group_keys, limit.max(1));
+            new_keywords.push((keyword.clone(), new_keys));
+        } // if
+    } // for
+
+    let keywords: Vec<KString> = new_keywords
+        .iter()
+        .map(|(keyword, _keys)| keyword.clone())
+        .collect();
+
+    // The new keywords are already sorted, so the `BTreeMap` can be built
+    // from them in one pass and merged into the search index:
+    let mut new_keywords: BTreeMap<KString, BTreeSet<K>> = new_keywords.into_iter().collect();
+    b_tree_map.append(&mut new_keywords);
+
+    keywords
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Adds keys to a keyword's keys, until the limit is reached.
+fn extend_keys<'k, K: Clone + Ord + 'k>(
+    keys: &mut BTreeSet<K>,
+    new_keys: impl ExactSizeIterator<Item = &'k K>,
+    limit: usize,
+) {
+    if keys.len() + new_keys.len() <= limit {
+        keys.extend(new_keys.cloned());
+    } else {
+        for key in new_keys {
+            if keys.len() >= limit {
+                // Display warning for debug builds.
+                #[cfg(debug_assertions)]
+                tracing::warn!(
+                    "Internal table limit of {} keys per keyword has been reached on insert. \
+                    Records were not attached to a keyword. \
+                    This will impact accuracy of results. \
+                    For this data set, consider using a more comprehensive search solution like MeiliSearch.",
+                    limit,
+                ); // warn!
+                break;
+            } // if
+            keys.insert(key.clone());
+        } // for
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Inserts `(key, value)` pairs into the search index. This is much faster
+/// than calling `insert` for each record, and gives the same result.
+///
+/// Each record is split into keywords first. The keywords are then sorted, and
+/// added to the search index in one pass. With the `rayon` feature, see
+/// `SearchIndex::par_extend` to split the records in parallel.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::SearchIndex;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+///
+/// search_index.extend([
+///     (0, "Harold Godwinson"),
+///     (1, "Edgar Ætheling"),
+///     (2, "William the Conqueror"),
+/// ]);
+///
+/// assert_eq!(search_index.search("william"), vec![&2]);
+/// ```
+impl<K: Clone + Ord, I: Indexable> Extend<(K, I)> for SearchIndex<K> {
+    fn extend<T: IntoIterator<Item = (K, I)>>(&mut self, records: T) {
+        let records: Vec<(K, RecordKeywords)> = records
+            .into_iter()
+            // Split each record into keywords:
+            .map(|(key, value)| {
+                let record_keywords = self.bulk_keywords(&value);
+                (key, record_keywords)
+            }) // map
+            .collect();
+
+        self.insert_record_keywords(records);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "rayon")]
+impl<K: Clone + Ord + Send + Sync> SearchIndex<K> {
+    /// Inserts `(key, value)` pairs into the search index, like `extend`, but
+    /// splits the records into keywords in parallel using `rayon`. The
+    /// keywords are then sorted, and added to the search index in one pass.
+    /// The result is the same as `extend`.
+    ///
+    /// This requires the keys and records to be sendable between threads,
+    /// which is why it's separate from the `Extend` implementation.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.par_extend([
+    ///     (0, "Harold Godwinson"),
+    ///     (1, "Edgar Ætheling"),
+    ///     (2, "William the Conqueror"),
+    /// ]);
+    ///
+    /// assert_eq!(search_index.search("william"), vec![&2]);
+    /// ```
+    pub fn par_extend<I: Indexable + Send>(&mut self, records: impl IntoIterator<Item = (K, I)>) {
+        let records: Vec<(K, I)> = records.into_iter().collect();
+
+        let search_index: &Self = self;
+        let records: Vec<(K, RecordKeywords)> = records
+            .into_par_iter()
+            // Split each record into keywords:
+            .map(|(key, value)| {
+                let record_keywords = search_index.bulk_keywords(&value);
+                (key, record_keywords)
+            }) // map
+            .collect();
+
+        self.insert_record_keywords(records);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Builds a search index with the default settings from `(key, value)` pairs.
+/// To use other settings, see `SearchIndexBuilder::build_from`.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::SearchIndex;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let search_index: SearchIndex<usize> = [
+///     "Harold Godwinson",
+///     "Edgar Ætheling",
+///     "William the Conqueror",
+/// ]
+///     .into_iter()
+///     .enumerate()
+///     .collect();
+///
+/// assert_eq!(search_index.search("edgar"), vec![&1]);
+/// ```
+impl<K: Clone + Ord, I> FromIterator<(K, I)> for SearchIndex<K>
+where
+    Self: Extend<(K, I)>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, I)>>(records: T) -> Self {
+        let mut search_index = Self::default();
+        search_index.extend(records);
+        search_index
+    } // fn
+} // impl
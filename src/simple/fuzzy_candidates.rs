@@ -0,0 +1,32 @@
+// -----------------------------------------------------------------------------
+//
+/// Indicium `simple` search provides two ways of choosing the keywords that a
+/// user's keyword is fuzzy matched against. The chosen keywords (or
+/// candidates) are then scored with the selected string similarity metric.
+///
+/// For more information on the setting the fuzzy candidates in a
+/// `SearchIndex` type see: [`SearchIndexBuilder`].
+///
+/// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum FuzzyCandidates {
+    /// Only keywords that start with the first `fuzzy_length` characters of
+    /// the user's keyword are candidates. This uses no extra memory, but a
+    /// typo in the first characters (i.e. `fhone` for `phone`) is never
+    /// found.
+    #[default]
+    Prefix = 0,
+
+    /// Keywords within `fuzzy_distance` edits (insertions, deletions,
+    /// substitutions, or transpositions) of the user's keyword are candidates,
+    /// wherever the typos are. They're found with a
+    /// [SymSpell](https://github.com/wolfgarbe/SymSpell)-style dictionary of
+    /// every keyword's deletions, so the search index isn't scanned. The
+    /// dictionary uses more memory, and is kept as records are inserted.
+    ///
+    /// Candidates are whole keywords, so a partial keyword is only matched
+    /// against keywords that are about the same length.
+    Deletions = 1,
+}
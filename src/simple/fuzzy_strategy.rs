@@ -0,0 +1,266 @@
+// Without any string similarity crate, `FuzzyMetric` has no variants, so a
+// `FuzzyStrategy` can't be made and its methods are never called:
+#![cfg_attr(
+    not(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim")),
+    allow(
+        unreachable_code,
+        unused_variables,
+        clippy::missing_const_for_fn,
+        clippy::uninhabited_references,
+    )
+)]
+
+use crate::simple::Similarity;
+use std::sync::Arc;
+
+#[cfg(feature = "eddie")]
+use crate::simple::EddieMetric;
+
+#[cfg(feature = "rapidfuzz")]
+use crate::simple::RapidfuzzMetric;
+
+#[cfg(feature = "strsim")]
+use crate::simple::StrsimMetric;
+
+// -----------------------------------------------------------------------------
+//
+/// Custom string similarity metrics and their weights.
+type CustomMetrics = Vec<(Arc<dyn Similarity>, f64)>;
+
+// -----------------------------------------------------------------------------
+//
+/// A string similarity metric from any of the enabled string similarity
+/// crates. Several crates may be enabled at the same time, and their metrics
+/// may be combined in a [`FuzzyStrategy`].
+///
+/// The variants depend on which string similarity features are enabled, so
+/// matches on this enum need a wildcard arm.
+///
+/// [`FuzzyStrategy`]: struct.FuzzyStrategy.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum FuzzyMetric {
+    /// A metric from the [eddie](https://crates.io/crates/eddie) crate.
+    #[cfg(feature = "eddie")]
+    Eddie(EddieMetric),
+
+    /// A metric from the [rapidfuzz](https://crates.io/crates/rapidfuzz)
+    /// crate.
+    #[cfg(feature = "rapidfuzz")]
+    Rapidfuzz(RapidfuzzMetric),
+
+    /// A metric from the [strsim](https://crates.io/crates/strsim) crate.
+    #[cfg(feature = "strsim")]
+    Strsim(StrsimMetric),
+} // FuzzyMetric
+
+// -----------------------------------------------------------------------------
+
+impl Similarity for FuzzyMetric {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        match *self {
+            #[cfg(feature = "eddie")]
+            Self::Eddie(ref metric) => metric.similarity(a, b),
+            #[cfg(feature = "rapidfuzz")]
+            Self::Rapidfuzz(ref metric) => metric.similarity(a, b),
+            #[cfg(feature = "strsim")]
+            Self::Strsim(ref metric) => metric.similarity(a, b),
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "eddie")]
+impl From<EddieMetric> for FuzzyMetric {
+    fn from(metric: EddieMetric) -> Self {
+        Self::Eddie(metric)
+    } // fn
+} // impl
+
+#[cfg(feature = "rapidfuzz")]
+impl From<RapidfuzzMetric> for FuzzyMetric {
+    fn from(metric: RapidfuzzMetric) -> Self {
+        Self::Rapidfuzz(metric)
+    } // fn
+} // impl
+
+#[cfg(feature = "strsim")]
+impl From<StrsimMetric> for FuzzyMetric {
+    fn from(metric: StrsimMetric) -> Self {
+        Self::Strsim(metric)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// How a user's keyword is fuzzy matched against the keywords in the search
+/// index: one string similarity metric, or a weighted combination of several.
+/// Custom metrics that implement the [`Similarity`] trait may be combined with
+/// the built-in metrics.
+///
+/// A strategy may be set for each fuzzy matching operation in the
+/// [`SearchIndexBuilder`] (i.e. `JaroWinkler` for autocompletion and `Osa` for
+/// search), or passed to a single call with
+/// [`SearchIndex::search_with_strategy`] or
+/// [`SearchIndex::autocomplete_with_strategy`]. If no strategy has been set,
+/// the `eddie_metric`, `rapidfuzz_metric` and `strsim_metric` settings are
+/// used.
+///
+/// The similarity of a combined strategy is the weighted average of its
+/// metrics' similarities, so it's still between `0.0` and `1.0` and the
+/// `fuzzy_minimum_score` setting applies as usual.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # #[cfg(feature = "rapidfuzz")] {
+/// # use indicium::simple::{FuzzyStrategy, RapidfuzzMetric, Similarity};
+/// #
+/// let strategy = FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler)
+///     .with(RapidfuzzMetric::Osa, 0.5);
+///
+/// assert!(strategy.similarity("phone", "phone") > 0.99);
+/// assert!(strategy.similarity("phone", "phnoe") > 0.7);
+/// # }
+/// ```
+///
+/// Custom metrics can't be serialized or saved in snapshots. Rather than load
+/// a strategy without them, deserializing a strategy that had custom metrics
+/// fails, and so does loading a snapshot of a search index that used one.
+///
+/// [`Similarity`]: trait.Similarity.html
+/// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
+/// [`SearchIndex::search_with_strategy`]: struct.SearchIndex.html#method.search_with_strategy
+/// [`SearchIndex::autocomplete_with_strategy`]: struct.SearchIndex.html#method.autocomplete_with_strategy
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FuzzyStrategy {
+    /// The built-in metrics and their weights.
+    pub(crate) metrics: Vec<(FuzzyMetric, f64)>,
+
+    /// The custom metrics and their weights. Only whether there are any is
+    /// serialized.
+    #[cfg_attr(feature = "serde", serde(
+        default,
+        serialize_with = "serialize_custom",
+        deserialize_with = "deserialize_custom",
+    ))]
+    pub(crate) custom: CustomMetrics,
+} // FuzzyStrategy
+
+// -----------------------------------------------------------------------------
+//
+/// Serializes whether the strategy has custom metrics.
+#[cfg(feature = "serde")]
+fn serialize_custom<S: serde::Serializer>(
+    custom: &[(Arc<dyn Similarity>, f64)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bool(!custom.is_empty())
+} // fn
+
+/// Refuses to deserialize a strategy that had custom metrics, since the
+/// strategy would be empty or would measure similarity differently without
+/// them.
+#[cfg(feature = "serde")]
+fn deserialize_custom<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<CustomMetrics, D::Error> {
+    if serde::Deserialize::deserialize(deserializer)? {
+        Err(serde::de::Error::custom(
+            "fuzzy strategy used custom similarity metrics, which can't be deserialized",
+        ))
+    } else {
+        Ok(Vec::new())
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl FuzzyStrategy {
+    /// Makes a strategy that uses a single string similarity metric.
+    #[must_use]
+    pub fn new(metric: impl Into<FuzzyMetric>) -> Self {
+        Self { metrics: vec![(metric.into(), 1.0)], custom: Vec::new() }
+    } // fn
+
+    /// Makes a strategy that uses a single custom string similarity metric.
+    #[must_use]
+    pub fn custom(metric: impl Similarity + 'static) -> Self {
+        Self { metrics: Vec::new(), custom: vec![(Arc::new(metric), 1.0)] }
+    } // fn
+
+    /// Adds another string similarity metric to the strategy. The first metric
+    /// has a weight of `1.0`, so a `weight` of `0.5` counts this metric half
+    /// as much as the first one. Metrics with a weight of zero or less are
+    /// ignored.
+    #[must_use]
+    pub fn with(mut self, metric: impl Into<FuzzyMetric>, weight: f64) -> Self {
+        self.metrics.push((metric.into(), weight));
+        self
+    } // fn
+
+    /// Adds a custom string similarity metric to the strategy. Weights work
+    /// the same way as for `with`.
+    #[must_use]
+    pub fn with_custom(mut self, metric: impl Similarity + 'static, weight: f64) -> Self {
+        self.custom.push((Arc::new(metric), weight));
+        self
+    } // fn
+
+    /// Returns the built-in metrics and their weights.
+    #[must_use]
+    pub fn metrics(&self) -> &[(FuzzyMetric, f64)] {
+        &self.metrics
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Similarity for FuzzyStrategy {
+    /// Calculates the weighted similarity of two strings. The score is between
+    /// `0.0` and `1.0` (inclusive), where `1.0` means the strings are the
+    /// same.
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        let built_in = self.metrics
+            .iter()
+            .map(|(metric, weight)| (metric as &dyn Similarity, *weight));
+
+        let custom = self.custom
+            .iter()
+            .map(|(metric, weight)| (metric.as_ref(), *weight));
+
+        let (score, weights) = built_in
+            .chain(custom)
+            .filter(|(_metric, weight)| *weight > 0.0)
+            .fold((0.0, 0.0), |(score, weights), (metric, weight)| (
+                metric.similarity(a, b).mul_add(weight, score),
+                weights + weight,
+            )); // fold
+
+        if weights > 0.0 { score / weights } else { 0.0 }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl PartialEq for FuzzyStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        self.metrics == other.metrics
+            && self.custom.len() == other.custom.len()
+            && self.custom.iter().zip(&other.custom).all(|((a, a_weight), (b, b_weight))| {
+                format!("{a:?}") == format!("{b:?}") && a_weight == b_weight
+            }) // all
+        // Note: custom metrics are compared by their `Debug` output, since
+        // trait objects can't be compared directly.
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<FuzzyMetric> for FuzzyStrategy {
+    fn from(metric: FuzzyMetric) -> Self {
+        Self::new(metric)
+    } // fn
+} // impl
@@ -52,11 +52,71 @@
 /// them from your `Indexable` trait. Then, if a user intends to search for
 /// cities in United States, your application could quietly prepend the user's
 /// query with `#US` to restrict the search to American cities.
+///
+/// #### Named Fields
+///
+/// By default, the search index doesn't know which field a keyword came from.
+/// If you'd like to restrict searches to a field (i.e. `title:dawn`) then
+/// the optional `fields` method may be implemented. It returns a
+/// `(field name, text)` pair for every field that is to be indexed:
+///
+/// ```rust
+/// # use indicium::simple::{Indexable, SearchIndex};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// struct MyStruct {
+///     title: String,
+///     body: String,
+/// }
+///
+/// impl Indexable for MyStruct {
+///     fn strings(&self) -> Vec<String> {
+///         vec![self.title.clone(), self.body.clone()]
+///     }
+///
+///     fn fields(&self) -> Vec<(String, String)> {
+///         vec![
+///             ("title".to_string(), self.title.clone()),
+///             ("body".to_string(), self.body.clone()),
+///         ]
+///     }
+/// }
+///
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+///
+/// search_index.insert(&0, &MyStruct {
+///     title: "Silver Dawn".to_string(),
+///     body: "A thread of light.".to_string(),
+/// });
+///
+/// search_index.insert(&1, &MyStruct {
+///     title: "Golden Thread".to_string(),
+///     body: "Just before dawn.".to_string(),
+/// });
+///
+/// assert_eq!(search_index.search("dawn"), vec![&0, &1]);
+/// assert_eq!(search_index.search("title:dawn"), vec![&0]);
+/// ```
 
 pub trait Indexable {
     /// Returns a string for every field for a record that is to be indexed by
     /// Indicium Search.
     fn strings(&self) -> Vec<String>;
+
+    /// Returns a `(field name, text)` pair for every field for a record that
+    /// is to be indexed by Indicium Search.
+    ///
+    /// The default implementation returns the `strings` with an empty field
+    /// name. Strings with an empty field name are indexed, but can't be
+    /// searched for by field. The search index reads records through this
+    /// method, so if it's overridden it should return every string that
+    /// `strings` would.
+    fn fields(&self) -> Vec<(String, String)> {
+        self.strings()
+            .into_iter()
+            .map(|string| (String::new(), string))
+            .collect()
+    } // fn fields
 } // Indexable
 
 // -----------------------------------------------------------------------------
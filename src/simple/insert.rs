@@ -12,7 +12,7 @@ use rustc_hash::FxHashSet as HashSet;
 use std::collections::HashSet;
 
 // Static dependencies:
-use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use crate::simple::{indexable::Indexable, search_index::SearchIndex, FuzzyCandidates, ScoringType};
 use kstring::KString;
 use std::collections::BTreeSet;
 
@@ -139,15 +139,14 @@ impl<K: Clone + Ord> SearchIndex<K> {
     /// You can use the enum's variants to represent your different collections.
     /// Each variant's associated data can hold the `key` for your record.
     ///
-    /// Note that I couldn't implement the `FromIterator` trait which would
-    /// allow a caller to `.collect();` into the `SearchIndex`. `FromIterator`
-    /// works with an owned iterator (`IntoIterator`) and uses owned values
-    /// only. If there were a similar trait that worked with borrowed values,
-    /// it would be do-able.
+    /// Note that when loading many records at once, `extend` or `.collect();`
+    /// into the `SearchIndex` is much faster than calling `insert` for each
+    /// record. Those work with owned `(key, value)` pairs only.
     #[tracing::instrument(level = "trace", name = "search index insert", skip(self, key, value))]
     pub fn insert(&mut self, key: &K, value: &dyn Indexable) {
         // Get all keywords for the `Indexable` record:
-        let mut keywords: HashSet<KString> = self.indexable_keywords(value);
+        let record_keywords = self.indexable_keywords(value);
+        let mut keywords: HashSet<KString> = record_keywords.keywords;
 
         // If `dump_keyword` feature is turned on, ensure that all records are
         // attached to this special keyword:
@@ -155,6 +154,18 @@ impl<K: Clone + Ord> SearchIndex<K> {
             keywords.insert(dump_keyword.as_ref().into());
         } // if
 
+        // Record the statistics used for relevance scoring, if required. This
+        // is done before the keys are added, so that keywords the record
+        // already has from an earlier value aren't counted twice:
+        if self.scoring_type != ScoringType::HitCount {
+            let b_tree_map = &self.b_tree_map;
+            self.scoring_statistics.insert(
+                key,
+                record_keywords.frequencies.iter(),
+                |keyword| b_tree_map.get(keyword).is_some_and(|keys| keys.contains(key)),
+            ); // insert
+        } // if
+
         // Iterate over the keywords:
         #[allow(clippy::needless_for_each, reason = "better performance")]
         keywords
@@ -191,8 +202,45 @@ impl<K: Clone + Ord> SearchIndex<K> {
                 } else {
                     let mut b_tree_set = BTreeSet::new();
                     b_tree_set.insert(key.clone());
+                    // Add the new keyword's grams for infix matching, if
+                    // required:
+                    if self.infix_matching {
+                        self.trigram_index.insert(&normalized_keyword);
+                    } // if
+                    // Add the new keyword's deletions for fuzzy matching, if
+                    // required:
+                    if self.fuzzy_candidates == FuzzyCandidates::Deletions {
+                        self.deletion_index.insert(&normalized_keyword, self.fuzzy_distance);
+                    } // if
+                    // Add the new keyword's phonetic codes for phonetic
+                    // matching, if required:
+                    if let Some(algorithm) = &self.phonetic_matching {
+                        self.phonetic_index.insert(&normalized_keyword, algorithm);
+                    } // if
                     self.b_tree_map.insert(normalized_keyword, b_tree_set);
                 } // match
             }); // for_each
+
+        // Record the keyword positions used for phrase and proximity
+        // searches, if required:
+        if self.index_positions {
+            self.positional_index.insert(None, key, record_keywords.positions.iter());
+            for (field, positions) in &record_keywords.field_positions {
+                self.positional_index.insert(Some(field), key, positions.iter());
+            } // for
+        } // if
+
+        // Iterate over the keywords for each named field:
+        for (field, keywords) in record_keywords.fields {
+            let b_tree_map = self.field_b_tree_maps.entry(field).or_default();
+            for keyword in keywords {
+                let keys = b_tree_map.entry(keyword).or_default();
+                // Field keywords observe the same keys per keyword limit as
+                // the main search index:
+                if keys.len() < self.maximum_keys_per_keyword {
+                    keys.insert(key.clone());
+                } // if
+            } // for
+        } // for
     } // fn
 } // impl
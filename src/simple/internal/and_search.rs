@@ -1,4 +1,4 @@
-use crate::simple::search_index::SearchIndex;
+use crate::simple::{internal::fuzzy_fallback::Substitutes, search_index::SearchIndex, FuzzyStrategy};
 use kstring::KString;
 use std::{collections::BTreeSet, hash::Hash};
 
@@ -13,11 +13,31 @@ impl<K: Hash + Ord> SearchIndex<K> {
     /// Search only supports exact keyword matches and does not use fuzzy
     /// matching. Consider providing the `autocomplete` feature to your users as
     /// an ergonomic alternative to fuzzy matching.
-    #[allow(clippy::option_if_let_else)] // `map_or_else` is illegible
     pub(crate) fn internal_and_search(
         &self,
         keywords: &[KString],
     ) -> BTreeSet<&K> {
+        self.internal_fuzzy_and_search(keywords, &mut 0, None, &mut Vec::new())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Works like `internal_and_search`, except that keywords that aren't in
+    /// the search index fall back to their fuzzy matches if `fuzzy_fallback`
+    /// is set. `expansions` is the number of fuzzy matches that may be used,
+    /// and is reduced by the number used. If a fuzzy matching `strategy` is
+    /// given, it's used to find the fuzzy matches.
+    ///
+    /// The matches that were substituted for each keyword are added to
+    /// `substitutes`, for relevance scoring.
+    #[allow(clippy::option_if_let_else)] // `map_or_else` is illegible
+    pub(crate) fn internal_fuzzy_and_search<'s>(
+        &'s self,
+        keywords: &[KString],
+        expansions: &mut usize,
+        strategy: Option<&FuzzyStrategy>,
+        substitutes: &mut Vec<Substitutes<'s, K>>,
+    ) -> BTreeSet<&'s K> {
         // This `BTreeSet` is used to contain the search results:
         let mut search_results = BTreeSet::<&K>::new();
 
@@ -46,6 +66,23 @@ impl<K: Hash + Ord> SearchIndex<K> {
                         // become the new `search_results`:
                         .collect()
                 }
+            } else if let Some(keys) = self.fuzzy_fallback(keyword, expansions, strategy) {
+                // The keyword isn't in the search index, but phonetic or fuzzy
+                // matches may be substituted for it:
+                search_results = if search_results.is_empty() {
+                    keys.keys().copied().collect()
+                } else {
+                    search_results
+                        .into_iter()
+                        .filter(|key| keys.contains_key(key))
+                        .collect()
+                }; // if
+
+                if search_results.is_empty() {
+                    return search_results
+                } // if
+
+                substitutes.push(keys);
             } else {
                 search_results.clear();
                 return search_results
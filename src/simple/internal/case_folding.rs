@@ -0,0 +1,194 @@
+//! Full Unicode case folding, and combining mark detection. Case folding is
+//! mostly the same as converting to lower case. The characters where it
+//! differs are listed here.
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the full case folding of a string. If `turkic` is set, the Turkish
+/// and Azerbaijani rules for the letter `i` are used.
+pub fn case_fold(string: &str, turkic: bool) -> String {
+    let mut folded = String::with_capacity(string.len());
+    let mut chars = string.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if turkic {
+            match char {
+                // A decomposed `İ` is a capital `I` followed by a combining
+                // dot above:
+                'I' if chars.peek() == Some(&'\u{0307}') => {
+                    chars.next();
+                    folded.push('i');
+                    continue;
+                }, // 'I'
+                'I' => {
+                    folded.push('ı');
+                    continue;
+                }, // 'I'
+                'İ' => {
+                    folded.push('i');
+                    continue;
+                }, // 'İ'
+                _ => {},
+            } // match
+        } // if
+
+        match (fold(char), fold_cherokee(char)) {
+            (Some(replacement), _) => folded.push_str(replacement),
+            (None, Some(replacement)) => folded.push(replacement),
+            (None, None) => folded.extend(char.to_lowercase()),
+        } // match
+    } // while
+
+    folded
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the case folding of a character, if it's different from the
+/// character's lower case form.
+#[allow(clippy::too_many_lines)]
+const fn fold(char: char) -> Option<&'static str> {
+    let folded = match char {
+        // Characters that fold to a different character than their lower
+        // case form:
+        '\u{00B5}' => "\u{03BC}",
+        '\u{017F}' => "s",
+        '\u{0345}' | '\u{1FBE}' => "\u{03B9}",
+        '\u{03C2}' => "\u{03C3}",
+        '\u{03D0}' => "\u{03B2}",
+        '\u{03D1}' => "\u{03B8}",
+        '\u{03D5}' => "\u{03C6}",
+        '\u{03D6}' => "\u{03C0}",
+        '\u{03F0}' => "\u{03BA}",
+        '\u{03F1}' => "\u{03C1}",
+        '\u{03F5}' => "\u{03B5}",
+        '\u{1C80}' => "\u{0432}",
+        '\u{1C81}' => "\u{0434}",
+        '\u{1C82}' => "\u{043E}",
+        '\u{1C83}' => "\u{0441}",
+        '\u{1C84}' | '\u{1C85}' => "\u{0442}",
+        '\u{1C86}' => "\u{044A}",
+        '\u{1C87}' => "\u{0463}",
+        '\u{1C88}' => "\u{A64B}",
+        '\u{1E9B}' => "\u{1E61}",
+
+        // Characters that fold to more than one character:
+        '\u{00DF}' | '\u{1E9E}' => "ss",
+        '\u{0149}' => "\u{02BC}n",
+        '\u{01F0}' => "j\u{030C}",
+        '\u{0390}' | '\u{1FD3}' => "\u{03B9}\u{0308}\u{0301}",
+        '\u{03B0}' | '\u{1FE3}' => "\u{03C5}\u{0308}\u{0301}",
+        '\u{0587}' => "\u{0565}\u{0582}",
+        '\u{1E96}' => "h\u{0331}",
+        '\u{1E97}' => "t\u{0308}",
+        '\u{1E98}' => "w\u{030A}",
+        '\u{1E99}' => "y\u{030A}",
+        '\u{1E9A}' => "a\u{02BE}",
+        '\u{1F50}' => "\u{03C5}\u{0313}",
+        '\u{1F52}' => "\u{03C5}\u{0313}\u{0300}",
+        '\u{1F54}' => "\u{03C5}\u{0313}\u{0301}",
+        '\u{1F56}' => "\u{03C5}\u{0313}\u{0342}",
+        '\u{1F80}' | '\u{1F88}' => "\u{1F00}\u{03B9}",
+        '\u{1F81}' | '\u{1F89}' => "\u{1F01}\u{03B9}",
+        '\u{1F82}' | '\u{1F8A}' => "\u{1F02}\u{03B9}",
+        '\u{1F83}' | '\u{1F8B}' => "\u{1F03}\u{03B9}",
+        '\u{1F84}' | '\u{1F8C}' => "\u{1F04}\u{03B9}",
+        '\u{1F85}' | '\u{1F8D}' => "\u{1F05}\u{03B9}",
+        '\u{1F86}' | '\u{1F8E}' => "\u{1F06}\u{03B9}",
+        '\u{1F87}' | '\u{1F8F}' => "\u{1F07}\u{03B9}",
+        '\u{1F90}' | '\u{1F98}' => "\u{1F20}\u{03B9}",
+        '\u{1F91}' | '\u{1F99}' => "\u{1F21}\u{03B9}",
+        '\u{1F92}' | '\u{1F9A}' => "\u{1F22}\u{03B9}",
+        '\u{1F93}' | '\u{1F9B}' => "\u{1F23}\u{03B9}",
+        '\u{1F94}' | '\u{1F9C}' => "\u{1F24}\u{03B9}",
+        '\u{1F95}' | '\u{1F9D}' => "\u{1F25}\u{03B9}",
+        '\u{1F96}' | '\u{1F9E}' => "\u{1F26}\u{03B9}",
+        '\u{1F97}' | '\u{1F9F}' => "\u{1F27}\u{03B9}",
+        '\u{1FA0}' | '\u{1FA8}' => "\u{1F60}\u{03B9}",
+        '\u{1FA1}' | '\u{1FA9}' => "\u{1F61}\u{03B9}",
+        '\u{1FA2}' | '\u{1FAA}' => "\u{1F62}\u{03B9}",
+        '\u{1FA3}' | '\u{1FAB}' => "\u{1F63}\u{03B9}",
+        '\u{1FA4}' | '\u{1FAC}' => "\u{1F64}\u{03B9}",
+        '\u{1FA5}' | '\u{1FAD}' => "\u{1F65}\u{03B9}",
+        '\u{1FA6}' | '\u{1FAE}' => "\u{1F66}\u{03B9}",
+        '\u{1FA7}' | '\u{1FAF}' => "\u{1F67}\u{03B9}",
+        '\u{1FB2}' => "\u{1F70}\u{03B9}",
+        '\u{1FB3}' | '\u{1FBC}' => "\u{03B1}\u{03B9}",
+        '\u{1FB4}' => "\u{03AC}\u{03B9}",
+        '\u{1FB6}' => "\u{03B1}\u{0342}",
+        '\u{1FB7}' => "\u{03B1}\u{0342}\u{03B9}",
+        '\u{1FC2}' => "\u{1F74}\u{03B9}",
+        '\u{1FC3}' | '\u{1FCC}' => "\u{03B7}\u{03B9}",
+        '\u{1FC4}' => "\u{03AE}\u{03B9}",
+        '\u{1FC6}' => "\u{03B7}\u{0342}",
+        '\u{1FC7}' => "\u{03B7}\u{0342}\u{03B9}",
+        '\u{1FD2}' => "\u{03B9}\u{0308}\u{0300}",
+        '\u{1FD6}' => "\u{03B9}\u{0342}",
+        '\u{1FD7}' => "\u{03B9}\u{0308}\u{0342}",
+        '\u{1FE2}' => "\u{03C5}\u{0308}\u{0300}",
+        '\u{1FE4}' => "\u{03C1}\u{0313}",
+        '\u{1FE6}' => "\u{03C5}\u{0342}",
+        '\u{1FE7}' => "\u{03C5}\u{0308}\u{0342}",
+        '\u{1FF2}' => "\u{1F7C}\u{03B9}",
+        '\u{1FF3}' | '\u{1FFC}' => "\u{03C9}\u{03B9}",
+        '\u{1FF4}' => "\u{03CE}\u{03B9}",
+        '\u{1FF6}' => "\u{03C9}\u{0342}",
+        '\u{1FF7}' => "\u{03C9}\u{0342}\u{03B9}",
+        '\u{FB00}' => "ff",
+        '\u{FB01}' => "fi",
+        '\u{FB02}' => "fl",
+        '\u{FB03}' => "ffi",
+        '\u{FB04}' => "ffl",
+        '\u{FB05}' | '\u{FB06}' => "st",
+        '\u{FB13}' => "\u{0574}\u{0576}",
+        '\u{FB14}' => "\u{0574}\u{0565}",
+        '\u{FB15}' => "\u{0574}\u{056B}",
+        '\u{FB16}' => "\u{057E}\u{0576}",
+        '\u{FB17}' => "\u{0574}\u{056D}",
+
+        _ => return None,
+    }; // match
+
+    Some(folded)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the case folding of a Cherokee letter. Cherokee folds to upper case,
+/// which is the opposite of its lower case form.
+const fn fold_cherokee(char: char) -> Option<char> {
+    match char {
+        '\u{13A0}'..='\u{13F5}' => Some(char),
+        '\u{13F8}'..='\u{13FD}' => char::from_u32(char as u32 - 0x0008),
+        '\u{AB70}'..='\u{ABBF}' => char::from_u32(char as u32 - 0xAB70 + 0x13A0),
+        _ => None,
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the character is a combining diacritical mark, such as an
+/// accent. Hebrew points and Arabic vowel marks are included.
+pub const fn is_combining_mark(char: char) -> bool {
+    matches!(
+        char,
+        '\u{0300}'..='\u{036F}'   // Combining Diacritical Marks
+        | '\u{0483}'..='\u{0489}' // Cyrillic combining marks
+        | '\u{0591}'..='\u{05BD}' // Hebrew points
+        | '\u{05BF}'
+        | '\u{05C1}'..='\u{05C2}'
+        | '\u{05C4}'..='\u{05C5}'
+        | '\u{05C7}'
+        | '\u{0610}'..='\u{061A}' // Arabic marks
+        | '\u{064B}'..='\u{065F}'
+        | '\u{0670}'
+        | '\u{06D6}'..='\u{06DC}'
+        | '\u{06DF}'..='\u{06E4}'
+        | '\u{06E7}'..='\u{06E8}'
+        | '\u{06EA}'..='\u{06ED}'
+        | '\u{1AB0}'..='\u{1AFF}' // Combining Diacritical Marks Extended
+        | '\u{1DC0}'..='\u{1DFF}' // Combining Diacritical Marks Supplement
+        | '\u{20D0}'..='\u{20FF}' // Combining Diacritical Marks for Symbols
+        | '\u{FE20}'..='\u{FE2F}' // Combining Half Marks
+    )
+} // fn
@@ -0,0 +1,160 @@
+//! A deletion dictionary of the search index's keywords, for typo-tolerant
+//! fuzzy matching. The deletion index is only maintained when
+//! `SearchIndexBuilder::fuzzy_candidates` is set to `FuzzyCandidates::Deletions`.
+
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// Only the first few chars of a keyword are used for its deletions. This
+/// keeps the number of deletions per keyword small. Typos after the prefix
+/// don't change it, so those keywords are still found.
+const PREFIX_LENGTH: usize = 7;
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords that each deletion was made from.
+///
+/// A deletion is a keyword's prefix with up to `fuzzy_distance` chars removed.
+/// For example, with a distance of `1`, `phone` has the deletions `phone`,
+/// `hone`, `pone`, `phne`, `phoe`, and `phon`. Two keywords that are within
+/// the distance of each other always share a deletion: `pgone` and `phone`
+/// share `pone`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DeletionIndex {
+    /// The keywords that each deletion was made from.
+    pub(crate) deletions: BTreeMap<KString, BTreeSet<KString>>,
+} // DeletionIndex
+
+// -----------------------------------------------------------------------------
+
+impl DeletionIndex {
+    /// Makes a deletion index for the given keywords.
+    pub(crate) fn from_keywords<'k>(
+        keywords: impl Iterator<Item = &'k KString>,
+        distance: usize,
+    ) -> Self {
+        let mut deletion_index = Self::default();
+        keywords.for_each(|keyword| deletion_index.insert(keyword, distance));
+        deletion_index
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a keyword that has been added to the search index.
+    pub(crate) fn insert(&mut self, keyword: &KString, distance: usize) {
+        for deletion in deletions(keyword, distance) {
+            self.deletions
+                .entry(KString::from(deletion))
+                .or_default()
+                .insert(keyword.clone());
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes a keyword that has been removed from the search index.
+    pub(crate) fn remove(&mut self, keyword: &str, distance: usize) {
+        for deletion in deletions(keyword, distance) {
+            let is_empty = self.deletions.get_mut(deletion.as_str()).is_some_and(|keywords| {
+                keywords.remove(keyword);
+                keywords.is_empty()
+            }); // is_some_and
+
+            if is_empty {
+                self.deletions.remove(deletion.as_str());
+            } // if
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that are within `distance` edits of the user's
+    /// keyword, in lexicographic order.
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    pub(crate) fn keywords(&self, user_keyword: &str, distance: usize) -> BTreeSet<&KString> {
+        // Any keyword that shares a deletion with the user's keyword is a
+        // candidate. The candidates are then checked for the whole keyword:
+        deletions(user_keyword, distance)
+            .iter()
+            .filter_map(|deletion| self.deletions.get(deletion.as_str()))
+            .flatten()
+            .filter(|keyword| within_distance(user_keyword, keyword, distance))
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the deletions of a keyword's prefix: every string that can be made
+/// by removing up to `distance` chars from it, including the prefix itself.
+fn deletions(keyword: &str, distance: usize) -> BTreeSet<String> {
+    let prefix: Vec<char> = keyword.chars().take(PREFIX_LENGTH).collect();
+
+    let mut deletions: BTreeSet<String> = BTreeSet::new();
+    deletions.insert(prefix.iter().collect());
+
+    // Each round removes one more char from the previous round's deletions:
+    let mut previous: Vec<Vec<char>> = vec![prefix];
+
+    for _round in 0..distance {
+        let mut next: Vec<Vec<char>> = Vec::new();
+
+        for chars in &previous {
+            for index in 0..chars.len() {
+                let mut deletion = chars.clone();
+                deletion.remove(index);
+                if deletions.insert(deletion.iter().collect()) {
+                    next.push(deletion);
+                } // if
+            } // for
+        } // for
+
+        previous = next;
+    } // for
+
+    deletions
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns whether two keywords are within `distance` edits of each other,
+/// using the optimal string alignment distance: insertions, deletions,
+/// substitutions, and transpositions of adjacent chars each count as one edit.
+#[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+fn within_distance(a: &str, b: &str, distance: usize) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > distance {
+        return false;
+    } // if
+
+    // Only the last three rows of the distance matrix are needed:
+    let mut before_previous: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current: Vec<usize> = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before_previous[j - 2] + 1);
+            } // if
+        } // for
+
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    } // for
+
+    previous[b.len()] <= distance
+} // fn
@@ -0,0 +1,126 @@
+use crate::simple::{internal::string_keywords::SplitContext, search_index::SearchIndex};
+use kstring::KString;
+use std::{borrow::Cow, collections::BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// A search term that has been restricted to a named field, for example
+/// `title:dawn`.
+#[derive(Debug)]
+pub struct FieldTerm {
+    /// The normalized name of the field (i.e. `title`).
+    pub(crate) field: KString,
+    /// The text to search for in the field (i.e. `dawn`).
+    pub(crate) string: String,
+    /// Whether this term was the last term in the search string. For live
+    /// searches, the last term is treated as a partial keyword.
+    pub(crate) is_last: bool,
+} // FieldTerm
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Separates field-restricted terms (i.e. `title:dawn`) from the rest of
+    /// the search string. Only fields that exist in the search index are
+    /// recognized. Anything else (i.e. `12:30`) is left in the search string
+    /// untouched, so it'll be handled the same way it always has been.
+    ///
+    /// Returns the field terms and the remaining search string.
+    pub(crate) fn field_terms<'s>(&self, string: &'s str) -> (Vec<FieldTerm>, Cow<'s, str>) {
+        // If no named fields have been indexed, there's nothing to do:
+        if self.field_b_tree_maps.is_empty() || !string.contains(':') {
+            return (Vec::new(), Cow::Borrowed(string));
+        } // if
+
+        let mut field_terms: Vec<FieldTerm> = Vec::new();
+        let mut remaining: Vec<&str> = Vec::new();
+        let mut terms = string.split_whitespace().peekable();
+
+        while let Some(term) = terms.next() {
+            // Attempt to split the term into a field name and its text, and
+            // check that the field has been indexed:
+            let field_term = term
+                .split_once(':')
+                .filter(|(field, text)| !field.is_empty() && !text.is_empty())
+                .map(|(field, text)| (KString::from_ref(&self.normalize(field)), text))
+                .filter(|(field, _text)| self.field_b_tree_maps.contains_key(field));
+
+            match field_term {
+                Some((field, text)) => field_terms.push(FieldTerm {
+                    field,
+                    string: text.to_string(),
+                    is_last: terms.peek().is_none(),
+                }),
+                None => remaining.push(term),
+            } // match
+        } // while
+
+        if field_terms.is_empty() {
+            (field_terms, Cow::Borrowed(string))
+        } else {
+            (field_terms, Cow::Owned(remaining.join(" ")))
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of records that contain every keyword of the string in
+    /// the named field. If `partial` is set, the last keyword is treated as
+    /// the beginning of a keyword (for live searches.)
+    ///
+    /// Note: This function is lower-level and for internal use only. It does
+    /// not observe any settings such as _maximum results_. These constraints
+    /// should be observed at higher levels.
+    pub(crate) fn internal_field_search(
+        &self,
+        field: &str,
+        string: &str,
+        partial: bool,
+    ) -> BTreeSet<&K> {
+        // Get the keyword map for the field. If the field does not exist, no
+        // records can match:
+        let Some(b_tree_map) = self.field_b_tree_maps.get(field) else {
+            return BTreeSet::new();
+        }; // let
+
+        // Split the field's text into keywords according to the `SearchIndex`
+        // settings:
+        let mut keywords: Vec<KString> =
+            self.string_keywords(string, &SplitContext::Searching);
+
+        // If this is a live search, the last keyword is matched by its prefix:
+        let last_keyword: Option<KString> = if partial { keywords.pop() } else { None };
+
+        let mut search_results: Option<BTreeSet<&K>> = None;
+
+        // Intersect the keys for each keyword in the field:
+        for keyword in &keywords {
+            let keys: BTreeSet<&K> = b_tree_map
+                .get(keyword)
+                .map(|keys| keys.iter().collect())
+                .unwrap_or_default();
+
+            search_results = Some(match search_results {
+                Some(search_results) => &search_results & &keys,
+                None => keys,
+            }); // match
+        } // for
+
+        // Intersect the keys for every keyword that starts with the last
+        // (partial) keyword:
+        if let Some(last_keyword) = last_keyword {
+            let keys: BTreeSet<&K> = b_tree_map
+                .range(last_keyword.clone()..)
+                .take_while(|(keyword, _keys)| keyword.starts_with(&*last_keyword))
+                .flat_map(|(_keyword, keys)| keys)
+                .collect();
+
+            search_results = Some(match search_results {
+                Some(search_results) => &search_results & &keys,
+                None => keys,
+            }); // match
+        } // if
+
+        search_results.unwrap_or_default()
+    } // fn
+} // impl
@@ -0,0 +1,52 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// The keyword map of every named field in the search index, paired with the
+/// field's relevance weight.
+pub type WeightedFields<'a, K> = Vec<(&'a BTreeMap<KString, BTreeSet<K>>, f64)>;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Pairs the keyword map of every named field with its relevance weight.
+    /// Fields that weren't given a weight in the `SearchIndexBuilder` have a
+    /// weight of `1.0`.
+    ///
+    /// Returns `None` if no field weights have been set, in which case every
+    /// keyword has a weight of `1.0`.
+    pub(crate) fn weighted_fields(&self) -> Option<WeightedFields<'_, K>> {
+        let field_weights = self.field_weights.as_ref()?;
+
+        Some(self.field_b_tree_maps
+            .iter()
+            .map(|(field, b_tree_map)| (b_tree_map, field_weights.get(field).copied().unwrap_or(1.0)))
+            .collect())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the relevance weight of a keyword for a record. This is the
+    /// highest weight of the named fields that the keyword was found in for
+    /// the record. If the keyword wasn't found in a named field, its weight is
+    /// `1.0`.
+    pub(crate) fn keyword_weight(
+        weighted_fields: Option<&WeightedFields<'_, K>>,
+        keyword: &str,
+        key: &K,
+    ) -> f64 {
+        weighted_fields
+            .and_then(|weighted_fields| weighted_fields
+                .iter()
+                // Only consider fields where this record has the keyword:
+                .filter(|(b_tree_map, _weight)| b_tree_map
+                    .get(keyword)
+                    .is_some_and(|keys| keys.contains(key)))
+                .map(|(_b_tree_map, weight)| *weight)
+                // Keep the highest weight:
+                .reduce(f64::max))
+            .unwrap_or(1.0)
+    } // fn
+} // impl
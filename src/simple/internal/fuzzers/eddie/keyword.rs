@@ -130,6 +130,19 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
         &'s self,
         user_keyword: &str,
     ) -> impl Iterator<Item = (&'s kstring::KString, &'s BTreeSet<K>)> {
+        // Return the top scoring keywords that could be used as autocomplete
+        // options, and their keys, to the caller:
+        self.eddie_keyword_top_scores(user_keyword).results()
+    } // fn
+
+    /// Scans the entire search index for the keywords that are the most
+    /// similar to the user's keyword, and returns them with their keys and
+    /// similarity scores. This is the basis of `eddie_keyword`.
+    #[inline(always)]
+    pub(crate) fn eddie_keyword_top_scores<'s>(
+        &'s self,
+        user_keyword: &str,
+    ) -> crate::simple::internal::fuzzers::FuzzyTopScores<'s, K, f64> {
         // This structure will track the top scoring keywords:
         let mut top_scores =
             crate::simple::internal::fuzzers::FuzzyTopScores::<K, f64>::with_capacity(
@@ -183,8 +196,8 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
             } // match
         } // if
 
-        // Return the top scoring keywords that could be used as autocomplete
-        // options, and their keys, to the caller:
-        top_scores.results()
+        // Return the top scoring keywords, their keys, and their scores to
+        // the caller:
+        top_scores
     } // fn
 } // impl
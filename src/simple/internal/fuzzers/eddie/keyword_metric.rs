@@ -54,17 +54,10 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
         let scorer = M::new();
 
         // Scan the search index for the highest scoring keywords:
-        self.b_tree_map
-            // Get matching keywords starting with (partial) keyword string:
-            .range(kstring::KString::from_ref(index_range)..)
-            // We did not specify an end bound for our `range` function (see
-            // above.) `range` will return _every_ keyword greater than the
-            // supplied keyword. The below `take_while` will effectively break
-            // iteration when we reach a keyword that does not start with our
-            // supplied (partial) keyword.
-            .take_while(|(index_keyword, _keys)|
-                index_keyword.starts_with::<&str>(index_range.as_ref())
-            )
+        self
+            // Get the keywords to fuzzy match against. Usually, these are the
+            // keywords that start with the index range:
+            .fuzzy_keywords(user_keyword, index_range)
             // For example: if the user typed "gold", and the autocompletion
             // options were "gold" and "golden", this filter would remove "gold"
             // as an autocompletion option because it's currently what's already
@@ -20,6 +20,8 @@ pub mod global_metric;
 pub mod context;
 pub mod context_metric;
 
+pub mod similarity;
+
 // pub mod substitute;
 // pub mod substitute_metric;
 
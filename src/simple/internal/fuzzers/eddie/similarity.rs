@@ -0,0 +1,26 @@
+use crate::simple::{EddieMetric, Similarity};
+use eddie::str::{
+    Levenshtein,
+    DamerauLevenshtein,
+    Jaro,
+    JaroWinkler,
+};
+
+// -----------------------------------------------------------------------------
+
+impl Similarity for EddieMetric {
+    /// Calculates the normalized similarity of two strings using this
+    /// [eddie](https://crates.io/crates/eddie) metric. The score is between
+    /// `0.0` and `1.0` (inclusive), where `1.0` means the strings are the same.
+    ///
+    /// This is used by a `FuzzyStrategy`, which may combine metrics from
+    /// several string similarity crates.
+    fn similarity(&self, str1: &str, str2: &str) -> f64 {
+        match self {
+            Self::Levenshtein => Levenshtein::new().similarity(str1, str2),
+            Self::DamerauLevenshtein => DamerauLevenshtein::new().similarity(str1, str2),
+            Self::Jaro => Jaro::new().similarity(str1, str2),
+            Self::JaroWinkler => JaroWinkler::new().similarity(str1, str2),
+        } // match
+    } // fn
+} // impl
@@ -3,6 +3,7 @@
 /// This is used to select a string similarity metric implemented by Ilia
 /// Schelokov's [eddie](https://crates.io/crates/eddie) crate.
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[repr(u8)]
 pub enum EddieMetric {
     /// See [the detailed description](https://en.wikipedia.org/wiki/Levenshtein_distance).
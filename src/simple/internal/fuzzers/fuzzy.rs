@@ -185,4 +185,71 @@ pub trait Fuzzy<'s, K: Hash + Ord> {
         last_results: &mut std::collections::BTreeSet<&'s K>,
         last_keyword: &str,
     );
-} // trait Fuzzy
\ No newline at end of file
+
+    /// Scans the entire search index for the closest matching _n_ keywords
+    /// using the provided keyword and configured string similarity crate and
+    /// metric.
+    ///
+    /// Unlike the other methods, the similarity scores are kept. This is used
+    /// to report how a search result was matched.
+    ///
+    /// # Input
+    ///
+    /// * `search_index` · A reference to the search index.
+    ///
+    /// * `user_keyword` · The keyword being searched for.
+    ///
+    /// # Output
+    ///
+    /// The closest matching keywords, their keys, and their similarity
+    /// scores, in order of descending similarity. Scores are between `0.0`
+    /// and `1.0` (inclusive), where `1.0` means the strings are the same.
+    ///
+    /// # Notes
+    ///
+    /// * This method expects the input to be normalized already, i.e. if the
+    ///   search is meant to be case-insensitive then the inputs should be in
+    ///   lowercase.
+    fn keyword_similarities(
+        search_index: &'s crate::simple::search_index::SearchIndex<K>,
+        user_keyword: &str,
+    ) -> Vec<(&'s KString, &'s BTreeSet<K>, f64)>;
+
+    /// Scans the search index for the closest matching _n_ keywords using the
+    /// provided keyword and configured string similarity crate and metric.
+    /// Only keywords that are attached to the preceding search results are
+    /// considered.
+    ///
+    /// Unlike the other methods, the similarity scores are kept. This is used
+    /// to report how a search result was matched.
+    ///
+    /// # Input
+    ///
+    /// * `search_index` · A reference to the search index.
+    ///
+    /// * `preceding_keywords` · The keywords before the last keyword in the
+    ///   search string.
+    ///
+    /// * `preceding_results` · The search results for the preceding keywords.
+    ///
+    /// * `last_keyword` · The keyword being searched for.
+    ///
+    /// # Output
+    ///
+    /// The closest matching keywords, their keys, and their similarity
+    /// scores, in order of descending similarity.
+    ///
+    /// # Notes
+    ///
+    /// * This method is a small variation of `keyword_similarities`.
+    ///
+    /// * This method expects the input to be normalized already, i.e. if the
+    ///   search is meant to be case-insensitive then the inputs should be in
+    ///   lowercase.
+    fn context_similarities(
+        search_index: &'s crate::simple::search_index::SearchIndex<K>,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) -> Vec<(&'s KString, &'s BTreeSet<K>, f64)>;
+} // trait Fuzzy
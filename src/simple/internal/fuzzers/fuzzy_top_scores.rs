@@ -155,6 +155,18 @@ impl<'a, K: Hash + Ord, S: Clone + PartialOrd> FuzzyTopScores<'a, K, S> {
     pub(crate) fn results(
         self
     ) -> impl Iterator<Item = (&'a KString, &'a BTreeSet<K>)> {
+        // Yield only the keyword and keys:
+        self.scored_results().map(|(keyword, keys, _score)| (keyword, keys))
+    }
+
+    /// Consumes the tracker and returns results in descending score order,
+    /// along with their scores.
+    ///
+    /// Returns an iterator yielding keyword-keys-score tuples, ordered from
+    /// highest to lowest score.
+    pub(crate) fn scored_results(
+        self
+    ) -> impl Iterator<Item = (&'a KString, &'a BTreeSet<K>, S)> {
         // Collect into a Vec for sorting. A BTreeMap would avoid this but
         // would require `S: Ord`, which excludes floating-point scores:
         let mut vec: Vec<_> = self.top.into_iter().collect();
@@ -164,7 +176,6 @@ impl<'a, K: Hash + Ord, S: Clone + PartialOrd> FuzzyTopScores<'a, K, S> {
             b_score.partial_cmp(a_score).unwrap()
         });
 
-        // Yield only the keyword and keys:
-        vec.into_iter().map(|(keyword, (keys, _))| (keyword, keys))
+        vec.into_iter().map(|(keyword, (keys, score))| (keyword, keys, score))
     }
 }
\ No newline at end of file
@@ -56,4 +56,12 @@ pub use crate::simple::internal::fuzzers::fuzzy::Fuzzy;
 pub mod fuzzy_top_scores;
 
 #[cfg(any(feature = "strsim", feature = "eddie", feature = "rapidfuzz"))]
-pub use crate::simple::internal::fuzzers::fuzzy_top_scores::FuzzyTopScores;
\ No newline at end of file
+pub use crate::simple::internal::fuzzers::fuzzy_top_scores::FuzzyTopScores;
+
+// -----------------------------------------------------------------------------
+//
+// Dispatches fuzzy matching to a `FuzzyStrategy`, or to the string similarity
+// crates' own metric settings.
+
+#[cfg(any(feature = "strsim", feature = "eddie", feature = "rapidfuzz"))]
+mod strategy;
\ No newline at end of file
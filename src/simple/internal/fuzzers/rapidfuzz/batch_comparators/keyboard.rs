@@ -0,0 +1,168 @@
+//! Keyboard-aware weighted edit distance
+
+#![allow(clippy::inline_always)]
+
+use std::marker::PhantomData;
+
+// -----------------------------------------------------------------------------
+//
+/// The cost of inserting or deleting a char, in half-edits.
+const INDEL_COST: usize = 2;
+
+/// The cost of substituting a char for one on a neighbouring key, or of
+/// swapping two adjacent chars, in half-edits.
+const SLIP_COST: usize = 1;
+
+/// The cost of substituting a char for one elsewhere on the keyboard, in
+/// half-edits.
+const SUBSTITUTION_COST: usize = 2;
+
+// -----------------------------------------------------------------------------
+//
+/// A keyboard layout, as the unshifted chars of its rows from the number row
+/// down. Each row is offset half a key to the right of the row above it.
+pub trait KeyboardLayout {
+    /// The rows of the keyboard.
+    const ROWS: [&'static str; 4];
+} // trait KeyboardLayout
+
+/// The US English QWERTY layout.
+pub struct Qwerty;
+
+impl KeyboardLayout for Qwerty {
+    const ROWS: [&'static str; 4] = ["1234567890-=", "qwertyuiop[]", "asdfghjkl;'", "zxcvbnm,./"];
+} // impl
+
+/// The French AZERTY layout.
+pub struct Azerty;
+
+impl KeyboardLayout for Azerty {
+    const ROWS: [&'static str; 4] = ["&é\"'(-è_çà)=", "azertyuiop^$", "qsdfghjklmù*", "wxcvbn,;:!"];
+} // impl
+
+/// The German QWERTZ layout.
+pub struct Qwertz;
+
+impl KeyboardLayout for Qwertz {
+    const ROWS: [&'static str; 4] = ["1234567890ß´", "qwertzuiopü+", "asdfghjklöä#", "yxcvbnm,.-"];
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// This `struct` is used to access the keyboard-aware weighted edit distance
+/// in the same generic manner as the
+/// [rapidfuzz](https://crates.io/crates/rapidfuzz) crate's algorithms.
+///
+/// The distance is an Optimal String Alignment distance where substituting a
+/// char for one on a neighbouring key, or swapping two adjacent chars, counts
+/// as half an edit. These are the most common typing slips, so `thrwad` is
+/// closer to `thread` than `thrxad` is.
+pub struct Keyboard<L: KeyboardLayout> {
+    /// The user's keyword, and the key position of each of its chars.
+    one: Vec<(char, Option<(usize, usize)>)>,
+    layout: PhantomData<L>,
+} // Keyboard
+
+/// The keyboard-aware weighted edit distance for the QWERTY layout.
+pub type KeyboardQwerty = Keyboard<Qwerty>;
+
+/// The keyboard-aware weighted edit distance for the AZERTY layout.
+pub type KeyboardAzerty = Keyboard<Azerty>;
+
+/// The keyboard-aware weighted edit distance for the QWERTZ layout.
+pub type KeyboardQwertz = Keyboard<Qwertz>;
+
+// -----------------------------------------------------------------------------
+
+impl<L: KeyboardLayout> Keyboard<L> {
+    /// Returns the row and the doubled horizontal position of a char's key.
+    /// Doubling the position keeps the half-key offset of each row whole.
+    fn position(char: char) -> Option<(usize, usize)> {
+        let char = char.to_lowercase().next().unwrap_or(char);
+        L::ROWS.iter().enumerate().find_map(|(row, keys)| {
+            keys.chars()
+                .position(|key| key == char)
+                .map(|column| (row, column * 2 + row))
+        }) // find_map
+    } // fn
+
+    /// Returns whether two keys are neighbours: beside each other in the same
+    /// row, or touching in the row above or below.
+    const fn is_neighbour(a: (usize, usize), b: (usize, usize)) -> bool {
+        let rows = a.0.abs_diff(b.0);
+        let columns = a.1.abs_diff(b.1);
+        (rows == 0 && columns == 2) || (rows == 1 && columns == 1)
+    } // fn
+
+    /// Returns the cost of substituting one char for another, in half-edits.
+    const fn substitution_cost(
+        a: (char, Option<(usize, usize)>),
+        b: (char, Option<(usize, usize)>),
+    ) -> usize {
+        match (a, b) {
+            ((a, _), (b, _)) if a == b => 0,
+            ((_, Some(a)), (_, Some(b))) if Self::is_neighbour(a, b) => SLIP_COST,
+            _ => SUBSTITUTION_COST,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// This `BatchComparator` trait implementation is used to access the
+/// keyboard-aware weighted edit distance in a generic manner.
+impl<L: KeyboardLayout> crate::simple::internal::fuzzers::rapidfuzz::BatchComparator for Keyboard<L> {
+    /// Instantiates a new batch comparator.
+    #[inline(always)]
+    fn new(one: &str) -> Self {
+        Self {
+            one: one.chars().map(|char| (char, Self::position(char))).collect(),
+            layout: PhantomData,
+        } // Self
+    } // fn
+
+    /// Calculates normalized similarity.
+    #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
+    fn normalized_similarity(
+        &self,
+        many: &str,
+        score_cutoff: f64
+    ) -> Option<f64> {
+        let one = &self.one;
+        let many: Vec<(char, Option<(usize, usize)>)> = many
+            .chars()
+            .map(|char| (char, Self::position(char)))
+            .collect();
+
+        let maximum = one.len().max(many.len()) * INDEL_COST;
+        if maximum == 0 {
+            return Some(1.0);
+        } // if
+
+        // Only the last three rows of the distance matrix are needed:
+        let mut before_previous: Vec<usize> = vec![0; many.len() + 1];
+        let mut previous: Vec<usize> = (0..=many.len()).map(|j| j * INDEL_COST).collect();
+        let mut current: Vec<usize> = vec![0; many.len() + 1];
+
+        for i in 1..=one.len() {
+            current[0] = i * INDEL_COST;
+
+            for j in 1..=many.len() {
+                current[j] = (previous[j] + INDEL_COST)
+                    .min(current[j - 1] + INDEL_COST)
+                    .min(previous[j - 1] + Self::substitution_cost(one[i - 1], many[j - 1]));
+
+                if i > 1 && j > 1 && one[i - 1].0 == many[j - 2].0 && one[i - 2].0 == many[j - 1].0 {
+                    current[j] = current[j].min(before_previous[j - 2] + SLIP_COST);
+                } // if
+            } // for
+
+            std::mem::swap(&mut before_previous, &mut previous);
+            std::mem::swap(&mut previous, &mut current);
+        } // for
+
+        let similarity = 1.0 - previous[many.len()] as f64 / maximum as f64;
+        (similarity >= score_cutoff).then_some(similarity)
+    } // fn
+} // impl
@@ -7,6 +7,7 @@ pub mod hamming;
 pub mod indel;
 pub mod jaro;
 pub mod jaro_winkler;
+pub mod keyboard;
 pub mod lcs_seq;
 pub mod levenshtein;
 pub mod osa;
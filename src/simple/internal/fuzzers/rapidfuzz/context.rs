@@ -6,6 +6,9 @@ use crate::simple::internal::fuzzers::rapidfuzz::{
     Indel,
     Jaro,
     JaroWinkler,
+    KeyboardAzerty,
+    KeyboardQwerty,
+    KeyboardQwertz,
     LcsSeq,
     Levenshtein,
     Osa,
@@ -170,6 +173,24 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
         preceding_results: &BTreeSet<&'s K>,
         last_keyword: &str,
     ) -> impl Iterator<Item = (&'s KString, &'s BTreeSet<K>)> {
+        // Return the top scoring keywords that could be used as autocomplete
+        // options, and their keys, to the caller:
+        self.rapidfuzz_context_top_scores(preceding_keywords, preceding_results, last_keyword)
+            .results()
+    } // fn
+
+    /// Scans the search index for the keywords that are the most similar to
+    /// the user's last keyword, in the context of the preceding keywords, and
+    /// returns them with their keys and similarity scores. This is the basis
+    /// of `rapidfuzz_context`.
+    #[inline(always)]
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn rapidfuzz_context_top_scores<'s>(
+        &'s self,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) -> crate::simple::internal::fuzzers::FuzzyTopScores<'s, K, f64> {
         // This structure will track the top scoring keywords:
         let mut top_scores =
             crate::simple::internal::fuzzers::FuzzyTopScores::<K, f64>::with_capacity(
@@ -279,14 +300,41 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
                         &mut top_scores,
                     ),
 
+                Some(RapidfuzzMetric::KeyboardQwerty) => self
+                    .rapidfuzz_context_comparator::<KeyboardQwerty>(
+                        preceding_keywords,
+                        preceding_results,
+                        last_keyword,
+                        &index_range,
+                        &mut top_scores,
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardAzerty) => self
+                    .rapidfuzz_context_comparator::<KeyboardAzerty>(
+                        preceding_keywords,
+                        preceding_results,
+                        last_keyword,
+                        &index_range,
+                        &mut top_scores,
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardQwertz) => self
+                    .rapidfuzz_context_comparator::<KeyboardQwertz>(
+                        preceding_keywords,
+                        preceding_results,
+                        last_keyword,
+                        &index_range,
+                        &mut top_scores,
+                    ),
+
                 // If no string similarity metric was defined in the search
                 // index, fuzzy string matching is effectively turned off.
                 None => { /* Do nothing */ },
             } // match
         } // if
 
-        // Return the top scoring keywords that could be used as autocomplete
-        // options, and their keys, to the caller:
-        top_scores.results()
+        // Return the top scoring keywords, their keys, and their scores to
+        // the caller:
+        top_scores
     } // fn
 } // impl
@@ -85,17 +85,10 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
         let scorer = BC::new(last_keyword);
 
         // Scan the search index for the highest scoring keywords:
-        self.b_tree_map
-            // Get matching keywords starting with (partial) keyword string:
-            .range(KString::from_ref(index_range)..)
-            // We did not specify an end bound for our `range` function (see
-            // above.) `range` will return _every_ keyword greater than the
-            // supplied keyword. The below `take_while` will effectively break
-            // iteration when we reach a keyword that does not start with our
-            // supplied (partial) keyword.
-            .take_while(|(index_keyword, _keys)|
-                index_keyword.starts_with::<&str>(index_range.as_ref())
-            )
+        self
+            // Get the keywords to fuzzy match against. Usually, these are the
+            // keywords that start with the index range:
+            .fuzzy_keywords(last_keyword, index_range)
             // Filter out repetitious keywords. If the keyword has already been
             // used in the preceding keywords, or if it matches what the user
             // has currently typed in (as the last partial keyword we're
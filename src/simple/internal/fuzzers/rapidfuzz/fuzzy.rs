@@ -157,4 +157,32 @@ impl<'s, K: Hash + Ord> crate::simple::internal::fuzzers::Fuzzy<'s, K> for Rapid
             *last_results = fuzzy_search_results;
         } // if
     } // fn
-} // trait Fuzzy
\ No newline at end of file
+
+    #[inline(always)]
+    fn keyword_similarities(
+        search_index: &'s SearchIndex<K>,
+        user_keyword: &str,
+    ) -> Vec<(&'s KString, &'s BTreeSet<K>, f64)> {
+        search_index
+            .rapidfuzz_keyword_top_scores(user_keyword)
+            // Keep the similarity score of each keyword:
+            .scored_results()
+            // Collect all fuzzy matches into a `Vec`:
+            .collect()
+    } // fn
+
+    #[inline(always)]
+    fn context_similarities(
+        search_index: &'s SearchIndex<K>,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) -> Vec<(&'s KString, &'s BTreeSet<K>, f64)> {
+        search_index
+            .rapidfuzz_context_top_scores(preceding_keywords, preceding_results, last_keyword)
+            // Keep the similarity score of each keyword:
+            .scored_results()
+            // Collect all fuzzy matches into a `Vec`:
+            .collect()
+    } // fn
+} // trait Fuzzy
@@ -6,6 +6,9 @@ use crate::simple::internal::fuzzers::rapidfuzz::{
     Indel,
     Jaro,
     JaroWinkler,
+    KeyboardAzerty,
+    KeyboardQwerty,
+    KeyboardQwertz,
     LcsSeq,
     Levenshtein,
     Osa,
@@ -148,6 +151,7 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
     /// );
     /// ```
     #[inline(always)]
+    #[allow(clippy::too_many_lines)]
     pub(crate) fn rapidfuzz_global<'s>(
         &'s self,
         preceding_keywords: &[KString],
@@ -252,6 +256,30 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
                         &mut top_scores
                     ),
 
+                Some(RapidfuzzMetric::KeyboardQwerty) => self
+                    .rapidfuzz_global_comparator::<KeyboardQwerty>(
+                        preceding_keywords,
+                        last_keyword,
+                        &index_range,
+                        &mut top_scores
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardAzerty) => self
+                    .rapidfuzz_global_comparator::<KeyboardAzerty>(
+                        preceding_keywords,
+                        last_keyword,
+                        &index_range,
+                        &mut top_scores
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardQwertz) => self
+                    .rapidfuzz_global_comparator::<KeyboardQwertz>(
+                        preceding_keywords,
+                        last_keyword,
+                        &index_range,
+                        &mut top_scores
+                    ),
+
                 // If no string similarity metric was defined in the search
                 // index, fuzzy string matching is effectively turned off.
                 None => { /* Do nothing */ },
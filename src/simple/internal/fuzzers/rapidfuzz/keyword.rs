@@ -6,6 +6,9 @@ use crate::simple::internal::fuzzers::rapidfuzz::{
     Indel,
     Jaro,
     JaroWinkler,
+    KeyboardAzerty,
+    KeyboardQwerty,
+    KeyboardQwertz,
     LcsSeq,
     Levenshtein,
     Osa,
@@ -137,6 +140,19 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
         &'s self,
         user_keyword: &str,
     ) -> impl Iterator<Item = (&'s kstring::KString, &'s BTreeSet<K>)> {
+        // Return the top scoring keywords that could be used as autocomplete
+        // options, and their keys, to the caller:
+        self.rapidfuzz_keyword_top_scores(user_keyword).results()
+    } // fn
+
+    /// Scans the entire search index for the keywords that are the most
+    /// similar to the user's keyword, and returns them with their keys and
+    /// similarity scores. This is the basis of `rapidfuzz_keyword`.
+    #[inline(always)]
+    pub(crate) fn rapidfuzz_keyword_top_scores<'s>(
+        &'s self,
+        user_keyword: &str,
+    ) -> crate::simple::internal::fuzzers::FuzzyTopScores<'s, K, f64> {
         // This structure will track the top scoring keywords:
         let mut top_scores =
             crate::simple::internal::fuzzers::FuzzyTopScores::<K, f64>::with_capacity(
@@ -226,14 +242,35 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
                         &mut top_scores
                     ),
 
+                Some(RapidfuzzMetric::KeyboardQwerty) => self
+                    .rapidfuzz_keyword_comparator::<KeyboardQwerty>(
+                        user_keyword,
+                        &index_range,
+                        &mut top_scores
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardAzerty) => self
+                    .rapidfuzz_keyword_comparator::<KeyboardAzerty>(
+                        user_keyword,
+                        &index_range,
+                        &mut top_scores
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardQwertz) => self
+                    .rapidfuzz_keyword_comparator::<KeyboardQwertz>(
+                        user_keyword,
+                        &index_range,
+                        &mut top_scores
+                    ),
+
                 // If no string similarity metric was defined in the search
                 // index, fuzzy string matching is effectively turned off.
                 None => { /* Do nothing */ },
             } // match
         } // if
 
-        // Return the top scoring keywords that could be used as autocomplete
-        // options, and their keys, to the caller:
-        top_scores.results()
+        // Return the top scoring keywords, their keys, and their scores to
+        // the caller:
+        top_scores
     } // fn
 } // impl
@@ -20,6 +20,8 @@ pub mod global_comparator;
 pub mod context;
 pub mod context_comparator;
 
+pub mod similarity;
+
 // pub mod substitute;
 // pub mod substitute_comparator;
 
@@ -45,6 +47,7 @@ use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::hamming::Ham
 use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::indel::Indel;
 use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::jaro::Jaro;
 use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::jaro_winkler::JaroWinkler;
+use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::keyboard::{KeyboardAzerty, KeyboardQwerty, KeyboardQwertz};
 use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::lcs_seq::LcsSeq;
 use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::levenshtein::Levenshtein;
 use crate::simple::internal::fuzzers::rapidfuzz::batch_comparators::osa::Osa;
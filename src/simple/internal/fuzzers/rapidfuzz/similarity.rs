@@ -0,0 +1,60 @@
+#![allow(clippy::inline_always)]
+
+use crate::simple::internal::fuzzers::rapidfuzz::{
+    BatchComparator,
+    DamerauLevenshtein,
+    Hamming,
+    Indel,
+    Jaro,
+    JaroWinkler,
+    KeyboardAzerty,
+    KeyboardQwerty,
+    KeyboardQwertz,
+    LcsSeq,
+    Levenshtein,
+    Osa,
+    Postfix,
+    Prefix,
+};
+use crate::simple::{RapidfuzzMetric, Similarity};
+
+// -----------------------------------------------------------------------------
+
+impl Similarity for RapidfuzzMetric {
+    /// Calculates the normalized similarity of two strings using this
+    /// [rapidfuzz](https://crates.io/crates/rapidfuzz) metric. The score is
+    /// between `0.0` and `1.0` (inclusive), where `1.0` means the strings are
+    /// the same.
+    ///
+    /// This is used by a `FuzzyStrategy`, which may combine metrics from
+    /// several string similarity crates. Scanning the search index with a
+    /// single metric is faster through the batch comparators.
+    fn similarity(&self, one: &str, many: &str) -> f64 {
+        match self {
+            Self::DamerauLevenshtein => similarity::<DamerauLevenshtein>(one, many),
+            Self::Hamming => similarity::<Hamming>(one, many),
+            Self::Indel => similarity::<Indel>(one, many),
+            Self::Jaro => similarity::<Jaro>(one, many),
+            Self::JaroWinkler => similarity::<JaroWinkler>(one, many),
+            Self::LcsSeq => similarity::<LcsSeq>(one, many),
+            Self::Levenshtein => similarity::<Levenshtein>(one, many),
+            Self::Osa => similarity::<Osa>(one, many),
+            Self::Postfix => similarity::<Postfix>(one, many),
+            Self::Prefix => similarity::<Prefix>(one, many),
+            Self::KeyboardQwerty => similarity::<KeyboardQwerty>(one, many),
+            Self::KeyboardAzerty => similarity::<KeyboardAzerty>(one, many),
+            Self::KeyboardQwertz => similarity::<KeyboardQwertz>(one, many),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Calculates the normalized similarity of two strings with the given batch
+/// comparator.
+#[inline(always)]
+fn similarity<BC: BatchComparator>(one: &str, many: &str) -> f64 {
+    BC::new(one)
+        .normalized_similarity(many, 0.0)
+        .unwrap_or_default()
+} // fn
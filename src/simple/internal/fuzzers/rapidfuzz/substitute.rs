@@ -6,6 +6,9 @@ use crate::simple::internal::fuzzers::rapidfuzz::{
     Indel,
     Jaro,
     JaroWinkler,
+    KeyboardAzerty,
+    KeyboardQwerty,
+    KeyboardQwertz,
     LcsSeq,
     Levenshtein,
     Osa,
@@ -207,6 +210,24 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
                         &index_range,
                     ),
 
+                Some(RapidfuzzMetric::KeyboardQwerty) =>
+                    self.rapidfuzz_substitute_comparator::<KeyboardQwerty>(
+                        &user_keyword,
+                        &index_range,
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardAzerty) =>
+                    self.rapidfuzz_substitute_comparator::<KeyboardAzerty>(
+                        &user_keyword,
+                        &index_range,
+                    ),
+
+                Some(RapidfuzzMetric::KeyboardQwertz) =>
+                    self.rapidfuzz_substitute_comparator::<KeyboardQwertz>(
+                        &user_keyword,
+                        &index_range,
+                    ),
+
                 // If no string similarity metric was defined in the search
                 // index, fuzzy string matching is effectively turned off.
                 None => None,
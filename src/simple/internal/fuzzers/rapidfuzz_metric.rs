@@ -3,6 +3,7 @@
 /// This is used to select a string similarity metric implemented by the 
 /// [rapidfuzz](https://crates.io/crates/rapidfuzz) crate.
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[repr(u8)]
 pub enum RapidfuzzMetric {
     /// The Damerau-Levenshtein distance measures the minimum number of
@@ -101,4 +102,21 @@ pub enum RapidfuzzMetric {
     /// The Prefix similarity measures the length of the common prefix between
     /// two sequences.
     Prefix = 9,
+
+    /// A keyboard-aware weighted edit distance for the US English QWERTY
+    /// layout. This is not implemented by `rapidfuzz` itself.
+    ///
+    /// It's the Optimal String Alignment distance, where substituting a
+    /// character for one on a neighbouring key (i.e. `thrwad` for `thread`) or
+    /// swapping two adjacent characters (i.e. `thraed`) only counts as half an
+    /// edit. These typing slips are ranked as closer than other typos.
+    KeyboardQwerty = 10,
+
+    /// A keyboard-aware weighted edit distance for the French AZERTY layout.
+    /// See `KeyboardQwerty`.
+    KeyboardAzerty = 11,
+
+    /// A keyboard-aware weighted edit distance for the German QWERTZ layout.
+    /// See `KeyboardQwerty`.
+    KeyboardQwertz = 12,
 }
\ No newline at end of file
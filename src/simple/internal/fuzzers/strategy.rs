@@ -0,0 +1,383 @@
+//! Dispatches fuzzy matching to a `FuzzyStrategy`, or to the string similarity
+//! crates' own metric settings if no strategy is in use.
+
+use crate::simple::internal::fuzzers::FuzzyTopScores;
+use crate::simple::search_index::SearchIndex;
+use crate::simple::{FuzzyStrategy, Similarity};
+use kstring::KString;
+use std::{collections::BTreeSet, hash::Hash};
+
+#[allow(unused_imports)]
+use crate::simple::internal::fuzzers::Fuzzy;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+    /// Scans the search index for the closest matching _n_ keywords using the
+    /// provided keyword and fuzzy matching strategy.
+    ///
+    /// * Keywords that are already present in the `preceding_keywords` are
+    ///   not returned.
+    ///
+    /// * If `preceding_results` isn't empty, only keywords that are attached
+    ///   to at least one of its keys are returned. This is how contextual
+    ///   fuzzy matching is achieved.
+    ///
+    /// This method expects the input to be normalized already.
+    pub(crate) fn strategy_top_scores<'s>(
+        &'s self,
+        strategy: &FuzzyStrategy,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) -> FuzzyTopScores<'s, K, f64> {
+        // This structure will track the top scoring keywords:
+        let mut top_scores: FuzzyTopScores<'s, K, f64> =
+            FuzzyTopScores::with_capacity(self.maximum_autocomplete_options);
+
+        // This call to `index_range` builds a keyword index range to fuzzy
+        // match against. If a `None` is returned, no fuzzy matching should be
+        // performed:
+        let Some(index_range) = self.index_range(last_keyword) else {
+            return top_scores;
+        }; // let
+
+        self
+            // Get the keywords to fuzzy match against. Usually, these are the
+            // keywords that start with the index range:
+            .fuzzy_keywords(last_keyword, &index_range)
+            // If the keyword is already present in the user's search string,
+            // then don't return it:
+            .filter(|(index_keyword, _index_keys)| !preceding_keywords.contains(index_keyword))
+            // Only examine search index keywords that intersect with the
+            // preceding results, if there are any:
+            .filter(|(_index_keyword, index_keys)| {
+                preceding_results.is_empty() || index_keys
+                    .iter()
+                    .any(|index_key| preceding_results.contains(index_key))
+            }) // filter
+            // Score each keyword, and keep it if it meets the minimum score:
+            .for_each(|(index_keyword, index_keys)| {
+                let score: f64 = strategy.similarity(last_keyword, index_keyword);
+                if score >= self.fuzzy_minimum_score {
+                    top_scores.insert(index_keyword, index_keys, score);
+                } // if
+            }); // for_each
+
+        top_scores
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// If there are no autocompletion options for the user's keyword, fills
+    /// them with the closest fuzzy matches. See `Fuzzy::autocomplete_keyword`.
+    pub(crate) fn fuzzy_autocomplete_keyword<'s>(
+        &'s self,
+        strategy: Option<&FuzzyStrategy>,
+        autocomplete_options: &mut Vec<&'s str>,
+        keyword: &str,
+    ) {
+        if let Some(strategy) = strategy {
+            if autocomplete_options.is_empty() {
+                *autocomplete_options = self
+                    .strategy_top_scores(strategy, &[], &BTreeSet::new(), keyword)
+                    .results()
+                    .map(|(keyword, _keys)| keyword.as_str())
+                    .collect();
+            } // if
+            return;
+        } // if
+
+        // Otherwise, each enabled string similarity crate is tried in turn,
+        // until one of them finds fuzzy matches:
+        #[cfg(feature = "rapidfuzz")]
+        crate::simple::internal::fuzzers::Rapidfuzz::autocomplete_keyword(
+            self,
+            autocomplete_options,
+            keyword,
+        );
+
+        #[cfg(feature = "strsim")]
+        crate::simple::internal::fuzzers::Strsim::autocomplete_keyword(
+            self,
+            autocomplete_options,
+            keyword,
+        );
+
+        #[cfg(feature = "eddie")]
+        crate::simple::internal::fuzzers::Eddie::autocomplete_keyword(
+            self,
+            autocomplete_options,
+            keyword,
+        );
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// If there are no autocompletion options for the user's last keyword,
+    /// fills them with the closest fuzzy matches that aren't already in the
+    /// search string. See `Fuzzy::autocomplete_global`.
+    pub(crate) fn fuzzy_autocomplete_global<'s>(
+        &'s self,
+        strategy: Option<&FuzzyStrategy>,
+        preceding_keywords: &[KString],
+        last_autocomplete_options: &mut Vec<&'s KString>,
+        last_keyword: &str,
+    ) {
+        if let Some(strategy) = strategy {
+            if last_autocomplete_options.is_empty() {
+                *last_autocomplete_options = self
+                    .strategy_top_scores(strategy, preceding_keywords, &BTreeSet::new(), last_keyword)
+                    .results()
+                    .map(|(keyword, _keys)| keyword)
+                    .collect();
+            } // if
+            return;
+        } // if
+
+        #[cfg(feature = "rapidfuzz")]
+        crate::simple::internal::fuzzers::Rapidfuzz::autocomplete_global(
+            self,
+            preceding_keywords,
+            last_autocomplete_options,
+            last_keyword,
+        );
+
+        #[cfg(feature = "strsim")]
+        crate::simple::internal::fuzzers::Strsim::autocomplete_global(
+            self,
+            preceding_keywords,
+            last_autocomplete_options,
+            last_keyword,
+        );
+
+        #[cfg(feature = "eddie")]
+        crate::simple::internal::fuzzers::Eddie::autocomplete_global(
+            self,
+            preceding_keywords,
+            last_autocomplete_options,
+            last_keyword,
+        );
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// If there are no autocompletion options for the user's last keyword,
+    /// fills them with the closest fuzzy matches that are attached to the
+    /// preceding results. See `Fuzzy::autocomplete_context`.
+    pub(crate) fn fuzzy_autocomplete_context<'s>(
+        &'s self,
+        strategy: Option<&FuzzyStrategy>,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+        last_autocomplete_options: &mut Vec<&'s KString>,
+    ) {
+        if let Some(strategy) = strategy {
+            if last_autocomplete_options.is_empty() {
+                *last_autocomplete_options = self
+                    .strategy_top_scores(strategy, preceding_keywords, preceding_results, last_keyword)
+                    .results()
+                    .map(|(keyword, _keys)| keyword)
+                    .collect();
+            } // if
+            return;
+        } // if
+
+        #[cfg(feature = "rapidfuzz")]
+        crate::simple::internal::fuzzers::Rapidfuzz::autocomplete_context(
+            self,
+            preceding_keywords,
+            preceding_results,
+            last_keyword,
+            last_autocomplete_options,
+        );
+
+        #[cfg(feature = "strsim")]
+        crate::simple::internal::fuzzers::Strsim::autocomplete_context(
+            self,
+            preceding_keywords,
+            preceding_results,
+            last_keyword,
+            last_autocomplete_options,
+        );
+
+        #[cfg(feature = "eddie")]
+        crate::simple::internal::fuzzers::Eddie::autocomplete_context(
+            self,
+            preceding_keywords,
+            preceding_results,
+            last_keyword,
+            last_autocomplete_options,
+        );
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// If there are no `Live` search results for the user's keyword, fills
+    /// them with the keys of the closest fuzzy matches. See
+    /// `Fuzzy::live_search_keyword`.
+    pub(crate) fn fuzzy_live_keyword<'s>(
+        &'s self,
+        strategy: Option<&FuzzyStrategy>,
+        search_results: &mut BTreeSet<&'s K>,
+        user_keyword: &str,
+    ) {
+        if let Some(strategy) = strategy {
+            if search_results.is_empty() {
+                *search_results = self
+                    .strategy_top_scores(strategy, &[], &BTreeSet::new(), user_keyword)
+                    .results()
+                    .flat_map(|(_keyword, keys)| keys)
+                    .take(self.maximum_search_results)
+                    .collect();
+            } // if
+            return;
+        } // if
+
+        #[cfg(feature = "rapidfuzz")]
+        crate::simple::internal::fuzzers::Rapidfuzz::live_search_keyword(
+            self,
+            search_results,
+            user_keyword,
+        );
+
+        #[cfg(feature = "strsim")]
+        crate::simple::internal::fuzzers::Strsim::live_search_keyword(
+            self,
+            search_results,
+            user_keyword,
+        );
+
+        #[cfg(feature = "eddie")]
+        crate::simple::internal::fuzzers::Eddie::live_search_keyword(
+            self,
+            search_results,
+            user_keyword,
+        );
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// If there are no `Live` search results for the user's last keyword,
+    /// fills them with the keys of the closest fuzzy matches that are also in
+    /// the preceding results. See `Fuzzy::live_search_context`.
+    pub(crate) fn fuzzy_live_context<'s>(
+        &'s self,
+        strategy: Option<&FuzzyStrategy>,
+        preceding_results: &BTreeSet<&'s K>,
+        preceding_keywords: &[KString],
+        last_results: &mut BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) {
+        if let Some(strategy) = strategy {
+            if last_results.is_empty() {
+                *last_results = self
+                    .strategy_top_scores(strategy, preceding_keywords, preceding_results, last_keyword)
+                    .results()
+                    .flat_map(|(_keyword, keys)| keys)
+                    .take(self.maximum_search_results)
+                    .collect();
+            } // if
+            return;
+        } // if
+
+        #[cfg(feature = "rapidfuzz")]
+        crate::simple::internal::fuzzers::Rapidfuzz::live_search_context(
+            self,
+            preceding_results,
+            preceding_keywords,
+            last_results,
+            last_keyword,
+        );
+
+        #[cfg(feature = "strsim")]
+        crate::simple::internal::fuzzers::Strsim::live_search_context(
+            self,
+            preceding_results,
+            preceding_keywords,
+            last_results,
+            last_keyword,
+        );
+
+        #[cfg(feature = "eddie")]
+        crate::simple::internal::fuzzers::Eddie::live_search_context(
+            self,
+            preceding_results,
+            preceding_keywords,
+            last_results,
+            last_keyword,
+        );
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the closest fuzzy matches for the user's last keyword, their
+    /// keys, and their similarity scores, in order of descending similarity.
+    /// If there are preceding keywords, only keywords that are attached to
+    /// the preceding results are returned. See `Fuzzy::keyword_similarities`
+    /// and `Fuzzy::context_similarities`.
+    pub(crate) fn fuzzy_similarities<'s>(
+        &'s self,
+        strategy: Option<&FuzzyStrategy>,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) -> Vec<(&'s KString, &'s BTreeSet<K>, f64)> {
+        if let Some(strategy) = strategy {
+            return self
+                .strategy_top_scores(strategy, preceding_keywords, preceding_results, last_keyword)
+                .scored_results()
+                .collect();
+        } // if
+
+        // Otherwise, each enabled string similarity crate is tried in turn,
+        // until one of them finds fuzzy matches:
+        #[allow(unused_mut)]
+        let mut similarities: Vec<(&'s KString, &'s BTreeSet<K>, f64)> = Vec::new();
+
+        #[cfg(feature = "rapidfuzz")]
+        if similarities.is_empty() {
+            similarities = if preceding_keywords.is_empty() {
+                crate::simple::internal::fuzzers::Rapidfuzz::keyword_similarities(self, last_keyword)
+            } else {
+                crate::simple::internal::fuzzers::Rapidfuzz::context_similarities(
+                    self,
+                    preceding_keywords,
+                    preceding_results,
+                    last_keyword,
+                )
+            }; // if
+        } // if
+
+        #[cfg(feature = "strsim")]
+        if similarities.is_empty() {
+            similarities = if preceding_keywords.is_empty() {
+                crate::simple::internal::fuzzers::Strsim::keyword_similarities(self, last_keyword)
+            } else {
+                crate::simple::internal::fuzzers::Strsim::context_similarities(
+                    self,
+                    preceding_keywords,
+                    preceding_results,
+                    last_keyword,
+                )
+            }; // if
+        } // if
+
+        #[cfg(feature = "eddie")]
+        if similarities.is_empty() {
+            similarities = if preceding_keywords.is_empty() {
+                crate::simple::internal::fuzzers::Eddie::keyword_similarities(self, last_keyword)
+            } else {
+                crate::simple::internal::fuzzers::Eddie::context_similarities(
+                    self,
+                    preceding_keywords,
+                    preceding_results,
+                    last_keyword,
+                )
+            }; // if
+        } // if
+
+        similarities
+    } // fn
+} // impl
@@ -165,6 +165,23 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
         preceding_results: &BTreeSet<&'s K>,
         last_keyword: &str,
     ) -> impl Iterator<Item = (&'s KString, &'s BTreeSet<K>)> {
+        // Return the top scoring keywords that could be used as autocomplete
+        // options, and their keys, to the caller:
+        self.strsim_context_top_scores(preceding_keywords, preceding_results, last_keyword)
+            .results()
+    } // fn
+
+    /// Scans the search index for the keywords that are the most similar to
+    /// the user's last keyword, in the context of the preceding keywords, and
+    /// returns them with their keys and similarity scores. This is the basis
+    /// of `strsim_context`.
+    #[inline(always)]
+    pub(crate) fn strsim_context_top_scores<'s>(
+        &'s self,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) -> crate::simple::internal::fuzzers::FuzzyTopScores<'s, K, f64> {
         // This structure will track the top scoring keywords:
         let mut top_scores =
             crate::simple::internal::fuzzers::FuzzyTopScores::<K, f64>::with_capacity(
@@ -235,8 +252,8 @@ impl<K: std::hash::Hash + Ord> crate::simple::search_index::SearchIndex<K> {
             } // match
         } // if
 
-        // Return the top scoring keywords that could be used as autocomplete
-        // options, and their keys, to the caller:
-        top_scores.results()
+        // Return the top scoring keywords, their keys, and their scores to
+        // the caller:
+        top_scores
     } // fn
 } // impl
@@ -0,0 +1,29 @@
+use crate::simple::internal::fuzzers::strsim::{
+    DamerauLevenshtein,
+    Jaro,
+    JaroWinkler,
+    Levenshtein,
+    Metric,
+    SorensenDice,
+};
+use crate::simple::{StrsimMetric, Similarity};
+
+// -----------------------------------------------------------------------------
+
+impl Similarity for StrsimMetric {
+    /// Calculates the normalized similarity of two strings using this
+    /// [strsim](https://crates.io/crates/strsim) metric. The score is between
+    /// `0.0` and `1.0` (inclusive), where `1.0` means the strings are the same.
+    ///
+    /// This is used by a `FuzzyStrategy`, which may combine metrics from
+    /// several string similarity crates.
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        match self {
+            Self::DamerauLevenshtein => DamerauLevenshtein::similarity(a, b),
+            Self::Jaro => Jaro::similarity(a, b),
+            Self::JaroWinkler => JaroWinkler::similarity(a, b),
+            Self::Levenshtein => Levenshtein::similarity(a, b),
+            Self::SorensenDice => SorensenDice::similarity(a, b),
+        } // match
+    } // fn
+} // impl
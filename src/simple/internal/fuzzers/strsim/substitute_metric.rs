@@ -46,17 +46,10 @@ impl<K: Ord> crate::simple::search_index::SearchIndex<K> {
     ) -> Option<&'s str>
     where M: crate::simple::internal::fuzzers::strsim::Metric {
         // Scan the search index for the highest scoring keyword:
-        self.b_tree_map
-            // Get matching keywords starting with (partial) keyword string:
-            .range(kstring::KString::from_ref(index_range)..)
-            // We did not specify an end bound for our `range` function (see
-            // above.) `range` will return _every_ keyword greater than the
-            // supplied keyword. The below `take_while` will effectively break
-            // iteration when we reach a keyword that does not start with our
-            // supplied (partial) keyword.
-            .take_while(|(index_keyword, _keys)|
-                index_keyword.starts_with::<&str>(index_range.as_ref())
-            )
+        self
+            // Get the keywords to fuzzy match against. Usually, these are the
+            // keywords that start with the index range:
+            .fuzzy_keywords(user_keyword, index_range)
             // For each keyword in the search index, calculate its similarity
             // to the user's keyword. Map the `(keyword, keys)` tuple into
             // a `(keyword, score)` tuple:
@@ -3,6 +3,7 @@
 /// This is used to select a string similarity metric implemented by Danny Guo's
 /// [strsim](https://crates.io/crates/strsim) crate.
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[repr(u8)]
 pub enum StrsimMetric {
     /// Like optimal string alignment, but substrings can be edited an unlimited
@@ -0,0 +1,81 @@
+use crate::simple::{internal::phonetics::PHONETIC_SIMILARITY, search_index::SearchIndex, FuzzyStrategy};
+use kstring::KString;
+use std::{collections::{BTreeMap, BTreeSet}, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// The phonetic or fuzzy matches that were substituted for a keyword. Each key
+/// is paired with the best match that it contains, and the match's similarity
+/// score.
+pub type Substitutes<'s, K> = BTreeMap<&'s K, (&'s KString, f64)>;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+    /// Returns the keys for the closest matches of a keyword that isn't in the
+    /// search index. Keywords that sound like it are used if
+    /// `phonetic_matching` is set. Otherwise, the closest fuzzy matches are
+    /// used if `fuzzy_fallback` is set. Each key is returned with the best
+    /// match that it contains and the match's similarity score, for relevance
+    /// scoring. Phonetic matches have a similarity of `PHONETIC_SIMILARITY`.
+    ///
+    /// `expansions` is the number of matches that the rest of the search may
+    /// still use. It's reduced by the number of matches used here. `None` is
+    /// returned if the keyword is in the search index, or if no fallback may
+    /// be used.
+    ///
+    /// If a fuzzy matching `strategy` is given, it's used instead of the
+    /// search index's string similarity metrics.
+    ///
+    /// This method expects the keyword to be normalized already.
+    #[allow(unused_variables, clippy::unused_self)]
+    pub(crate) fn fuzzy_fallback(
+        &self,
+        keyword: &str,
+        expansions: &mut usize,
+        strategy: Option<&FuzzyStrategy>,
+    ) -> Option<Substitutes<'_, K>> {
+        if !(self.fuzzy_fallback || self.phonetic_matching.is_some())
+            || *expansions == 0
+            || self.b_tree_map.contains_key(keyword)
+        {
+            return None;
+        } // if
+
+        // Keywords that sound like the keyword are tried first:
+        #[allow(unused_mut, reason = "compiler complains when fuzzy matching is off")]
+        let mut substitutes: Vec<(&KString, &BTreeSet<K>, f64)> = self
+            .phonetic_keywords(&[], &BTreeSet::new(), keyword)
+            .into_iter()
+            .map(|(substitute, substitute_keys)| (substitute, substitute_keys, PHONETIC_SIMILARITY))
+            .collect();
+
+        // Otherwise, find the closest matches with the strategy, or with the
+        // enabled string similarity crates:
+        #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+        if substitutes.is_empty() && self.fuzzy_fallback {
+            substitutes = self.fuzzy_similarities(strategy, &[], &BTreeSet::new(), keyword);
+        } // if
+
+        if substitutes.is_empty() && !self.fuzzy_fallback {
+            return None;
+        } // if
+
+        // The fuzzy substitutes are in order of descending similarity, so each
+        // key keeps the first (best) keyword that it was found under:
+        let mut keys: Substitutes<'_, K> = BTreeMap::new();
+
+        for (substitute, substitute_keys, similarity) in substitutes.into_iter().take(*expansions) {
+            *expansions -= 1;
+            for key in substitute_keys {
+                keys.entry(key).or_insert((substitute, similarity));
+            } // for
+        } // for
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("fuzzy fallback for {:?}: {} keys", keyword, keys.len());
+
+        Some(keys)
+    } // fn
+} // impl
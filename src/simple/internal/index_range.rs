@@ -1,5 +1,44 @@
+use crate::simple::FuzzyCandidates;
+use kstring::KString;
+use std::collections::{btree_map, BTreeSet};
+
+// -----------------------------------------------------------------------------
+
 const EMPTY_STRING: &str = "";
 
+// -----------------------------------------------------------------------------
+//
+/// The keywords in the search index that a user's keyword is fuzzy matched
+/// against, and their keys. See `SearchIndex::fuzzy_keywords`.
+pub enum FuzzyKeywords<'s, 'r, K> {
+    /// The keywords that start with the index range.
+    Prefix {
+        range: btree_map::Range<'s, KString, BTreeSet<K>>,
+        index_range: &'r str,
+    }, // Prefix
+    /// The keywords found in the deletion index.
+    Deletions(std::vec::IntoIter<(&'s KString, &'s BTreeSet<K>)>),
+} // FuzzyKeywords
+
+// -----------------------------------------------------------------------------
+
+impl<'s, K> Iterator for FuzzyKeywords<'s, '_, K> {
+    type Item = (&'s KString, &'s BTreeSet<K>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            // We did not specify an end bound for our `range` (see
+            // `fuzzy_keywords`.) `range` will return _every_ keyword greater
+            // than the index range. This effectively breaks iteration when we
+            // reach a keyword that does not start with the index range:
+            Self::Prefix { range, index_range } => range
+                .next()
+                .filter(|(index_keyword, _keys)| index_keyword.starts_with(*index_range)),
+            Self::Deletions(keywords) => keywords.next(),
+        } // match
+    } // fn
+} // impl
+
 // -----------------------------------------------------------------------------
 
 impl<K: Ord> crate::simple::SearchIndex<K> {
@@ -49,12 +88,18 @@ impl<K: Ord> crate::simple::SearchIndex<K> {
     ///   be fuzzy matched against every keyword in the index. This is OK (or
     ///   even desirable) if the search index isn't large, however, this will be
     ///   crippling slow on very large search indicies.
+    ///
+    /// With `FuzzyCandidates::Deletions`, the first characters don't have to
+    /// match, so the index range is always empty.
     #[inline]
     pub(crate) fn index_range<'k>(
         &self,
         user_keyword: &'k str
     ) -> Option<beef::lean::Cow<'k, str>> {
-        if self.fuzzy_length > 0 {
+        if self.fuzzy_candidates == FuzzyCandidates::Deletions {
+            // The deletion index chooses the keywords to fuzzy match against:
+            Some(EMPTY_STRING.into())
+        } else if self.fuzzy_length > 0 {
             // The user keyword must be longer than the match length to be
             // evaluated for fuzzy-matches:
             if user_keyword.len() >= self.fuzzy_length {
@@ -80,4 +125,37 @@ impl<K: Ord> crate::simple::SearchIndex<K> {
             Some(EMPTY_STRING.into())
         } // if
     } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords in the search index that the user's keyword should
+    /// be fuzzy matched against, according to the `FuzzyCandidates` setting.
+    ///
+    /// * For `Prefix`, these are the keywords that start with the index range
+    ///   (see `index_range`.)
+    ///
+    /// * For `Deletions`, these are the keywords within `fuzzy_distance` edits
+    ///   of the user's keyword, wherever the edits are. The index range is
+    ///   ignored.
+    #[inline]
+    pub(crate) fn fuzzy_keywords<'s, 'r>(
+        &'s self,
+        user_keyword: &str,
+        index_range: &'r str,
+    ) -> FuzzyKeywords<'s, 'r, K> {
+        match self.fuzzy_candidates {
+            FuzzyCandidates::Prefix => FuzzyKeywords::Prefix {
+                range: self.b_tree_map.range(KString::from_ref(index_range)..),
+                index_range,
+            }, // Prefix
+            FuzzyCandidates::Deletions => FuzzyKeywords::Deletions(
+                self.deletion_index
+                    .keywords(user_keyword, self.fuzzy_distance)
+                    .into_iter()
+                    .filter_map(|keyword| self.b_tree_map.get_key_value(keyword))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            ), // Deletions
+        } // match
+    } // fn
 } // impl
\ No newline at end of file
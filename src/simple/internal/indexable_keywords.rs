@@ -1,18 +1,51 @@
 // Conditionally select hash map type based on feature flags:
 #[cfg(feature = "gxhash")]
+type HashMap<K, V> = std::collections::HashMap<K, V, gxhash::GxBuildHasher>;
+#[cfg(feature = "gxhash")]
 type HashSet<T> = std::collections::HashSet<T, gxhash::GxBuildHasher>;
 
 #[cfg(feature = "ahash")]
-use ahash::HashSet;
+use ahash::{HashMap, HashSet};
 
 #[cfg(feature = "rustc-hash")]
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 #[cfg(all(not(feature = "ahash"), not(feature = "gxhash"), not(feature = "rustc-hash")))]
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 // Static dependencies:
-use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::{
+    internal::{positions::POSITION_GAP, string_keywords::SplitContext},
+    ScoringType,
+};
+use kstring::KString;
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords found in an `Indexable` record, as returned by the
+/// `indexable_keywords` helper method.
+#[derive(Debug, Default)]
+pub struct RecordKeywords {
+    /// Every keyword in the record, regardless of which field it came from.
+    pub(crate) keywords: HashSet<KString>,
+
+    /// The keywords for each named field in the record, keyed by the
+    /// normalized field name. Records that only implement
+    /// `Indexable::strings` have no named fields.
+    pub(crate) fields: HashMap<KString, HashSet<KString>>,
+
+    /// The number of times each keyword appears in the record. This is only
+    /// counted for the `Bm25` and `TfIdf` scoring types.
+    pub(crate) frequencies: HashMap<KString, usize>,
+
+    /// The positions of each keyword in the record. This is only recorded
+    /// when keyword positions are being indexed.
+    pub(crate) positions: HashMap<KString, Vec<usize>>,
+
+    /// The positions of each keyword in the record, for each named field.
+    /// These use the same numbering as `positions`.
+    pub(crate) field_positions: HashMap<KString, HashMap<KString, Vec<usize>>>,
+} // RecordKeywords
 
 // -----------------------------------------------------------------------------
 
@@ -24,21 +57,78 @@ impl<K: Ord> crate::simple::SearchIndex<K> {
     pub(crate) fn indexable_keywords(
         &self,
         value: &dyn crate::simple::Indexable
-    ) -> HashSet<kstring::KString> {
-        // The implemented trait method `strings()` will return the strings from
-        // the record that are meant to be indexed:
-        let strings = value.strings();
-
-        // Store the individual keywords from these strings:
-        strings
-            // Iterate over each `String` field from the record:
+    ) -> RecordKeywords {
+        let mut record_keywords = RecordKeywords::default();
+
+        // The position of the next string's first keyword in the record:
+        let mut offset: usize = 0;
+
+        // The implemented trait method `fields()` will return the strings from
+        // the record that are meant to be indexed, along with the name of the
+        // field that the string came from. Unless it has been overridden, this
+        // method returns the output of `strings()` with empty field names:
+        value
+            .fields()
+            // Iterate over each `(String, String)` field from the record:
             .into_iter()
-            // Split each `String` into keywords according to the `SearchIndex`
-            // settings. Note that `string_keywords` will allow "use entire
-            // string as a keyword" if enabled in user settings. Flatten the
-            // string's keywords into the `HashSet`:
-            .flat_map(|string| self.string_keywords(&string, &SplitContext::Indexing))
-            // Collect all keywords into a `HashSet`:
-            .collect()
+            .for_each(|(field, string)| {
+                // Split each `String` into keywords according to the
+                // `SearchIndex` settings. Note that `string_keywords` will
+                // allow "use entire string as a keyword" if enabled in user
+                // settings:
+                let keywords = self.string_keywords(&string, &SplitContext::Indexing);
+
+                let field: Option<KString> = (!field.is_empty())
+                    .then(|| self.normalize(&field).to_string().into());
+
+                // If the string came from a named field, also record its
+                // keywords under the field's name:
+                if let Some(field) = &field {
+                    record_keywords
+                        .fields
+                        .entry(field.clone())
+                        .or_default()
+                        .extend(keywords.iter().cloned());
+                } // if
+
+                // Record the position of each keyword, if required. The string
+                // is split the same way a search string is, so that the
+                // positions line up with the keywords of a quoted phrase:
+                if self.index_positions {
+                    let tokens = self.string_keywords(&string, &SplitContext::Searching);
+
+                    for (index, token) in tokens.iter().enumerate() {
+                        record_keywords
+                            .positions
+                            .entry(token.clone())
+                            .or_default()
+                            .push(offset + index);
+
+                        if let Some(field) = &field {
+                            record_keywords
+                                .field_positions
+                                .entry(field.clone())
+                                .or_default()
+                                .entry(token.clone())
+                                .or_default()
+                                .push(offset + index);
+                        } // if
+                    } // for
+
+                    offset += tokens.len() + POSITION_GAP;
+                } // if
+
+                // Count the keywords for relevance scoring, if required:
+                if self.scoring_type != ScoringType::HitCount {
+                    for keyword in &keywords {
+                        *record_keywords.frequencies.entry(keyword.clone()).or_default() += 1;
+                    } // for
+                } // if
+
+                // Flatten the string's keywords into the `HashSet`:
+                record_keywords.keywords.extend(keywords);
+            }); // for_each
+
+        record_keywords
     } // fn
 } // impl
@@ -1,10 +1,22 @@
 //! Structures, traits, and methods that are meant for internal use.
 
 mod and_search;
-mod indexable_keywords;
+pub mod case_folding;
+pub mod deletions;
+mod field_search;
+pub mod fuzzy_fallback;
+pub mod field_weights;
+pub mod positions;
+pub mod scoring;
+pub mod indexable_keywords;
 mod keyword_search;
 mod normalize;
+mod phonetic_keywords;
+pub mod patterns;
+pub mod phonetics;
 pub mod string_keywords;
+pub mod synonym_keywords;
+pub mod trigrams;
 
 #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
 mod index_range;
@@ -1,4 +1,11 @@
-#[cfg(feature = "unicode-normalization")]
+use crate::simple::{
+    internal::case_folding::{case_fold, is_combining_mark},
+    CaseFolding,
+    NormalizationForm,
+};
+use std::borrow::Cow;
+
+#[cfg(all(feature = "unicode-normalization", not(feature = "icu_normalizer")))]
 use unicode_normalization::UnicodeNormalization;
 
 // -----------------------------------------------------------------------------
@@ -11,43 +18,87 @@ impl<K: Ord> crate::simple::SearchIndex<K> {
     /// match equivalent characters (like "ﬁ" and "fi") regardless of how
     /// they were encoded.
     ///
-    /// When the `unicode-normalization` feature is enabled, NFKC normalization
-    /// is applied to decompose compatibility characters into their canonical
-    /// forms. When case insensitivity is also enabled, the string is
-    /// additionally lowercased.
+    /// When the `icu_normalizer` or `unicode-normalization` feature is
+    /// enabled, the search index's `NormalizationForm` is applied. If
+    /// combining marks are to be removed, characters are decomposed first so
+    /// that their marks can be separated, and are composed again afterwards
+    /// for the composing forms.
     ///
-    /// * If the search index case been set to be case sensitive, the string
-    ///   will be returned as-is.
+    /// * If the search index case been set to be case sensitive, the string's
+    ///   case will be left as-is.
     ///
     /// * If the search index case been set to be case insensitive, the string
-    ///   will be returned in lower-case form.
+    ///   will be returned in lower-case or case-folded form, according to the
+    ///   `CaseFolding` setting.
     #[inline]
     pub(crate) fn normalize<'k>(
         &self,
         keyword: &'k str
     ) -> beef::lean::Cow<'k, str> {
-        if self.case_sensitive {
-            #[cfg(feature = "unicode-normalization")]
-            let normalized = keyword.nfkc().collect::<String>().into();
+        let mut normalized: Cow<'k, str> =
+            self.normalization_form(keyword, self.remove_combining_marks);
 
-            #[cfg(feature = "icu_normalizer")]
-            let normalized = self.icu_normalizer.normalize(keyword).into();
+        // Convert the string to a common case, if case insensitive:
+        if !self.case_sensitive {
+            normalized = Cow::Owned(match self.case_folding {
+                CaseFolding::Lowercase => normalized.to_lowercase(),
+                CaseFolding::Full => case_fold(&normalized, false),
+                CaseFolding::Turkic => case_fold(&normalized, true),
+            }); // Owned
+        } // if
 
-            #[cfg(not(any(feature = "unicode-normalization", feature = "icu_normalizer")))]
-            let normalized = keyword.into();
+        // Remove combining marks and, for the composing forms, compose the
+        // remaining characters again:
+        if self.remove_combining_marks {
+            let stripped: String = normalized
+                .chars()
+                .filter(|char| !is_combining_mark(*char))
+                .collect();
 
-            normalized
-        } else {
-            #[cfg(feature = "unicode-normalization")]
-            let normalized = keyword.nfkc().collect::<String>().to_lowercase().into();
+            normalized = Cow::Owned(self.normalization_form(&stripped, false).into_owned());
+        } // if
 
-            #[cfg(feature = "icu_normalizer")]
-            let normalized = self.icu_normalizer.normalize(keyword).to_lowercase().into();
+        match normalized {
+            Cow::Borrowed(normalized) => normalized.into(),
+            Cow::Owned(normalized) => normalized.into(),
+        } // match
+    } // fn
 
-            #[cfg(not(any(feature = "unicode-normalization", feature = "icu_normalizer")))]
-            let normalized = keyword.to_lowercase().into();
+    // -------------------------------------------------------------------------
+    //
+    /// Applies the search index's Unicode normalization form to a string. If
+    /// `decompose` is set, the decomposing counterpart of the form is applied
+    /// instead (i.e. `Nfd` in place of `Nfc`.)
+    ///
+    /// If neither Unicode normalization feature is enabled, the string is
+    /// returned as-is.
+    #[allow(unused_variables, clippy::missing_const_for_fn, reason = "not used when normalization is off")]
+    fn normalization_form<'s>(&self, string: &'s str, decompose: bool) -> Cow<'s, str> {
+        let normalization_form = match (&self.normalization_form, decompose) {
+            (NormalizationForm::Nfc | NormalizationForm::Nfd, true) => &NormalizationForm::Nfd,
+            (NormalizationForm::Nfkc | NormalizationForm::Nfkd, true) => &NormalizationForm::Nfkd,
+            (normalization_form, false) => normalization_form,
+        }; // match
 
-            normalized
-        } // if
+        #[cfg(feature = "icu_normalizer")]
+        let normalized = match normalization_form {
+            NormalizationForm::Nfc => icu_normalizer::ComposingNormalizer::new_nfc().normalize(string),
+            NormalizationForm::Nfd => icu_normalizer::DecomposingNormalizer::new_nfd().normalize(string),
+            NormalizationForm::Nfkc => self.icu_normalizer.normalize(string),
+            NormalizationForm::Nfkd => icu_normalizer::DecomposingNormalizer::new_nfkd().normalize(string),
+        }; // match
+
+        #[cfg(all(feature = "unicode-normalization", not(feature = "icu_normalizer")))]
+        let normalized = Cow::Owned(match normalization_form {
+            NormalizationForm::Nfc => string.nfc().collect(),
+            NormalizationForm::Nfd => string.nfd().collect(),
+            NormalizationForm::Nfkc => string.nfkc().collect(),
+            NormalizationForm::Nfkd => string.nfkd().collect(),
+        }); // Owned
+
+        #[cfg(not(any(feature = "unicode-normalization", feature = "icu_normalizer")))]
+        let normalized = Cow::Borrowed(string);
+
+        normalized
     } // fn
-} // impl
\ No newline at end of file
+} // impl
@@ -0,0 +1,259 @@
+//! Wildcard and regular expression terms, which are matched against the
+//! keywords in the search index rather than looked up exactly. See
+//! `SearchIndexBuilder::pattern_matching`.
+
+use crate::simple::{QueryNode, SearchIndex};
+use kstring::KString;
+use std::{borrow::Cow, collections::BTreeMap};
+
+// -----------------------------------------------------------------------------
+//
+/// A search term that matches keywords by pattern, for example `colo?r` or
+/// `/^ab\d{3}$/`.
+#[derive(Clone, Debug)]
+pub struct KeywordPattern {
+    /// The literal beginning of the pattern, if it has one. Only keywords that
+    /// start with it are checked, so that the whole keyword map doesn't have
+    /// to be walked.
+    prefix: String,
+    /// How each candidate keyword is checked.
+    matcher: Matcher,
+} // KeywordPattern
+
+// -----------------------------------------------------------------------------
+//
+/// How a `KeywordPattern` checks a keyword.
+#[derive(Clone, Debug)]
+enum Matcher {
+    /// A `?` matches any one char, and a `*` matches any number of chars. The
+    /// pattern must match the entire keyword.
+    Wildcard(Vec<char>),
+    /// A regular expression. It may match any part of the keyword, unless it's
+    /// anchored with `^` and `$`.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+} // Matcher
+
+// -----------------------------------------------------------------------------
+
+impl KeywordPattern {
+    /// Returns whether the keyword matches the pattern.
+    fn is_match(&self, keyword: &str) -> bool {
+        match &self.matcher {
+            Matcher::Wildcard(pattern) => {
+                let keyword: Vec<char> = keyword.chars().collect();
+                wildcard_match(pattern, &keyword)
+            } // Wildcard
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) => regex.is_match(keyword),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the entries of a keyword map whose keywords match the pattern,
+    /// in lexicographic order. No more than `limit` keywords are returned.
+    pub(crate) fn keywords<'m, V>(
+        &self,
+        b_tree_map: &'m BTreeMap<KString, V>,
+        limit: usize,
+    ) -> impl Iterator<Item = (&'m KString, &'m V)> + use<'m, '_, V> {
+        b_tree_map
+            // Only keywords that start with the literal prefix can match:
+            .range(KString::from_ref(&self.prefix)..)
+            .take_while(|(keyword, _value)| keyword.starts_with(self.prefix.as_str()))
+            .filter(|(keyword, _value)| self.is_match(keyword))
+            .take(limit)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns whether the keyword matches a wildcard pattern. When a mismatch is
+/// found after a `*`, the `*` is retried with one more char, rather than
+/// backtracking through every earlier choice.
+fn wildcard_match(pattern: &[char], keyword: &[char]) -> bool {
+    let mut pattern_position = 0;
+    let mut keyword_position = 0;
+
+    // The pattern position after the last `*`, and the keyword position that
+    // it was last tried at:
+    let mut star: Option<(usize, usize)> = None;
+
+    while keyword_position < keyword.len() {
+        match pattern.get(pattern_position) {
+            Some('*') => {
+                pattern_position += 1;
+                star = Some((pattern_position, keyword_position));
+            } // '*'
+            Some(char) if *char == '?' || *char == keyword[keyword_position] => {
+                pattern_position += 1;
+                keyword_position += 1;
+            } // char
+            _ => match star {
+                Some((after_star, tried)) => {
+                    pattern_position = after_star;
+                    keyword_position = tried + 1;
+                    star = Some((after_star, tried + 1));
+                } // Some
+                None => return false,
+            }, // _
+        } // match
+    } // while
+
+    // Any remaining `*` can match nothing:
+    pattern[pattern_position..].iter().all(|char| *char == '*')
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the literal beginning of an anchored regular expression, i.e. `ab`
+/// for `^ab\d{3}$`. Expressions that aren't anchored, or that contain
+/// alternatives, don't have one.
+#[cfg(feature = "regex")]
+fn regex_prefix(expression: &str) -> &str {
+    let Some(rest) = expression.strip_prefix('^') else {
+        return "";
+    }; // let
+
+    if expression.contains('|') {
+        return "";
+    } // if
+
+    let mut literal = rest
+        .char_indices()
+        .find(|(_index, char)| !char.is_alphanumeric())
+        .map_or(rest, |(index, _char)| &rest[..index]);
+
+    // A quantifier after the last literal char makes it optional, so that char
+    // isn't part of the prefix:
+    if rest[literal.len()..].starts_with(['?', '*', '{']) {
+        literal = literal
+            .char_indices()
+            .next_back()
+            .map_or("", |(index, _char)| &literal[..index]);
+    } // if
+
+    literal
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Makes a wildcard pattern from a search term, i.e. `colo?r` or
+    /// `inv-2024-*`. The term is normalized the same way keywords are. Terms
+    /// without a wildcard, or without anything but wildcards, aren't patterns.
+    pub(crate) fn wildcard_pattern(&self, term: &str) -> Option<KeywordPattern> {
+        let pattern: Vec<char> = self.normalize(term).chars().collect();
+
+        let is_wildcard = |char: &char| matches!(char, '?' | '*');
+
+        if !pattern.iter().any(is_wildcard) || pattern.iter().all(is_wildcard) {
+            return None;
+        } // if
+
+        Some(KeywordPattern {
+            prefix: pattern.iter().take_while(|char| !is_wildcard(char)).collect(),
+            matcher: Matcher::Wildcard(pattern),
+        }) // KeywordPattern
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Makes a regular expression pattern, i.e. `^ab\d{3}$`. The expression
+    /// is case insensitive, unless the search index is case sensitive.
+    /// Invalid expressions aren't patterns.
+    #[cfg(feature = "regex")]
+    pub(crate) fn regex_pattern(&self, expression: &str) -> Option<KeywordPattern> {
+        let regex = regex::RegexBuilder::new(expression)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .ok()?;
+
+        Some(KeywordPattern {
+            prefix: self.normalize(regex_prefix(expression)).into_owned(),
+            matcher: Matcher::Regex(regex),
+        }) // KeywordPattern
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Makes a pattern from a search term. A term between slashes is a regular
+    /// expression (with the `regex` feature), and a term that contains a `?`
+    /// or `*` is a wildcard pattern. Anything else isn't a pattern.
+    pub(crate) fn keyword_pattern(&self, term: &str) -> Option<KeywordPattern> {
+        #[cfg(feature = "regex")]
+        if let Some(expression) = term
+            .strip_prefix('/')
+            .and_then(|term| term.strip_suffix('/'))
+            .filter(|expression| !expression.is_empty())
+        {
+            return self.regex_pattern(expression);
+        } // if
+
+        self.wildcard_pattern(term)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Makes a pattern from a `Wildcard` or `Regex` query node.
+    pub(crate) fn query_pattern(&self, query: &QueryNode) -> Option<KeywordPattern> {
+        match query {
+            QueryNode::Wildcard(pattern) => self.wildcard_pattern(pattern),
+            #[cfg(feature = "regex")]
+            QueryNode::Regex(expression) => self.regex_pattern(expression),
+            _ => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Separates wildcard and regular expression terms from the rest of the
+    /// search string, if `pattern_matching` has been turned on. Terms that
+    /// aren't valid patterns are left in the search string untouched.
+    ///
+    /// Returns the patterns and the remaining search string.
+    pub(crate) fn pattern_terms<'s>(&self, string: &'s str) -> (Vec<KeywordPattern>, Cow<'s, str>) {
+        if !self.pattern_matching || !string.contains(['?', '*', '/']) {
+            return (Vec::new(), Cow::Borrowed(string));
+        } // if
+
+        let mut patterns: Vec<KeywordPattern> = Vec::new();
+        let mut remaining: Vec<&str> = Vec::new();
+
+        for term in string.split_whitespace() {
+            match self.keyword_pattern(term) {
+                Some(pattern) => patterns.push(pattern),
+                None => remaining.push(term),
+            } // match
+        } // for
+
+        if patterns.is_empty() {
+            (patterns, Cow::Borrowed(string))
+        } else {
+            (patterns, Cow::Owned(remaining.join(" ")))
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of the records that contain a keyword matching the
+    /// pattern. Each key is returned with the first keyword that it matched,
+    /// which is used for relevance scoring. No more than
+    /// `maximum_pattern_expansions` keywords are used.
+    ///
+    /// Note: This function is lower-level and for internal use only. It does
+    /// not observe any settings such as _maximum results_. These constraints
+    /// should be observed at higher levels.
+    pub(crate) fn pattern_search(&self, pattern: &KeywordPattern) -> BTreeMap<&K, &KString> {
+        let mut search_results: BTreeMap<&K, &KString> = BTreeMap::new();
+
+        for (keyword, keys) in pattern.keywords(&self.b_tree_map, self.maximum_pattern_expansions) {
+            for key in keys {
+                search_results.entry(key).or_insert(keyword);
+            } // for
+        } // for
+
+        search_results
+    } // fn
+} // impl
@@ -0,0 +1,45 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::{collections::BTreeSet, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+    /// Returns the search index's keywords that sound like the user's last
+    /// keyword, and their keys, in lexicographic order. Nothing is returned
+    /// unless `phonetic_matching` is set.
+    ///
+    /// * Keywords that are already present in the `preceding_keywords` are
+    ///   not returned.
+    ///
+    /// * If `preceding_results` isn't empty, only keywords that are attached
+    ///   to at least one of its keys are returned.
+    ///
+    /// This method expects the input to be normalized already.
+    pub(crate) fn phonetic_keywords<'s>(
+        &'s self,
+        preceding_keywords: &[KString],
+        preceding_results: &BTreeSet<&'s K>,
+        last_keyword: &str,
+    ) -> Vec<(&'s KString, &'s BTreeSet<K>)> {
+        let Some(algorithm) = &self.phonetic_matching else {
+            return Vec::new();
+        }; // let
+
+        self.phonetic_index
+            .keywords(last_keyword, algorithm)
+            .into_iter()
+            // If the keyword is already present in the user's search string,
+            // then don't return it:
+            .filter(|keyword| !preceding_keywords.contains(keyword))
+            // The phonetic index only holds the search index's keywords, so
+            // their keys are always found:
+            .filter_map(|keyword| self.b_tree_map.get_key_value(keyword))
+            // Only keep keywords that intersect with the preceding results, if
+            // there are any:
+            .filter(|(_keyword, keys)| {
+                preceding_results.is_empty() || keys.iter().any(|key| preceding_results.contains(key))
+            }) // filter
+            .collect()
+    } // fn
+} // impl
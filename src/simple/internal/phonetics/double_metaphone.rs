@@ -0,0 +1,634 @@
+//! The Double Metaphone phonetic algorithm by Lawrence Philips. Each keyword
+//! has a primary code and an alternate code, for keywords that may be
+//! pronounced more than one way. This follows the Apache Commons Codec
+//! implementation, with codes of up to four characters. `0` stands for the
+//! `TH` sound.
+
+/// The maximum length of the codes.
+const MAXIMUM_LENGTH: usize = 4;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the primary and alternate Double Metaphone codes for a keyword. The
+/// keyword is expected to be in upper-case.
+pub fn double_metaphone(keyword: &[char]) -> (String, String) {
+    let word = Word::new(keyword);
+    let mut codes = Codes::default();
+    let mut index = usize::from(word.is_silent_start());
+
+    while !codes.is_complete() && index < word.len() {
+        index = match word.char_at(index) {
+            'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                if index == 0 {
+                    codes.add('A');
+                } // if
+                index + 1
+            }, // vowels
+            'B' => {
+                codes.add('P');
+                word.skip_double(index, 'B')
+            }, // 'B'
+            'Ç' => {
+                codes.add('S');
+                index + 1
+            }, // 'Ç'
+            'C' => word.handle_c(&mut codes, index),
+            'D' => word.handle_d(&mut codes, index),
+            'F' => {
+                codes.add('F');
+                word.skip_double(index, 'F')
+            }, // 'F'
+            'G' => word.handle_g(&mut codes, index),
+            'H' => word.handle_h(&mut codes, index),
+            'J' => word.handle_j(&mut codes, index),
+            'K' => {
+                codes.add('K');
+                word.skip_double(index, 'K')
+            }, // 'K'
+            'L' => word.handle_l(&mut codes, index),
+            'M' => {
+                codes.add('M');
+                if word.condition_m0(index) { index + 2 } else { index + 1 }
+            }, // 'M'
+            'N' => {
+                codes.add('N');
+                word.skip_double(index, 'N')
+            }, // 'N'
+            'Ñ' => {
+                codes.add('N');
+                index + 1
+            }, // 'Ñ'
+            'P' => word.handle_p(&mut codes, index),
+            'Q' => {
+                codes.add('K');
+                word.skip_double(index, 'Q')
+            }, // 'Q'
+            'R' => word.handle_r(&mut codes, index),
+            'S' => word.handle_s(&mut codes, index),
+            'T' => word.handle_t(&mut codes, index),
+            'V' => {
+                codes.add('F');
+                word.skip_double(index, 'V')
+            }, // 'V'
+            'W' => word.handle_w(&mut codes, index),
+            'X' => word.handle_x(&mut codes, index),
+            'Z' => word.handle_z(&mut codes, index),
+            _ => index + 1,
+        }; // match
+    } // while
+
+    (codes.primary, codes.alternate)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The primary and alternate codes being built for a keyword.
+#[derive(Default)]
+struct Codes {
+    primary: String,
+    alternate: String,
+} // Codes
+
+// -----------------------------------------------------------------------------
+
+impl Codes {
+    /// Returns whether both codes have reached the maximum length.
+    fn is_complete(&self) -> bool {
+        self.primary.len() >= MAXIMUM_LENGTH && self.alternate.len() >= MAXIMUM_LENGTH
+    } // fn
+
+    /// Adds a letter to the primary code.
+    fn add_primary(&mut self, letter: char) {
+        if self.primary.len() < MAXIMUM_LENGTH {
+            self.primary.push(letter);
+        } // if
+    } // fn
+
+    /// Adds a letter to the alternate code.
+    fn add_alternate(&mut self, letter: char) {
+        if self.alternate.len() < MAXIMUM_LENGTH {
+            self.alternate.push(letter);
+        } // if
+    } // fn
+
+    /// Adds a letter to both codes.
+    fn add(&mut self, letter: char) {
+        self.add_each(letter, letter);
+    } // fn
+
+    /// Adds a letter to each code.
+    fn add_each(&mut self, primary: char, alternate: char) {
+        self.add_primary(primary);
+        self.add_alternate(alternate);
+    } // fn
+
+    /// Adds letters to both codes.
+    fn add_str(&mut self, letters: &str) {
+        self.add_each_str(letters, letters);
+    } // fn
+
+    /// Adds letters to each code.
+    fn add_each_str(&mut self, primary: &str, alternate: &str) {
+        primary.chars().for_each(|letter| self.add_primary(letter));
+        alternate.chars().for_each(|letter| self.add_alternate(letter));
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A keyword being coded.
+struct Word<'w> {
+    chars: &'w [char],
+    /// Whether the keyword looks Slavic or Germanic, which changes how some
+    /// letters are pronounced.
+    slavo_germanic: bool,
+} // Word
+
+// -----------------------------------------------------------------------------
+
+impl<'w> Word<'w> {
+    fn new(chars: &'w [char]) -> Self {
+        let mut word = Self { chars, slavo_germanic: false };
+        word.slavo_germanic = chars.iter().any(|letter| matches!(letter, 'W' | 'K'))
+            || (0..chars.len()).any(|index| word.contains(index, 2, &["CZ"]));
+        word
+    } // fn
+
+    const fn len(&self) -> usize {
+        self.chars.len()
+    } // fn
+
+    /// Returns the letter at `index`, or `\0` if it's out of bounds.
+    fn char_at(&self, index: usize) -> char {
+        self.chars.get(index).copied().unwrap_or('\0')
+    } // fn
+
+    /// Returns whether the `length` letters starting at `start` are one of
+    /// `criteria`.
+    fn contains(&self, start: usize, length: usize, criteria: &[&str]) -> bool {
+        self.chars.get(start..start + length).is_some_and(|letters| {
+            criteria
+                .iter()
+                .any(|criterion| criterion.chars().eq(letters.iter().copied()))
+        }) // is_some_and
+    } // fn
+
+    /// Returns whether the `length` letters starting `offset` letters before
+    /// `index` are one of `criteria`.
+    fn contains_before(&self, index: usize, offset: usize, length: usize, criteria: &[&str]) -> bool {
+        index
+            .checked_sub(offset)
+            .is_some_and(|start| self.contains(start, length, criteria))
+    } // fn
+
+    /// Returns the index after `letter`, skipping a doubled `letter`.
+    fn skip_double(&self, index: usize, letter: char) -> usize {
+        if self.char_at(index + 1) == letter { index + 2 } else { index + 1 }
+    } // fn
+
+    /// Returns whether the keyword starts with a silent letter.
+    fn is_silent_start(&self) -> bool {
+        self.contains(0, 2, &["GN", "KN", "PN", "WR", "PS"])
+    } // fn
+
+    fn handle_c(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.condition_c0(index) {
+            codes.add('K');
+            index + 2
+        } else if index == 0 && self.contains(index, 6, &["CAESAR"]) {
+            codes.add('S');
+            index + 2
+        } else if self.contains(index, 2, &["CH"]) {
+            self.handle_ch(codes, index)
+        } else if self.contains(index, 2, &["CZ"]) && !self.contains_before(index, 2, 4, &["WICZ"]) {
+            codes.add_each('S', 'X');
+            index + 2
+        } else if self.contains(index + 1, 3, &["CIA"]) {
+            codes.add('X');
+            index + 3
+        } else if self.contains(index, 2, &["CC"]) && !(index == 1 && self.char_at(0) == 'M') {
+            self.handle_cc(codes, index)
+        } else if self.contains(index, 2, &["CK", "CG", "CQ"]) {
+            codes.add('K');
+            index + 2
+        } else if self.contains(index, 2, &["CI", "CE", "CY"]) {
+            if self.contains(index, 3, &["CIO", "CIE", "CIA"]) {
+                codes.add_each('S', 'X');
+            } else {
+                codes.add('S');
+            } // if
+            index + 2
+        } else {
+            codes.add('K');
+            if self.contains(index + 1, 2, &[" C", " Q", " G"]) {
+                index + 3
+            } else if self.contains(index + 1, 1, &["C", "K", "Q"])
+                && !self.contains(index + 1, 2, &["CE", "CI"])
+            {
+                index + 2
+            } else {
+                index + 1
+            } // if
+        } // if
+    } // fn
+
+    fn handle_cc(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.contains(index + 2, 1, &["I", "E", "H"]) && !self.contains(index + 2, 2, &["HU"]) {
+            if (index == 1 && self.char_at(0) == 'A')
+                || self.contains_before(index, 1, 5, &["UCCEE", "UCCES"])
+            {
+                codes.add_str("KS");
+            } else {
+                codes.add('X');
+            } // if
+            index + 3
+        } else {
+            codes.add('K');
+            index + 2
+        } // if
+    } // fn
+
+    fn handle_ch(&self, codes: &mut Codes, index: usize) -> usize {
+        if index > 0 && self.contains(index, 4, &["CHAE"]) {
+            codes.add_each('K', 'X');
+        } else if self.condition_ch0(index) || self.condition_ch1(index) {
+            codes.add('K');
+        } else if index == 0 {
+            codes.add('X');
+        } else if self.contains(0, 2, &["MC"]) {
+            codes.add('K');
+        } else {
+            codes.add_each('X', 'K');
+        } // if
+        index + 2
+    } // fn
+
+    fn handle_d(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.contains(index, 2, &["DG"]) {
+            if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+                codes.add('J');
+                index + 3
+            } else {
+                codes.add_str("TK");
+                index + 2
+            } // if
+        } else if self.contains(index, 2, &["DT", "DD"]) {
+            codes.add('T');
+            index + 2
+        } else {
+            codes.add('T');
+            index + 1
+        } // if
+    } // fn
+
+    fn handle_g(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.char_at(index + 1) == 'H' {
+            self.handle_gh(codes, index)
+        } else if self.char_at(index + 1) == 'N' {
+            if index == 1 && is_vowel(self.char_at(0)) && !self.slavo_germanic {
+                codes.add_each_str("KN", "N");
+            } else if !self.contains(index + 2, 2, &["EY"])
+                && self.char_at(index + 1) != 'Y'
+                && !self.slavo_germanic
+            {
+                codes.add_each_str("N", "KN");
+            } else {
+                codes.add_str("KN");
+            } // if
+            index + 2
+        } else if self.contains(index + 1, 2, &["LI"]) && !self.slavo_germanic {
+            codes.add_each_str("KL", "L");
+            index + 2
+        } else if (index == 0
+            && (self.char_at(index + 1) == 'Y'
+                || self.contains(
+                    index + 1,
+                    2,
+                    &["ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER"],
+                )))
+            || ((self.contains(index + 1, 2, &["ER"]) || self.char_at(index + 1) == 'Y')
+                && !self.contains(0, 6, &["DANGER", "RANGER", "MANGER"])
+                && !self.contains_before(index, 1, 1, &["E", "I"])
+                && !self.contains_before(index, 1, 3, &["RGY", "OGY"]))
+        {
+            codes.add_each('K', 'J');
+            index + 2
+        } else if self.contains(index + 1, 1, &["E", "I", "Y"])
+            || self.contains_before(index, 1, 4, &["AGGI", "OGGI"])
+        {
+            if self.contains(0, 4, &["VAN ", "VON "])
+                || self.contains(0, 3, &["SCH"])
+                || self.contains(index + 1, 2, &["ET"])
+            {
+                codes.add('K');
+            } else if self.contains(index + 1, 3, &["IER"]) {
+                codes.add('J');
+            } else {
+                codes.add_each('J', 'K');
+            } // if
+            index + 2
+        } else if self.char_at(index + 1) == 'G' {
+            codes.add('K');
+            index + 2
+        } else {
+            codes.add('K');
+            index + 1
+        } // if
+    } // fn
+
+    fn handle_gh(&self, codes: &mut Codes, index: usize) -> usize {
+        if index > 0 && !is_vowel(self.char_at(index - 1)) {
+            codes.add('K');
+        } else if index == 0 {
+            codes.add(if self.char_at(index + 2) == 'I' { 'J' } else { 'K' });
+        } else if self.contains_before(index, 2, 1, &["B", "H", "D"])
+            || self.contains_before(index, 3, 1, &["B", "H", "D"])
+            || self.contains_before(index, 4, 1, &["B", "H"])
+        {
+            // Silent, as in `BOUGH` and `BROUGHTON`.
+        } else if index > 2
+            && self.char_at(index - 1) == 'U'
+            && self.contains(index - 3, 1, &["C", "G", "L", "R", "T"])
+        {
+            codes.add('F');
+        } else if self.char_at(index - 1) != 'I' {
+            codes.add('K');
+        } // if
+        index + 2
+    } // fn
+
+    fn handle_h(&self, codes: &mut Codes, index: usize) -> usize {
+        if (index == 0 || is_vowel(self.char_at(index - 1))) && is_vowel(self.char_at(index + 1)) {
+            codes.add('H');
+            index + 2
+        } else {
+            index + 1
+        } // if
+    } // fn
+
+    fn handle_j(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.contains(index, 4, &["JOSE"]) || self.contains(0, 4, &["SAN "]) {
+            if (index == 0 && self.char_at(index + 4) == ' ')
+                || self.len() == 4
+                || self.contains(0, 4, &["SAN "])
+            {
+                codes.add('H');
+            } else {
+                codes.add_each('J', 'H');
+            } // if
+            return index + 1;
+        } // if
+
+        if index == 0 {
+            codes.add_each('J', 'A');
+        } else if is_vowel(self.char_at(index - 1))
+            && !self.slavo_germanic
+            && matches!(self.char_at(index + 1), 'A' | 'O')
+        {
+            codes.add_each('J', 'H');
+        } else if index + 1 == self.len() {
+            codes.add_each('J', ' ');
+        } else if !self.contains(index + 1, 1, &["L", "T", "K", "S", "N", "M", "B", "Z"])
+            && !self.contains_before(index, 1, 1, &["S", "K", "L"])
+        {
+            codes.add('J');
+        } // if
+
+        self.skip_double(index, 'J')
+    } // fn
+
+    fn handle_l(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.char_at(index + 1) == 'L' {
+            if self.condition_l0(index) {
+                codes.add_primary('L');
+            } else {
+                codes.add('L');
+            } // if
+            index + 2
+        } else {
+            codes.add('L');
+            index + 1
+        } // if
+    } // fn
+
+    fn handle_p(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.char_at(index + 1) == 'H' {
+            codes.add('F');
+            index + 2
+        } else {
+            codes.add('P');
+            if self.contains(index + 1, 1, &["P", "B"]) { index + 2 } else { index + 1 }
+        } // if
+    } // fn
+
+    fn handle_r(&self, codes: &mut Codes, index: usize) -> usize {
+        // A final `R` is silent in French names, as in `ROGIER`:
+        if index + 1 == self.len()
+            && !self.slavo_germanic
+            && self.contains_before(index, 2, 2, &["IE"])
+            && !self.contains_before(index, 4, 2, &["ME", "MA"])
+        {
+            codes.add_alternate('R');
+        } else {
+            codes.add('R');
+        } // if
+        self.skip_double(index, 'R')
+    } // fn
+
+    fn handle_s(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.contains_before(index, 1, 3, &["ISL", "YSL"]) {
+            index + 1
+        } else if index == 0 && self.contains(index, 5, &["SUGAR"]) {
+            codes.add_each('X', 'S');
+            index + 1
+        } else if self.contains(index, 2, &["SH"]) {
+            if self.contains(index + 1, 4, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+                codes.add('S');
+            } else {
+                codes.add('X');
+            } // if
+            index + 2
+        } else if self.contains(index, 3, &["SIO", "SIA"]) || self.contains(index, 4, &["SIAN"]) {
+            if self.slavo_germanic {
+                codes.add('S');
+            } else {
+                codes.add_each('S', 'X');
+            } // if
+            index + 3
+        } else if (index == 0 && self.contains(index + 1, 1, &["M", "N", "L", "W"]))
+            || self.contains(index + 1, 1, &["Z"])
+        {
+            codes.add_each('S', 'X');
+            self.skip_double(index, 'Z')
+        } else if self.contains(index, 2, &["SC"]) {
+            self.handle_sc(codes, index)
+        } else {
+            // A final `S` is silent in French names, as in `ARTOIS`:
+            if index + 1 == self.len() && self.contains_before(index, 2, 2, &["AI", "OI"]) {
+                codes.add_alternate('S');
+            } else {
+                codes.add('S');
+            } // if
+            if self.contains(index + 1, 1, &["S", "Z"]) { index + 2 } else { index + 1 }
+        } // if
+    } // fn
+
+    fn handle_sc(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.char_at(index + 2) == 'H' {
+            if self.contains(index + 3, 2, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                if self.contains(index + 3, 2, &["ER", "EN"]) {
+                    codes.add_each_str("X", "SK");
+                } else {
+                    codes.add_str("SK");
+                } // if
+            } else if index == 0 && !is_vowel(self.char_at(3)) && self.char_at(3) != 'W' {
+                codes.add_each('X', 'S');
+            } else {
+                codes.add('X');
+            } // if
+        } else if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+            codes.add('S');
+        } else {
+            codes.add_str("SK");
+        } // if
+        index + 3
+    } // fn
+
+    fn handle_t(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.contains(index, 4, &["TION"]) || self.contains(index, 3, &["TIA", "TCH"]) {
+            codes.add('X');
+            index + 3
+        } else if self.contains(index, 2, &["TH"]) || self.contains(index, 3, &["TTH"]) {
+            if self.contains(index + 2, 2, &["OM", "AM"])
+                || self.contains(0, 4, &["VAN ", "VON "])
+                || self.contains(0, 3, &["SCH"])
+            {
+                codes.add('T');
+            } else {
+                codes.add_each('0', 'T');
+            } // if
+            index + 2
+        } else {
+            codes.add('T');
+            if self.contains(index + 1, 1, &["T", "D"]) { index + 2 } else { index + 1 }
+        } // if
+    } // fn
+
+    fn handle_w(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.contains(index, 2, &["WR"]) {
+            codes.add('R');
+            index + 2
+        } else if index == 0 && (is_vowel(self.char_at(index + 1)) || self.contains(index, 2, &["WH"])) {
+            if is_vowel(self.char_at(index + 1)) {
+                codes.add_each('A', 'F');
+            } else {
+                codes.add('A');
+            } // if
+            index + 1
+        } else if (index + 1 == self.len() && index > 0 && is_vowel(self.char_at(index - 1)))
+            || self.contains_before(index, 1, 5, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+            || self.contains(0, 3, &["SCH"])
+        {
+            codes.add_alternate('F');
+            index + 1
+        } else if self.contains(index, 4, &["WICZ", "WITZ"]) {
+            codes.add_each_str("TS", "FX");
+            index + 4
+        } else {
+            index + 1
+        } // if
+    } // fn
+
+    fn handle_x(&self, codes: &mut Codes, index: usize) -> usize {
+        if index == 0 {
+            codes.add('S');
+            return index + 1;
+        } // if
+
+        // A final `X` is silent in French names, as in `BREAUX`:
+        if !(index + 1 == self.len()
+            && (self.contains_before(index, 3, 3, &["IAU", "EAU"])
+                || self.contains_before(index, 2, 2, &["AU", "OU"])))
+        {
+            codes.add_str("KS");
+        } // if
+
+        if self.contains(index + 1, 1, &["C", "X"]) { index + 2 } else { index + 1 }
+    } // fn
+
+    fn handle_z(&self, codes: &mut Codes, index: usize) -> usize {
+        if self.char_at(index + 1) == 'H' {
+            codes.add('J');
+            return index + 2;
+        } // if
+
+        if self.contains(index + 1, 2, &["ZO", "ZI", "ZA"])
+            || (self.slavo_germanic && index > 0 && self.char_at(index - 1) != 'T')
+        {
+            codes.add_each_str("S", "TS");
+        } else {
+            codes.add('S');
+        } // if
+
+        self.skip_double(index, 'Z')
+    } // fn
+
+    /// Whether a `C` is a `K`, as in `BACHER` and `MACHER`.
+    fn condition_c0(&self, index: usize) -> bool {
+        if self.contains(index, 4, &["CHIA"]) {
+            true
+        } else if index <= 1
+            || is_vowel(self.char_at(index - 2))
+            || !self.contains(index - 1, 3, &["ACH"])
+        {
+            false
+        } else {
+            let letter = self.char_at(index + 2);
+            (letter != 'I' && letter != 'E') || self.contains(index - 2, 6, &["BACHER", "MACHER"])
+        } // if
+    } // fn
+
+    /// Whether a `CH` at the beginning of a Greek root is a `K`, as in
+    /// `CHARACTER`.
+    fn condition_ch0(&self, index: usize) -> bool {
+        index == 0
+            && (self.contains(index + 1, 5, &["HARAC", "HARIS"])
+                || self.contains(index + 1, 3, &["HOR", "HYM", "HIA", "HEM"]))
+            && !self.contains(0, 5, &["CHORE"])
+    } // fn
+
+    /// Whether a `CH` is a `K` in Germanic names and some Greek roots, as in
+    /// `ORCHESTRA`.
+    fn condition_ch1(&self, index: usize) -> bool {
+        self.contains(0, 4, &["VAN ", "VON "])
+            || self.contains(0, 3, &["SCH"])
+            || self.contains_before(index, 2, 6, &["ORCHES", "ARCHIT", "ORCHID"])
+            || self.contains(index + 2, 1, &["T", "S"])
+            || ((index == 0 || self.contains_before(index, 1, 1, &["A", "O", "U", "E"]))
+                && (self.contains(index + 2, 1, &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "])
+                    || index + 2 == self.len()))
+    } // fn
+
+    /// Whether a double `L` is silent in Spanish names, as in `CABRILLO`.
+    fn condition_l0(&self, index: usize) -> bool {
+        let length = self.len();
+        (index + 3 == length && self.contains_before(index, 1, 4, &["ILLO", "ILLA", "ALLE"]))
+            || ((self.contains_before(length, 2, 2, &["AS", "OS"])
+                || self.contains_before(length, 1, 1, &["A", "O"]))
+                && self.contains_before(index, 1, 4, &["ALLE"]))
+    } // fn
+
+    /// Whether an `M` is followed by a silent letter, as in `DUMB`.
+    fn condition_m0(&self, index: usize) -> bool {
+        self.char_at(index + 1) == 'M'
+            || (self.contains_before(index, 1, 3, &["UMB"])
+                && (index + 2 == self.len() || self.contains(index + 2, 2, &["ER"])))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns whether a letter is a vowel.
+const fn is_vowel(letter: char) -> bool {
+    matches!(letter, 'A' | 'E' | 'I' | 'O' | 'U' | 'Y')
+} // fn
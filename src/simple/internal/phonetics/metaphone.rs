@@ -0,0 +1,177 @@
+//! The original Metaphone phonetic algorithm by Lawrence Philips. This follows
+//! the Apache Commons Codec implementation, with codes of up to four
+//! characters. `0` stands for the `TH` sound.
+
+/// The maximum length of the codes.
+const MAXIMUM_LENGTH: usize = 4;
+
+/// Vowels that soften the consonant before them.
+const FRONT_VOWELS: &[char] = &['E', 'I', 'Y'];
+
+/// Letters that make a following `H` silent.
+const VARSON: &[char] = &['C', 'S', 'P', 'T', 'G'];
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the Metaphone code for a keyword. The keyword is expected to be in
+/// upper-case.
+pub fn metaphone(keyword: &[char]) -> String {
+    match keyword {
+        [] => return String::new(),
+        [letter] => return letter.to_string(),
+        _ => {}
+    } // match
+
+    // Some letters at the beginning of a keyword are silent, or sound like
+    // another letter:
+    let word: Vec<char> = match (keyword[0], keyword[1]) {
+        ('K' | 'G' | 'P', 'N') | ('A', 'E') | ('W', 'R') => keyword[1..].to_vec(),
+        ('W', 'H') => std::iter::once('W').chain(keyword[2..].iter().copied()).collect(),
+        ('X', _) => std::iter::once('S').chain(keyword[1..].iter().copied()).collect(),
+        _ => keyword.to_vec(),
+    }; // match
+
+    let word = Word(&word);
+    let length = word.0.len();
+    let mut code = String::with_capacity(MAXIMUM_LENGTH);
+    let mut index = 0;
+
+    while code.len() < MAXIMUM_LENGTH && index < length {
+        let letter = word.0[index];
+
+        // Double letters are coded once, except for `C`:
+        if letter != 'C' && word.is_previous(index, letter) {
+            index += 1;
+            continue;
+        } // if
+
+        match letter {
+            // Vowels are only coded at the beginning of a keyword:
+            'A' | 'E' | 'I' | 'O' | 'U' if index == 0 => code.push(letter),
+
+            // `B` is silent in a final `MB`:
+            'B' if !(word.is_previous(index, 'M') && word.is_last(index)) => code.push('B'),
+
+            'C' => if word.is_previous(index, 'S') && word.is_front_vowel(index + 1) {
+                // Silent in `SCI`, `SCE`, and `SCY`.
+            } else if word.matches(index, "CIA") {
+                code.push('X');
+            } else if word.is_front_vowel(index + 1) {
+                code.push('S');
+            } else if word.is_previous(index, 'S') && word.is_next(index, 'H') {
+                code.push('K');
+            } else if word.is_next(index, 'H') {
+                code.push(if index == 0 && length >= 3 && word.is_vowel(2) { 'K' } else { 'X' });
+            } else {
+                code.push('K');
+            }, // if
+
+            // `DGE`, `DGI`, and `DGY` sound like `J`:
+            'D' => if word.is_next(index, 'G') && word.is_front_vowel(index + 2) {
+                code.push('J');
+                index += 2;
+            } else {
+                code.push('T');
+            }, // if
+
+            'G' => if word.is_next(index, 'H') && (word.is_last(index + 1) || !word.is_vowel(index + 2)) {
+                // Silent in a final `GH`, or in `GH` before a consonant.
+            } else if index > 0 && (word.matches(index, "GN") || word.matches(index, "GNED")) {
+                // Silent in `GN` and `GNED`.
+            } else if word.is_front_vowel(index + 1) && !word.is_previous(index, 'G') {
+                code.push('J');
+            } else {
+                code.push('K');
+            }, // if
+
+            // `H` is only sounded before a vowel, and not after the letters
+            // that it changes:
+            'H' if !(word.is_last(index) || index > 0 && VARSON.contains(&word.0[index - 1]))
+                && word.is_vowel(index + 1) => code.push('H'),
+
+            'F' | 'J' | 'L' | 'M' | 'N' | 'R' => code.push(letter),
+
+            // `K` is silent after `C`:
+            'K' if !word.is_previous(index, 'C') => code.push('K'),
+
+            'P' => code.push(if word.is_next(index, 'H') { 'F' } else { 'P' }),
+
+            'Q' => code.push('K'),
+
+            'S' => if word.matches(index, "SH") || word.matches(index, "SIO") || word.matches(index, "SIA") {
+                code.push('X');
+            } else {
+                code.push('S');
+            }, // if
+
+            'T' => if word.matches(index, "TIA") || word.matches(index, "TIO") {
+                code.push('X');
+            } else if word.matches(index, "TCH") {
+                // Silent in `TCH`.
+            } else if word.matches(index, "TH") {
+                code.push('0');
+            } else {
+                code.push('T');
+            }, // if
+
+            'V' => code.push('F'),
+
+            // `W` and `Y` are only sounded before a vowel:
+            'W' | 'Y' if word.is_vowel(index + 1) => code.push(letter),
+
+            'X' => code.push_str("KS"),
+
+            'Z' => code.push('S'),
+
+            // Anything else is silent, including letters whose conditions
+            // above weren't met:
+            _ => {}
+        } // match
+
+        index += 1;
+    } // while
+
+    code.truncate(MAXIMUM_LENGTH);
+    code
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// A keyword after its first letters have been adjusted.
+struct Word<'w>(&'w [char]);
+
+// -----------------------------------------------------------------------------
+
+impl Word<'_> {
+    /// Returns whether the letter at `index` is the last letter.
+    const fn is_last(&self, index: usize) -> bool {
+        index + 1 == self.0.len()
+    } // fn
+
+    /// Returns whether the letter before `index` is `letter`.
+    fn is_previous(&self, index: usize, letter: char) -> bool {
+        index > 0 && self.0.get(index - 1) == Some(&letter)
+    } // fn
+
+    /// Returns whether the letter after `index` is `letter`.
+    fn is_next(&self, index: usize, letter: char) -> bool {
+        self.0.get(index + 1) == Some(&letter)
+    } // fn
+
+    /// Returns whether the letter at `index` is a vowel.
+    fn is_vowel(&self, index: usize) -> bool {
+        self.0.get(index).is_some_and(|letter| matches!(letter, 'A' | 'E' | 'I' | 'O' | 'U'))
+    } // fn
+
+    /// Returns whether the letter at `index` is `E`, `I`, or `Y`.
+    fn is_front_vowel(&self, index: usize) -> bool {
+        self.0.get(index).is_some_and(|letter| FRONT_VOWELS.contains(letter))
+    } // fn
+
+    /// Returns whether the letters starting at `index` are `letters`.
+    fn matches(&self, index: usize, letters: &str) -> bool {
+        self.0
+            .get(index..)
+            .is_some_and(|rest| rest.iter().copied().take(letters.len()).eq(letters.chars()))
+    } // fn
+} // impl
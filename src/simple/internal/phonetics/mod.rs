@@ -0,0 +1,118 @@
+//! A phonetic index of the search index's keywords, for matching keywords that
+//! sound alike. The phonetic index is only maintained when
+//! `SearchIndexBuilder::phonetic_matching` is set.
+
+mod double_metaphone;
+mod metaphone;
+mod soundex;
+
+use crate::simple::PhoneticAlgorithm;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// The similarity score given to a keyword that sounds like the user's
+/// keyword. Phonetic codes don't measure how alike two keywords are, so this
+/// stands in for a string similarity score. It's below `1.0` so that records
+/// matched by sound are ranked below records that contain the keyword itself.
+pub const PHONETIC_SIMILARITY: f64 = 0.8;
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords that have each phonetic code.
+///
+/// For example, with `PhoneticAlgorithm::Metaphone`, both `catherine` and
+/// `kathryn` have the code `K0RN`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PhoneticIndex {
+    /// The keywords that have each phonetic code.
+    pub(crate) codes: BTreeMap<KString, BTreeSet<KString>>,
+} // PhoneticIndex
+
+// -----------------------------------------------------------------------------
+
+impl PhoneticIndex {
+    /// Makes a phonetic index for the given keywords.
+    pub(crate) fn from_keywords<'k>(
+        keywords: impl Iterator<Item = &'k KString>,
+        algorithm: &PhoneticAlgorithm,
+    ) -> Self {
+        let mut phonetic_index = Self::default();
+        keywords.for_each(|keyword| phonetic_index.insert(keyword, algorithm));
+        phonetic_index
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a keyword that has been added to the search index.
+    pub(crate) fn insert(&mut self, keyword: &KString, algorithm: &PhoneticAlgorithm) {
+        for code in codes(keyword, algorithm) {
+            self.codes
+                .entry(KString::from(code))
+                .or_default()
+                .insert(keyword.clone());
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes a keyword that has been removed from the search index.
+    pub(crate) fn remove(&mut self, keyword: &str, algorithm: &PhoneticAlgorithm) {
+        for code in codes(keyword, algorithm) {
+            let is_empty = self.codes.get_mut(code.as_str()).is_some_and(|keywords| {
+                keywords.remove(keyword);
+                keywords.is_empty()
+            }); // is_some_and
+
+            if is_empty {
+                self.codes.remove(code.as_str());
+            } // if
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that sound like the user's keyword, in
+    /// lexicographic order. The user's keyword itself is not returned.
+    pub(crate) fn keywords(
+        &self,
+        user_keyword: &str,
+        algorithm: &PhoneticAlgorithm,
+    ) -> BTreeSet<&KString> {
+        codes(user_keyword, algorithm)
+            .iter()
+            .filter_map(|code| self.codes.get(code.as_str()))
+            .flatten()
+            .filter(|keyword| keyword.as_str() != user_keyword)
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the phonetic codes of a keyword. Keywords with chars other than
+/// letters (i.e. numbers, or part numbers such as `x-100`) have no codes.
+pub fn codes(keyword: &str, algorithm: &PhoneticAlgorithm) -> Vec<String> {
+    if keyword.is_empty() || !keyword.chars().all(char::is_alphabetic) {
+        return Vec::new();
+    } // if
+
+    let letters: Vec<char> = keyword.chars().flat_map(char::to_uppercase).collect();
+
+    let codes = match algorithm {
+        PhoneticAlgorithm::Soundex => vec![soundex::soundex(&letters)],
+        PhoneticAlgorithm::Metaphone => vec![metaphone::metaphone(&letters)],
+        PhoneticAlgorithm::DoubleMetaphone => {
+            let (primary, alternate) = double_metaphone::double_metaphone(&letters);
+            if primary == alternate {
+                vec![primary]
+            } else {
+                vec![primary, alternate]
+            } // if
+        } // DoubleMetaphone
+    }; // match
+
+    codes.into_iter().filter(|code| !code.is_empty()).collect()
+} // fn
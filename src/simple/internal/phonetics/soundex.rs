@@ -0,0 +1,63 @@
+//! The American Soundex phonetic algorithm. A keyword's code is its first
+//! letter followed by three digits for the consonants that follow it.
+
+/// The length of the codes.
+const CODE_LENGTH: usize = 4;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the Soundex code for a keyword. The keyword is expected to be in
+/// upper-case. Letters outside of `A` to `Z` are ignored.
+pub fn soundex(keyword: &[char]) -> String {
+    let mut letters = keyword.iter().copied().filter(char::is_ascii_alphabetic);
+
+    let Some(first) = letters.next() else {
+        return String::new();
+    }; // let
+
+    let mut code = String::with_capacity(CODE_LENGTH);
+    code.push(first);
+
+    // Adjacent letters with the same digit are coded once. This includes the
+    // first letter, so `Pfister` is `P236`:
+    let mut previous = digit(first);
+
+    for letter in letters {
+        if code.len() == CODE_LENGTH {
+            break;
+        } // if
+
+        // `H` and `W` don't separate letters with the same digit, but vowels
+        // do:
+        if matches!(letter, 'H' | 'W') {
+            continue;
+        } // if
+
+        let digit = digit(letter);
+        if digit != '0' && digit != previous {
+            code.push(digit);
+        } // if
+        previous = digit;
+    } // for
+
+    while code.len() < CODE_LENGTH {
+        code.push('0');
+    } // while
+
+    code
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the Soundex digit for a letter. Vowels (and `Y`) are `0`.
+const fn digit(letter: char) -> char {
+    match letter {
+        'B' | 'F' | 'P' | 'V' => '1',
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => '2',
+        'D' | 'T' => '3',
+        'L' => '4',
+        'M' | 'N' => '5',
+        'R' => '6',
+        _ => '0',
+    } // match
+} // fn
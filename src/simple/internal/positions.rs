@@ -0,0 +1,263 @@
+//! Keyword positions for phrase and proximity searches. Positions are only
+//! recorded when `SearchIndexBuilder::index_positions` has been turned on.
+
+use crate::simple::{QueryNode, SearchIndex};
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// The number of positions skipped between the strings of a record. This
+/// prevents a phrase from matching across two strings (i.e. the end of a
+/// title and the beginning of a body), and keeps the strings from being
+/// "near" each other for `NEAR/n` searches with a distance below this number.
+pub const POSITION_GAP: usize = 100;
+
+// -----------------------------------------------------------------------------
+//
+/// The positions of every keyword in a record, keyed by keyword and then by
+/// record key. Positions are in ascending order.
+pub type Positions<K> = BTreeMap<KString, BTreeMap<K, Vec<usize>>>;
+
+// -----------------------------------------------------------------------------
+//
+/// A span of keyword positions in a record, from the first keyword to the
+/// last keyword (inclusive.) A single keyword is a span of one position.
+type Span = (usize, usize);
+
+// -----------------------------------------------------------------------------
+//
+/// Keyword positions for every record in the search index, and for every named
+/// field. Field positions use the same numbering as the record's positions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "K: Ord + serde::Deserialize<'de>")))]
+pub struct PositionalIndex<K> {
+    /// The positions of each keyword in each record.
+    pub(crate) keywords: Positions<K>,
+
+    /// The positions of each keyword in each record, for each named field.
+    pub(crate) fields: BTreeMap<KString, Positions<K>>,
+} // PositionalIndex
+
+// -----------------------------------------------------------------------------
+
+impl<K> Default for PositionalIndex<K> {
+    fn default() -> Self {
+        Self {
+            keywords: BTreeMap::new(),
+            fields: BTreeMap::new(),
+        } // PositionalIndex
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> PositionalIndex<K> {
+    /// Records the keyword positions for a record that is being inserted into
+    /// the search index. If `field` is set, the positions are recorded for
+    /// the named field.
+    pub(crate) fn insert<'k>(
+        &mut self,
+        field: Option<&KString>,
+        key: &K,
+        positions: impl Iterator<Item = (&'k KString, &'k Vec<usize>)>,
+    ) {
+        let keywords = match field {
+            Some(field) => self.fields.entry(field.clone()).or_default(),
+            None => &mut self.keywords,
+        }; // match
+
+        for (keyword, positions) in positions {
+            keywords
+                .entry(keyword.clone())
+                .or_default()
+                .insert(key.clone(), positions.clone());
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the keyword positions for a record that is being removed from
+    /// the search index. If `field` is set, the positions are removed from
+    /// the named field.
+    pub(crate) fn remove<'k>(
+        &mut self,
+        field: Option<&KString>,
+        key: &K,
+        keywords: impl Iterator<Item = &'k KString>,
+    ) {
+        let positions = match field {
+            Some(field) => match self.fields.get_mut(field) {
+                Some(positions) => positions,
+                None => return,
+            }, // Some
+            None => &mut self.keywords,
+        }; // match
+
+        for keyword in keywords {
+            let is_empty = positions.get_mut(keyword).is_some_and(|keys| {
+                keys.remove(key);
+                keys.is_empty()
+            }); // is_some_and
+
+            if is_empty {
+                positions.remove(keyword);
+            } // if
+        } // for
+
+        // If there are no keywords left for this field, remove the field:
+        if let Some(field) = field {
+            if self.fields.get(field).is_some_and(BTreeMap::is_empty) {
+                self.fields.remove(field);
+            } // if
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Returns whether a record satisfies the positional constraints of a
+    /// query node: phrases must appear in order, and `NEAR/n` operands must be
+    /// within _n_ keywords of each other.
+    ///
+    /// If keyword positions aren't being recorded, every record satisfies the
+    /// constraints. In that case, phrases and `NEAR/n` searches behave like
+    /// `AND` searches.
+    pub(crate) fn matches_positions(&self, query: &QueryNode, field: Option<&str>, key: &K) -> bool {
+        !self.index_positions || !self.spans(query, field, key).is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns every span of positions where the query node matches the
+    /// record. Exclusions (`NOT`) have no positions, so they have no spans.
+    fn spans(&self, query: &QueryNode, field: Option<&str>, key: &K) -> Vec<Span> {
+        // Get the positions for the field, or for the entire record:
+        let positions = match field {
+            Some(field) => match self.positional_index.fields.get(field) {
+                Some(positions) => positions,
+                None => return Vec::new(),
+            }, // Some
+            None => &self.positional_index.keywords,
+        }; // match
+
+        // Returns the positions of a keyword in the record:
+        let keyword_positions = |keyword: &str| -> &[usize] {
+            positions
+                .get(keyword)
+                .and_then(|keys| keys.get(key))
+                .map_or(&[], Vec::as_slice)
+        }; // closure
+
+        let mut spans: Vec<Span> = match query {
+            QueryNode::Keyword(keyword) => keyword_positions(keyword)
+                .iter()
+                .map(|position| (*position, *position))
+                .collect(),
+
+            // A phrase starts wherever its first keyword is followed by each
+            // of the other keywords, in order:
+            QueryNode::Phrase(keywords) => {
+                let Some((first, rest)) = keywords.split_first() else {
+                    return Vec::new();
+                }; // let
+
+                let rest: Vec<&[usize]> = rest
+                    .iter()
+                    .map(|keyword| keyword_positions(keyword))
+                    .collect();
+
+                keyword_positions(first)
+                    .iter()
+                    .filter(|start| rest
+                        .iter()
+                        .enumerate()
+                        .all(|(index, positions)| positions
+                            .binary_search(&(*start + index + 1))
+                            .is_ok()))
+                    .map(|start| (*start, start + rest.len()))
+                    .collect()
+            } // Phrase
+
+            QueryNode::Prefix(prefix) => positions
+                .range(KString::from_ref(prefix)..)
+                .take_while(|(keyword, _keys)| keyword.starts_with(prefix.as_str()))
+                .filter_map(|(_keyword, keys)| keys.get(key))
+                .flatten()
+                .map(|position| (*position, *position))
+                .collect(),
+
+            #[cfg(feature = "regex")]
+            QueryNode::Regex(_) => self.pattern_spans(query, positions, key),
+            QueryNode::Wildcard(_) => self.pattern_spans(query, positions, key),
+
+            QueryNode::Or(nodes) => nodes
+                .iter()
+                .flat_map(|node| self.spans(node, field, key))
+                .collect(),
+
+            QueryNode::Field(field, node) => self.spans(node, Some(field), key),
+
+            // Two operands are near each other if no more than `distance`
+            // keywords are between them. Overlapping operands aren't near
+            // each other, so `dawn NEAR/2 dawn` needs two occurrences:
+            QueryNode::Near(left, right, distance) => {
+                let right = self.spans(right, field, key);
+                self.spans(left, field, key)
+                    .into_iter()
+                    .flat_map(|left| right.iter().filter_map(move |right| {
+                        let between = if right.0 > left.1 {
+                            right.0 - left.1 - 1
+                        } else if left.0 > right.1 {
+                            left.0 - right.1 - 1
+                        } else {
+                            return None;
+                        }; // if
+                        (between <= *distance)
+                            .then_some((left.0.min(right.0), left.1.max(right.1)))
+                    })) // flat_map
+                    .collect()
+            } // Near
+
+            // A group spans from the first to the last of its operands, for
+            // each combination of one span per operand. Exclusions have no
+            // positions, and they've already been checked against the record:
+            QueryNode::And(nodes) => nodes
+                .iter()
+                .filter(|node| !matches!(node, QueryNode::Not(_)))
+                .map(|node| self.spans(node, field, key))
+                .reduce(|spans, operand| spans
+                    .iter()
+                    .flat_map(|left| operand
+                        .iter()
+                        .map(move |right| (left.0.min(right.0), left.1.max(right.1))))
+                    .collect::<BTreeSet<Span>>()
+                    .into_iter()
+                    .collect())
+                .unwrap_or_default(),
+
+            QueryNode::Not(_) => Vec::new(),
+        }; // match
+
+        spans.sort_unstable();
+        spans.dedup();
+        spans
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the positions of every keyword in the record that matches a
+    /// `Wildcard` or `Regex` node.
+    fn pattern_spans(&self, query: &QueryNode, positions: &Positions<K>, key: &K) -> Vec<Span> {
+        self.query_pattern(query)
+            .map(|pattern| pattern
+                .keywords(positions, self.maximum_pattern_expansions)
+                .filter_map(|(_keyword, keys)| keys.get(key))
+                .flatten()
+                .map(|position| (*position, *position))
+                .collect())
+            .unwrap_or_default()
+    } // fn
+} // impl
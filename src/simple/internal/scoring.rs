@@ -0,0 +1,223 @@
+//! Relevance scoring for search results. See the `ScoringType` enum for more
+//! information.
+
+use crate::simple::{internal::field_weights::WeightedFields, ScoringType, SearchIndex};
+use kstring::KString;
+use std::collections::BTreeMap;
+
+// -----------------------------------------------------------------------------
+//
+/// Per-record statistics used by the `Bm25` and `TfIdf` scoring types.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "K: Ord + serde::Deserialize<'de>")))]
+pub struct ScoringStatistics<K> {
+    /// The number of keywords in each record (the document length.)
+    pub(crate) lengths: BTreeMap<K, usize>,
+
+    /// The sum of all record lengths. Used to calculate the average record
+    /// length.
+    pub(crate) total_length: usize,
+
+    /// The number of times a keyword appears in a record (the term
+    /// frequency.) To save memory, only keywords that appear more than once
+    /// in a record are stored. Any other keyword has a frequency of `1`.
+    pub(crate) frequencies: BTreeMap<KString, BTreeMap<K, usize>>,
+
+    /// The number of records that contain each keyword (the document
+    /// frequency.) This can't be taken from the search index's keys, since
+    /// they're capped by `maximum_keys_per_keyword`.
+    pub(crate) document_frequencies: BTreeMap<KString, usize>,
+} // ScoringStatistics
+
+// -----------------------------------------------------------------------------
+
+impl<K> Default for ScoringStatistics<K> {
+    fn default() -> Self {
+        Self {
+            lengths: BTreeMap::new(),
+            total_length: 0,
+            frequencies: BTreeMap::new(),
+            document_frequencies: BTreeMap::new(),
+        } // ScoringStatistics
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> ScoringStatistics<K> {
+    /// Records the statistics for a record that is being inserted into the
+    /// search index.
+    ///
+    /// A key may be inserted more than once, with different values. The
+    /// `has_keyword` closure returns whether the record already has a keyword
+    /// from an earlier value. Those keywords aren't counted again in the
+    /// document frequencies. Instead, their term frequencies and the record's
+    /// length are added to.
+    pub(crate) fn insert<'k>(
+        &mut self,
+        key: &K,
+        frequencies: impl Iterator<Item = (&'k KString, &'k usize)>,
+        has_keyword: impl Fn(&KString) -> bool,
+    ) {
+        let mut length: usize = 0;
+
+        for (keyword, frequency) in frequencies {
+            length += frequency;
+
+            let frequency = if has_keyword(keyword) {
+                // Keywords that aren't stored have a frequency of `1`:
+                self.frequencies
+                    .get(keyword)
+                    .and_then(|keys| keys.get(key))
+                    .map_or(1, |previous| *previous) + frequency
+            } else {
+                *self.document_frequencies.entry(keyword.clone()).or_default() += 1;
+                *frequency
+            }; // if
+
+            if frequency > 1 {
+                self.frequencies
+                    .entry(keyword.clone())
+                    .or_default()
+                    .insert(key.clone(), frequency);
+            } // if
+        } // for
+
+        *self.lengths.entry(key.clone()).or_default() += length;
+        self.total_length += length;
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the statistics for a record that is being removed from the
+    /// search index. The record loses the given keywords, and their term
+    /// frequencies are taken from its length. Once the record has no length
+    /// left, it's removed from the statistics.
+    pub(crate) fn remove<'k>(
+        &mut self,
+        key: &K,
+        keywords: impl Iterator<Item = &'k KString>,
+    ) {
+        let mut removed: usize = 0;
+
+        for keyword in keywords {
+            let mut frequency: usize = 1;
+
+            let is_empty = self.frequencies.get_mut(keyword).is_some_and(|keys| {
+                if let Some(previous) = keys.remove(key) {
+                    frequency = previous;
+                } // if
+                keys.is_empty()
+            }); // is_some_and
+
+            if is_empty {
+                self.frequencies.remove(keyword);
+            } // if
+
+            removed += frequency;
+
+            let is_zero = self.document_frequencies.get_mut(keyword).is_some_and(|records| {
+                *records = records.saturating_sub(1);
+                *records == 0
+            }); // is_some_and
+
+            if is_zero {
+                self.document_frequencies.remove(keyword);
+            } // if
+        } // for
+
+        if let Some(length) = self.lengths.get_mut(key) {
+            let removed = removed.min(*length);
+            *length -= removed;
+            self.total_length -= removed;
+
+            if *length == 0 {
+                self.lengths.remove(key);
+            } // if
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Returns whether relevance scoring needs anything more than the number
+    /// of keyword hits. If so, search results are ranked by their scores.
+    pub(crate) fn is_scored(&self, weighted_fields: Option<&WeightedFields<'_, K>>) -> bool {
+        weighted_fields.is_some() || self.scoring_type != ScoringType::HitCount
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the relevance score of a keyword for a record, according to
+    /// the search index's `ScoringType` and field weights. A record's score
+    /// for a search is the sum of its keyword scores.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn keyword_score(
+        &self,
+        weighted_fields: Option<&WeightedFields<'_, K>>,
+        keyword: &str,
+        key: &K,
+    ) -> f64 {
+        let weight = Self::keyword_weight(weighted_fields, keyword, key);
+
+        if self.scoring_type == ScoringType::HitCount {
+            return weight;
+        } // if
+
+        let statistics = &self.scoring_statistics;
+
+        // The number of records in the search index:
+        let records = statistics.lengths.len().max(1) as f64;
+
+        // The number of records that contain the keyword. Records that were
+        // inserted before the scoring type was set have no statistics, so the
+        // keyword's keys are counted instead:
+        let document_frequency = statistics.document_frequencies
+            .get(keyword)
+            .copied()
+            .unwrap_or_else(|| self.b_tree_map.get(keyword).map_or(0, std::collections::BTreeSet::len))
+            as f64;
+
+        // The number of times the keyword appears in the record:
+        let term_frequency = statistics.frequencies
+            .get(keyword)
+            .and_then(|keys| keys.get(key))
+            .copied()
+            .unwrap_or(1) as f64;
+
+        let score = match self.scoring_type {
+            ScoringType::Bm25 => {
+                let inverse_document_frequency =
+                    ((records - document_frequency + 0.5) / (document_frequency + 0.5)).ln_1p();
+
+                // Records without statistics are treated as average length:
+                let average_length = statistics.total_length as f64 / records;
+                let length = statistics.lengths
+                    .get(key)
+                    .map_or(average_length, |length| *length as f64);
+                let length_ratio = if average_length > 0.0 {
+                    length / average_length
+                } else {
+                    1.0
+                }; // if
+
+                inverse_document_frequency * term_frequency * (self.bm25_k1 + 1.0)
+                    / self.bm25_k1.mul_add(
+                        self.bm25_b.mul_add(length_ratio, 1.0 - self.bm25_b),
+                        term_frequency,
+                    )
+            } // Bm25
+            ScoringType::TfIdf => {
+                let inverse_document_frequency =
+                    (records / document_frequency.max(1.0)).ln_1p();
+
+                (1.0 + term_frequency.ln()) * inverse_document_frequency
+            } // TfIdf
+            ScoringType::HitCount => 1.0,
+        }; // match
+
+        weight * score
+    } // fn
+} // impl
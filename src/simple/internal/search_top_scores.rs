@@ -21,7 +21,7 @@ use rustc_hash::FxHashMap as HashMap;
 use std::collections::HashMap;
 
 // Static dependencies:
-use std::hash::Hash;
+use std::{cmp::Ordering, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -32,13 +32,13 @@ use std::hash::Hash;
 /// and a better match is found. Used to efficiently collect the top-k search
 /// results without storing all candidates.
 #[derive(Debug, Default)]
-pub struct SearchTopScores<'a, K: Hash + Ord> {
+pub struct SearchTopScores<'a, K: Hash + Ord, S: PartialOrd> {
     /// The top _n_ scores, mapping keys to their scores.
-    pub(crate) top: HashMap<&'a K, usize>,
+    pub(crate) top: HashMap<&'a K, S>,
 
     /// The lowest score currently in the top collection. Used to quickly
     /// determine if a new score qualifies for insertion without scanning.
-    pub(crate) bottom: Option<(&'a K, usize)>,
+    pub(crate) bottom: Option<(&'a K, S)>,
 
     /// Maximum number of top scores to retain.
     pub(crate) capacity: usize,
@@ -46,7 +46,7 @@ pub struct SearchTopScores<'a, K: Hash + Ord> {
 
 // -----------------------------------------------------------------------------
 
-impl<'a, K: Hash + Ord> crate::simple::internal::SearchTopScores<'a, K> {
+impl<'a, K: Hash + Ord, S: Clone + PartialOrd> crate::simple::internal::SearchTopScores<'a, K, S> {
     /// Creates a new top scores tracker with the specified capacity.
     ///
     /// The capacity determines how many top-scoring keys to retain. For
@@ -71,7 +71,7 @@ impl<'a, K: Hash + Ord> crate::simple::internal::SearchTopScores<'a, K> {
     /// * If at capacity, the entry is only inserted if its score exceeds the
     ///   current minimum, evicting the lowest-scoring entry in the process.
     ///   Scores that don't beat the minimum are silently ignored.
-    pub(crate) fn insert(&mut self, key: &'a K, score: usize) {
+    pub(crate) fn insert(&mut self, key: &'a K, score: S) {
         let below_threshold = self.top.len() >= self.capacity && self
             .bottom
             .as_ref()
@@ -85,7 +85,7 @@ impl<'a, K: Hash + Ord> crate::simple::internal::SearchTopScores<'a, K> {
             }
 
             // Insert the new entry:
-            self.top.insert(key, score);
+            self.top.insert(key, score.clone());
 
             // Update bottom tracking. If this is the new minimum (or we have no
             // bottom yet), record it:
@@ -115,8 +115,10 @@ impl<'a, K: Hash + Ord> crate::simple::internal::SearchTopScores<'a, K> {
         self.bottom = self
             .top
             .iter()
-            .min_by(|(_, a_score), (_, b_score)| a_score.cmp(b_score))
-            .map(|(key, score)| (*key, *score));
+            .min_by(|(_, a_score), (_, b_score)| {
+                a_score.partial_cmp(b_score).unwrap_or(Ordering::Equal)
+            })
+            .map(|(key, score)| (*key, score.clone()));
     }
 
     /// Consumes the tracker and returns results in ranked order.
@@ -124,7 +126,7 @@ impl<'a, K: Hash + Ord> crate::simple::internal::SearchTopScores<'a, K> {
     /// Returns an iterator yielding key-score pairs, ordered by descending
     /// score. Keys with equal scores are ordered by ascending key value for
     /// deterministic output.
-    pub(crate) fn results(self) -> impl Iterator<Item = (&'a K, usize)> {
+    pub(crate) fn results(self) -> impl Iterator<Item = (&'a K, S)> {
         // Collect into a Vec for sorting. A BTreeMap would avoid this but
         // would require a compound key of (score, K) which complicates the API:
         let mut vec: Vec<_> = self.top.into_iter().collect();
@@ -132,7 +134,10 @@ impl<'a, K: Hash + Ord> crate::simple::internal::SearchTopScores<'a, K> {
         // Single sort with composite comparator: descending score, then
         // ascending key for deterministic tie-breaking:
         vec.sort_unstable_by(|(a_key, a_score), (b_key, b_score)| {
-            b_score.cmp(a_score).then_with(|| a_key.cmp(b_key))
+            b_score
+                .partial_cmp(a_score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a_key.cmp(b_key))
         });
 
         vec.into_iter()
@@ -1,5 +1,20 @@
+// Conditionally select hash set type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashSet<T> = std::collections::HashSet<T, gxhash::GxBuildHasher>;
+
+#[cfg(feature = "ahash")]
+use ahash::HashSet;
+
+#[cfg(feature = "rustc-hash")]
+use rustc_hash::FxHashSet as HashSet;
+
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash"), not(feature = "rustc-hash")))]
+use std::collections::HashSet;
+
+// Static dependencies:
 use crate::simple::search_index::SearchIndex;
 use kstring::KString;
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 //
@@ -18,25 +33,20 @@ pub enum SplitContext {
 
 // -----------------------------------------------------------------------------
 //
-/// Function will check if the provided keyword is in the list of excluded
+/// Function will check if the provided keyword is in the set of excluded
 /// keywords. If it is, function will return `true`. If there are no excluded
 /// keywords, function will always return `false`.
 #[inline]
-pub fn exclude_keyword(keyword: &str, exclude_keywords: Option<&Vec<KString>>) -> bool {
-    // Check to see if there's any keywords in the exclusion list:
-    exclude_keywords
-        .as_ref()
-        .is_some_and(|exclude_keywords| exclude_keywords
-            .iter()
-            .any(|excluded| excluded.as_str() == keyword)
-        ) // is_some_and
+pub fn exclude_keyword(keyword: &str, exclude_keywords: Option<&HashSet<KString>>) -> bool {
+    // Check to see if the keyword is in the exclusion set:
+    exclude_keywords.is_some_and(|exclude_keywords| exclude_keywords.contains(keyword))
 } // fn
 
 // -----------------------------------------------------------------------------
 
 #[test]
 fn test_exclude_keyword() {
-    let excluded_keywords: Option<Vec<KString>> = Some(vec![
+    let excluded_keywords: Option<HashSet<KString>> = Some([
         "awake".into(),
         "arise".into(),
         "or".into(),
@@ -44,7 +54,7 @@ fn test_exclude_keyword() {
         "for".into(),
         "ever".into(),
         "fall’n".into(),
-    ]); // vec!
+    ].into_iter().collect()); // Some
 
     assert!(exclude_keyword("arise", excluded_keywords.as_ref()));
 
@@ -54,8 +64,8 @@ fn test_exclude_keyword() {
 // -----------------------------------------------------------------------------
 
 impl<K: Ord> SearchIndex<K> {
-    /// An associated helper method that splits a `&str` into keywords using a
-    /// split pattern (`Vec<char>`).
+    /// An associated helper method that splits a `&str` into keywords using the
+    /// search index's `Tokenizer`, or its split pattern (`Vec<char>`).
     ///
     /// This method will also perform case conversion if necessary, filter-out
     /// keywords that don't meet the defined length restrictions, and remove
@@ -66,35 +76,57 @@ impl<K: Ord> SearchIndex<K> {
         string: &str,
         context: &SplitContext
     ) -> Vec<KString> {
+        // Only keep a keyword if it's longer than the minimum length, shorter
+        // than the maximum length, and not in the exclusion list:
+        let keep_keyword = |keyword: &str| {
+            let chars = keyword.chars().count();
+
+            chars >= self.minimum_keyword_length
+                && chars <= self.maximum_keyword_length
+                && !exclude_keyword(keyword, self.exclude_keywords.as_ref())
+        }; // keep_keyword
+
         // If case sensitivity set, leave case intact. Otherwise, normalize the
         // entire string to lower case:
-        let string = KString::from(self.normalize(string).into_owned());
+        let normalized = KString::from(self.normalize(string).into_owned());
 
         // Split the the string into keywords:
-        let mut keywords: Vec<KString> = self.split_pattern
-            .as_ref()
-            .map(|split_pattern| string
+        let mut keywords: Vec<KString> = match (&self.tokenizer, &self.split_pattern) {
+            // If a tokenizer has been set, split the string before it's
+            // normalized so that the tokenizer can make use of case. Each
+            // token is normalized afterwards:
+            (Some(tokenizer), _) => tokenizer
+                .tokenize(string)
+                .iter()
+                .map(|token| Cow::Owned(self.normalize(token).into_owned()))
+                .filter_map(|keyword| self.filter_keyword(keyword))
+                .filter(|keyword| keep_keyword(keyword))
+                .map(|keyword| KString::from(keyword.into_owned()))
+                .collect(),
+
+            (None, Some(split_pattern)) => normalized
                 // Split the `KString` into smaller strings / keywords on
                 // specified characters:
                 .split(split_pattern.as_slice())
-                // Only keep the keyword if it's longer than the minimum length
-                // and shorter than the maximum length:
-                .filter(|keyword| {
-                    let chars = keyword.chars().count();
-
-                    chars >= self.minimum_keyword_length
-                        && chars <= self.maximum_keyword_length
-                }) // filter
-                // Only keep the keyword if it's not in the exclusion list:
-                .filter(|keyword| !exclude_keyword(keyword, self.exclude_keywords.as_ref()))
+                // Run the keyword through the token filters:
+                .filter_map(|keyword| self.filter_keyword(Cow::Borrowed(keyword)))
+                // Only keep the keyword if it meets the length restrictions
+                // and isn't in the exclusion list:
+                .filter(|keyword| keep_keyword(keyword))
                 // Copy string from reference:
-                .map(KString::from_ref)
+                .map(|keyword| match keyword {
+                    Cow::Borrowed(keyword) => KString::from_ref(keyword),
+                    Cow::Owned(keyword) => KString::from(keyword),
+                }) // map
                 // Collect all keywords into a `Vec`:
-                .collect()
-            ) // map
-            .unwrap_or_default();
+                .collect(),
 
-        // Using the whole string as a keyword:
+            (None, None) => Vec::new(),
+        }; // match
+
+        // Using the whole string as a keyword. Token filters only work on
+        // individual keywords (i.e. a stemmer would only stem the last word of
+        // the string) so the whole string isn't filtered:
         //
         // * For searching: return the whole string as the search keyword if
         // no split pattern is defined (keyword splitting is turned off).
@@ -102,6 +134,7 @@ impl<K: Ord> SearchIndex<K> {
         // * For indexing: if the option is enabled, store the field text /
         // entire string itself as a keyword. This feature is primarily for
         // autocompletion purposes.
+        let string = normalized;
         let chars = string.chars().count();
 
         // If we're searching, keep the whole string if there is no split
@@ -109,6 +142,7 @@ impl<K: Ord> SearchIndex<K> {
         // any keyword splitting:
         if context == &SplitContext::Searching
             && self.split_pattern.is_none()
+            && self.tokenizer.is_none()
             && chars >= self.minimum_keyword_length
         {
             // Set keywords to the entire string:
@@ -131,4 +165,27 @@ impl<K: Ord> SearchIndex<K> {
         // Return keywords to caller:
         keywords
     } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Runs a keyword through the search index's token filters, in order.
+    /// Returns `None` if one of the filters removed the keyword.
+    #[inline]
+    pub(crate) fn filter_keyword<'k>(&self, keyword: Cow<'k, str>) -> Option<Cow<'k, str>> {
+        self.token_filters
+            .iter()
+            .try_fold(keyword, |keyword, token_filter| token_filter.filter(keyword))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Joins keywords back into a string using the search index's `Tokenizer`.
+    /// If there is no tokenizer, the keywords are separated by a space.
+    #[inline]
+    pub(crate) fn join_keywords(&self, keywords: &[KString]) -> String {
+        self.tokenizer.as_ref().map_or_else(
+            || keywords.join(" "),
+            |tokenizer| tokenizer.join(&keywords.iter().map(KString::as_str).collect::<Vec<&str>>()),
+        ) // map_or_else
+    } // fn
 } // impl
@@ -0,0 +1,139 @@
+use crate::simple::{internal::string_keywords::SplitContext, search_index::SearchIndex, Synonyms};
+use kstring::KString;
+use std::ops::Range;
+
+// -----------------------------------------------------------------------------
+//
+/// The maximum number of synonym variations that will be autocompleted, in
+/// addition to the search string itself. This keeps search strings with many
+/// synonyms from multiplying into a large number of autocompletions.
+const MAXIMUM_SYNONYM_VARIANTS: usize = 16;
+
+// -----------------------------------------------------------------------------
+//
+/// A phrase in the search keywords that has synonyms.
+pub struct SynonymTerm<'s> {
+    /// The position of the phrase's keywords in the search keywords.
+    pub(crate) range: Range<usize>,
+    /// The keywords of each of the phrase's synonyms.
+    pub(crate) alternatives: &'s [Vec<KString>],
+} // SynonymTerm
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Splits each synonym entry into keywords, using the search index's
+    /// settings, and adds it to the synonym map. Entries that don't have any
+    /// keywords on either side are ignored.
+    pub(crate) fn add_synonyms(&mut self, synonyms: &Synonyms) {
+        for (phrase, synonym) in &synonyms.entries {
+            let phrase = self.string_keywords(phrase, &SplitContext::Searching);
+            let synonym = self.string_keywords(synonym, &SplitContext::Searching);
+
+            if phrase.is_empty() || synonym.is_empty() || phrase == synonym {
+                continue;
+            } // if
+
+            let alternatives = self.synonyms.entry(phrase.join(" ").into()).or_default();
+
+            if !alternatives.contains(&synonym) {
+                alternatives.push(synonym);
+            } // if
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the phrases in the search keywords that have synonyms, in
+    /// order.
+    ///
+    /// Phrases are found from left to right, and the longest phrase wins. For
+    /// example, `new york city` is matched as a whole before `new york`.
+    pub(crate) fn synonym_terms(&self, keywords: &[KString]) -> Vec<SynonymTerm<'_>> {
+        let mut terms: Vec<SynonymTerm<'_>> = Vec::new();
+
+        if self.synonyms.is_empty() {
+            return terms;
+        } // if
+
+        let mut start = 0;
+
+        while start < keywords.len() {
+            // Look for the longest phrase that begins at this keyword:
+            let phrase = (start + 1..=keywords.len())
+                .rev()
+                .find_map(|end| self.synonyms
+                    .get(keywords[start..end].join(" ").as_str())
+                    .map(|alternatives| (end, alternatives))
+                ); // find_map
+
+            match phrase {
+                Some((end, alternatives)) => {
+                    terms.push(SynonymTerm { range: start..end, alternatives });
+                    start = end;
+                } // Some
+                None => start += 1,
+            } // match
+        } // while
+
+        terms
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the variations of the search keywords that have synonyms
+    /// substituted in. The keywords themselves are not included. Variations
+    /// with fewer substitutions are returned first. The phrases are found as
+    /// described in `synonym_terms`.
+    pub(crate) fn synonym_variants(&self, keywords: &[KString]) -> Vec<Vec<KString>> {
+        let terms = self.synonym_terms(keywords);
+
+        if terms.is_empty() {
+            return Vec::new();
+        } // if
+
+        // Each variation is tracked with its number of substitutions:
+        let mut variants: Vec<(usize, Vec<KString>)> = vec![(0, Vec::new())];
+        let mut start = 0;
+
+        for SynonymTerm { range, alternatives } in terms {
+            // The keywords before the phrase are kept as-is:
+            for (_substitutions, variant) in &mut variants {
+                variant.extend_from_slice(&keywords[start..range.start]);
+            } // for
+
+            // Each variation so far is kept with the phrase, and is copied
+            // once for each of the phrase's synonyms. The unmodified keywords
+            // are always the first variation:
+            variants = variants
+                .into_iter()
+                .flat_map(|(substitutions, variant)| {
+                    let alternatives = alternatives
+                        .iter()
+                        .map(|alternative| (substitutions + 1, [variant.as_slice(), alternative].concat()))
+                        .collect::<Vec<(usize, Vec<KString>)>>();
+
+                    std::iter::once((substitutions, [variant.as_slice(), &keywords[range.clone()]].concat()))
+                        .chain(alternatives)
+                }) // flat_map
+                .take(MAXIMUM_SYNONYM_VARIANTS + 1)
+                .collect();
+
+            start = range.end;
+        } // for
+
+        // The keywords after the last phrase are kept as-is:
+        for (_substitutions, variant) in &mut variants {
+            variant.extend_from_slice(&keywords[start..]);
+        } // for
+
+        variants.sort_by_key(|(substitutions, _variant)| *substitutions);
+
+        variants
+            .into_iter()
+            // Skip the unmodified keywords:
+            .skip(1)
+            .map(|(_substitutions, variant)| variant)
+            .collect()
+    } // fn
+} // impl
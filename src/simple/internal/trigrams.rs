@@ -0,0 +1,145 @@
+//! A trigram index of the search index's keywords, for infix and suffix
+//! matching. The trigram index is only maintained when
+//! `SearchIndexBuilder::infix_matching` has been turned on.
+
+use crate::simple::SearchIndex;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// The length of the grams, in chars.
+const GRAM_LENGTH: usize = 3;
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords that contain each trigram.
+///
+/// A trigram is taken from every char in a keyword that's followed by at
+/// least two more chars. For example, `phone` has the trigrams `pho`, `hon`
+/// and `one`. Keywords that are shorter than three chars have no trigrams.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TrigramIndex {
+    /// The keywords that contain each gram.
+    pub(crate) grams: BTreeMap<KString, BTreeSet<KString>>,
+} // TrigramIndex
+
+// -----------------------------------------------------------------------------
+
+impl TrigramIndex {
+    /// Makes a trigram index for the given keywords.
+    pub(crate) fn from_keywords<'k>(keywords: impl Iterator<Item = &'k KString>) -> Self {
+        let mut trigram_index = Self::default();
+        keywords.for_each(|keyword| trigram_index.insert(keyword));
+        trigram_index
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a keyword that has been added to the search index.
+    pub(crate) fn insert(&mut self, keyword: &KString) {
+        for gram in grams(keyword) {
+            self.grams
+                .entry(KString::from_ref(gram))
+                .or_default()
+                .insert(keyword.clone());
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes a keyword that has been removed from the search index.
+    pub(crate) fn remove(&mut self, keyword: &str) {
+        for gram in grams(keyword) {
+            let is_empty = self.grams.get_mut(gram).is_some_and(|keywords| {
+                keywords.remove(keyword);
+                keywords.is_empty()
+            }); // is_some_and
+
+            if is_empty {
+                self.grams.remove(gram);
+            } // if
+        } // for
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that contain the fragment anywhere, in
+    /// lexicographic order.
+    pub(crate) fn keywords(&self, fragment: &str) -> BTreeSet<&KString> {
+        // A fragment that's shorter than a trigram would be found in most of
+        // the keywords, and scanning them would be slow. These fragments are
+        // only matched by prefix:
+        if fragment.chars().count() < GRAM_LENGTH {
+            return BTreeSet::new();
+        } // if
+
+        // The fragment is split into trigrams. A keyword must contain every
+        // one of them. The rarest trigram is used to find candidates, which
+        // are then checked for the whole fragment:
+        let Some(candidates) = grams(fragment)
+            .map(|gram| self.grams.get(gram))
+            .collect::<Option<Vec<&BTreeSet<KString>>>>()
+            .and_then(|keywords| keywords.into_iter().min_by_key(|keywords| keywords.len()))
+        else {
+            return BTreeSet::new();
+        }; // let
+
+        candidates
+            .iter()
+            .filter(|keyword| keyword.contains(fragment))
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the trigrams of a keyword: the three chars starting from each char,
+/// if there are that many left.
+fn grams(keyword: &str) -> impl Iterator<Item = &str> {
+    keyword.char_indices().map_while(move |(start, _char)| {
+        keyword[start..]
+            .char_indices()
+            .map(|(offset, _char)| start + offset)
+            .chain(std::iter::once(keyword.len()))
+            .nth(GRAM_LENGTH)
+            .map(|end| &keyword[start..end])
+    }) // map_while
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Returns the keywords (and their keys) that start with a partial
+    /// keyword, in lexicographic order. If infix matching has been turned on,
+    /// these are followed by the keywords that contain the partial keyword
+    /// elsewhere, in lexicographic order.
+    pub(crate) fn partial_keywords<'a, 'p>(
+        &'a self,
+        partial_keyword: &'p str,
+    ) -> impl Iterator<Item = (&'a KString, &'a BTreeSet<K>)> + use<'a, 'p, K> {
+        let prefix_matches = self.b_tree_map
+            // Get matching keywords starting with (partial) keyword string:
+            .range(KString::from_ref(partial_keyword)..)
+            // We did not specify an end bound for our `range` function (see
+            // above.) `range` will return _every_ keyword greater than the
+            // supplied keyword. The below `take_while` will effectively break
+            // iteration when we reach a keyword that does not start with our
+            // supplied (partial) keyword.
+            .take_while(move |(keyword, _keys)| keyword.starts_with(partial_keyword));
+
+        // The infix matches are only looked up if they're needed:
+        let infix_matches = std::iter::once(partial_keyword)
+            .filter(|_| self.infix_matching)
+            .flat_map(|partial_keyword| self.trigram_index.keywords(partial_keyword))
+            .filter(move |keyword| !keyword.starts_with(partial_keyword))
+            // Whole strings that are indexed as keywords (see
+            // `max_string_len`) are only matched by prefix, otherwise they'd
+            // duplicate the words that they contain:
+            .filter(|keyword| !keyword.contains(char::is_whitespace))
+            .filter_map(|keyword| self.b_tree_map.get_key_value(keyword));
+
+        prefix_matches.chain(infix_matches)
+    } // fn
+} // impl
@@ -0,0 +1,142 @@
+use crate::simple::stop_words;
+
+// -----------------------------------------------------------------------------
+//
+/// A natural language, for language-aware keyword processing such as
+/// stemming and stop words. These are the languages supported by the
+/// [Snowball](https://snowballstem.org/) stemmers.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum Language {
+    /// Arabic (`ar`.)
+    Arabic,
+    /// Danish (`da`.)
+    Danish,
+    /// Dutch (`nl`.)
+    Dutch,
+    /// English (`en`.)
+    English,
+    /// Finnish (`fi`.)
+    Finnish,
+    /// French (`fr`.)
+    French,
+    /// German (`de`.)
+    German,
+    /// Greek (`el`.)
+    Greek,
+    /// Hungarian (`hu`.)
+    Hungarian,
+    /// Italian (`it`.)
+    Italian,
+    /// Norwegian (`no`.)
+    Norwegian,
+    /// Portuguese (`pt`.)
+    Portuguese,
+    /// Romanian (`ro`.)
+    Romanian,
+    /// Russian (`ru`.)
+    Russian,
+    /// Spanish (`es`.)
+    Spanish,
+    /// Swedish (`sv`.)
+    Swedish,
+    /// Tamil (`ta`.)
+    Tamil,
+    /// Turkish (`tr`.)
+    Turkish,
+} // Language
+
+// -----------------------------------------------------------------------------
+
+impl Language {
+    /// Returns the language for an ISO 639-1 language code (i.e. `"de"`), or
+    /// `None` if the language isn't supported. The code isn't case sensitive.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::Language;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// assert_eq!(Language::from_code("pt"), Some(Language::Portuguese));
+    /// assert_eq!(Language::from_code("PT"), Some(Language::Portuguese));
+    /// assert_eq!(Language::from_code("xx"), None);
+    /// ```
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_ascii_lowercase().as_str() {
+            "ar" => Some(Self::Arabic),
+            "da" => Some(Self::Danish),
+            "nl" => Some(Self::Dutch),
+            "en" => Some(Self::English),
+            "fi" => Some(Self::Finnish),
+            "fr" => Some(Self::French),
+            "de" => Some(Self::German),
+            "el" => Some(Self::Greek),
+            "hu" => Some(Self::Hungarian),
+            "it" => Some(Self::Italian),
+            "no" => Some(Self::Norwegian),
+            "pt" => Some(Self::Portuguese),
+            "ro" => Some(Self::Romanian),
+            "ru" => Some(Self::Russian),
+            "es" => Some(Self::Spanish),
+            "sv" => Some(Self::Swedish),
+            "ta" => Some(Self::Tamil),
+            "tr" => Some(Self::Turkish),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Returns the language's ISO 639-1 language code (i.e. `"de"`).
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Arabic => "ar",
+            Self::Danish => "da",
+            Self::Dutch => "nl",
+            Self::English => "en",
+            Self::Finnish => "fi",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Greek => "el",
+            Self::Hungarian => "hu",
+            Self::Italian => "it",
+            Self::Norwegian => "no",
+            Self::Portuguese => "pt",
+            Self::Romanian => "ro",
+            Self::Russian => "ru",
+            Self::Spanish => "es",
+            Self::Swedish => "sv",
+            Self::Tamil => "ta",
+            Self::Turkish => "tr",
+        } // match
+    } // fn
+
+    /// Returns the language's stop words: minor words such as articles, short
+    /// conjunctions, and short prepositions, that aren't useful as search
+    /// keywords. The words are in lower case.
+    #[must_use]
+    pub const fn stop_words(self) -> &'static [&'static str] {
+        match self {
+            Self::Arabic => stop_words::ARABIC,
+            Self::Danish => stop_words::DANISH,
+            Self::Dutch => stop_words::DUTCH,
+            Self::English => stop_words::ENGLISH,
+            Self::Finnish => stop_words::FINNISH,
+            Self::French => stop_words::FRENCH,
+            Self::German => stop_words::GERMAN,
+            Self::Greek => stop_words::GREEK,
+            Self::Hungarian => stop_words::HUNGARIAN,
+            Self::Italian => stop_words::ITALIAN,
+            Self::Norwegian => stop_words::NORWEGIAN,
+            Self::Portuguese => stop_words::PORTUGUESE,
+            Self::Romanian => stop_words::ROMANIAN,
+            Self::Russian => stop_words::RUSSIAN,
+            Self::Spanish => stop_words::SPANISH,
+            Self::Swedish => stop_words::SWEDISH,
+            Self::Tamil => stop_words::TAMIL,
+            Self::Turkish => stop_words::TURKISH,
+        } // match
+    } // fn
+} // impl
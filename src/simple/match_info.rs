@@ -0,0 +1,60 @@
+// -----------------------------------------------------------------------------
+//
+/// Describes how a search result was matched. Returned by the
+/// `search_scored` family of methods alongside each key and its score.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MatchInfo {
+    /// The keywords from the search string that matched the record, in the
+    /// order they appear in the search string. Keywords that didn't match the
+    /// record (i.e. in an `Or` search) are not listed.
+    pub keywords: Vec<KeywordMatch>,
+} // MatchInfo
+
+// -----------------------------------------------------------------------------
+//
+/// Describes how a keyword from the search string matched a keyword in the
+/// search index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeywordMatch {
+    /// The (normalized) keyword from the search string.
+    pub search_keyword: String,
+
+    /// The keyword in the search index that was matched. This is the same as
+    /// the `search_keyword` for exact matches.
+    pub index_keyword: String,
+
+    /// The named field that the keyword was restricted to (i.e. `title` for
+    /// `title:dawn`), if any.
+    pub field: Option<String>,
+
+    /// How the keyword was matched.
+    pub match_type: MatchType,
+
+    /// The keyword's contribution to the record's score. For fuzzy matches,
+    /// this is scaled by the similarity score. Phonetic matches aren't
+    /// scaled.
+    pub score: f64,
+} // KeywordMatch
+
+// -----------------------------------------------------------------------------
+//
+/// How a keyword from the search string matched a keyword in the search index.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatchType {
+    /// The keyword was found in the search index as-is.
+    Exact,
+
+    /// The keyword is the beginning of a keyword in the search index. Used for
+    /// the last (partial) keyword in `Live` searches.
+    Prefix,
+
+    /// The keyword wasn't found, and a similar keyword from the search index
+    /// was substituted for it. Contains the string similarity score from the
+    /// configured metric, between `0.0` and `1.0` (inclusive.)
+    Fuzzy(f64),
+
+    /// The keyword wasn't found, and a keyword from the search index that
+    /// sounds like it was substituted for it. Used when `phonetic_matching` is
+    /// set.
+    Phonetic,
+} // MatchType
@@ -3,15 +3,6 @@
 //!
 //! There might be more search implementations in future versions.
 
-#[cfg(all(feature = "eddie", feature = "rapidfuzz"))]
-compile_error!("features `eddie` and `rapidfuzz` cannot both be enabled");
-
-#[cfg(all(feature = "eddie", feature = "strsim"))]
-compile_error!("features `eddie` and `strsim` cannot both be enabled");
-
-#[cfg(all(feature = "rapidfuzz", feature = "strsim"))]
-compile_error!("features `rapidfuzz` and `strsim` cannot both be enabled");
-
 #[cfg(all(feature = "ahash", feature = "gxhash"))]
 compile_error!("features `ahash` and `gxhash` cannot both be enabled");
 
@@ -24,24 +15,42 @@ compile_error!("features `gxhash` and `rustc-hash` cannot both be enabled");
 // Directories:
 mod autocomplete;
 mod internal;
+mod query;
 mod search;
+mod snapshot;
+mod token_filter;
+mod tokenizer;
 
 // Methods, structs & implementations:
 mod autocomplete_type;
 mod builder;
+mod case_folding;
 mod clear;
+mod concurrent_search_index;
 mod default;
 mod deref;
 mod deref_mut;
 mod dump_keyword;
+mod extend;
+mod fuzzy_candidates;
+mod fuzzy_strategy;
 mod indexable;
 mod insert;
+mod language;
+mod match_info;
 mod max_keys_per_keyword;
 mod new;
+mod normalization_form;
+mod phonetic_algorithm;
 mod remove;
 mod replace;
+mod scoring_type;
 mod search_index;
 mod search_type;
+mod similarity;
+mod stop_words;
+mod suggest;
+mod synonyms;
 mod tests;
 
 // For debug builds only:
@@ -51,11 +60,35 @@ mod profile;
 // Exports:
 pub use crate::simple::autocomplete_type::AutocompleteType;
 pub use crate::simple::builder::SearchIndexBuilder;
+pub use crate::simple::case_folding::CaseFolding;
+pub use crate::simple::concurrent_search_index::ConcurrentSearchIndex;
+pub use crate::simple::fuzzy_candidates::FuzzyCandidates;
+pub use crate::simple::fuzzy_strategy::{FuzzyMetric, FuzzyStrategy};
 pub use crate::simple::indexable::Indexable;
+pub use crate::simple::language::Language;
+pub use crate::simple::match_info::{KeywordMatch, MatchInfo, MatchType};
 pub use crate::simple::internal::fuzzers::{
     RapidfuzzMetric,
     EddieMetric,
     StrsimMetric
 };
+pub use crate::simple::normalization_form::NormalizationForm;
+pub use crate::simple::phonetic_algorithm::PhoneticAlgorithm;
+pub use crate::simple::query::QueryNode;
+pub use crate::simple::scoring_type::ScoringType;
 pub use crate::simple::search_index::SearchIndex;
 pub use crate::simple::search_type::SearchType;
+pub use crate::simple::similarity::Similarity;
+pub use crate::simple::snapshot::{SnapshotError, SnapshotKey};
+pub use crate::simple::synonyms::Synonyms;
+pub use crate::simple::token_filter::{AsciiFoldingFilter, LowercaseFilter, StopWordFilter, TokenFilter};
+pub use crate::simple::tokenizer::{CharTokenizer, CjkTokenizer, IdentifierTokenizer, NgramTokenizer, Tokenizer};
+
+#[cfg(feature = "rust-stemmers")]
+pub use crate::simple::token_filter::StemmerFilter;
+
+#[cfg(feature = "icu_segmenter")]
+pub use crate::simple::tokenizer::DictionaryTokenizer;
+
+#[cfg(feature = "unicode-segmentation")]
+pub use crate::simple::tokenizer::WordTokenizer;
@@ -50,15 +50,40 @@ impl<K: Ord> crate::simple::SearchIndex<K> {
     ) -> Self {
         Self {
             b_tree_map: std::collections::BTreeMap::new(),
+            field_b_tree_maps: std::collections::BTreeMap::new(),
+            scoring_statistics: crate::simple::internal::scoring::ScoringStatistics::default(),
+            positional_index: crate::simple::internal::positions::PositionalIndex::default(),
+            trigram_index: crate::simple::internal::trigrams::TrigramIndex::default(),
+            deletion_index: crate::simple::internal::deletions::DeletionIndex::default(),
+            phonetic_index: crate::simple::internal::phonetics::PhoneticIndex::default(),
             search_type,
             autocomplete_type,
+            scoring_type: crate::simple::ScoringType::HitCount,
+            bm25_k1: 1.2,
+            bm25_b: 0.75,
+            index_positions: false,
+            infix_matching: false,
+            pattern_matching: false,
+            maximum_pattern_expansions: 100,
             eddie_metric,
             rapidfuzz_metric,
             strsim_metric,
             fuzzy_length,
             fuzzy_minimum_score,
+            fuzzy_candidates: crate::simple::FuzzyCandidates::Prefix,
+            fuzzy_distance: 2,
+            fuzzy_fallback: false,
+            maximum_fuzzy_expansions: 10,
+            autocomplete_strategy: None,
+            search_strategy: None,
+            phonetic_matching: None,
             split_pattern,
+            tokenizer: None,
+            token_filters: Vec::new(),
             case_sensitive,
+            normalization_form: crate::simple::NormalizationForm::Nfkc,
+            case_folding: crate::simple::CaseFolding::Lowercase,
+            remove_combining_marks: false,
             minimum_keyword_length,
             maximum_keyword_length,
             maximum_string_length,
@@ -68,6 +93,8 @@ impl<K: Ord> crate::simple::SearchIndex<K> {
             maximum_search_results,
             maximum_keys_per_keyword,
             dump_keyword: dump_keyword.map(std::convert::Into::into),
+            field_weights: None,
+            synonyms: std::collections::BTreeMap::new(),
             empty_b_tree_set: std::collections::BTreeSet::new(),
             #[cfg(feature = "icu_normalizer")]
             icu_normalizer: icu_normalizer::ComposingNormalizer::new_nfkc(),
@@ -0,0 +1,36 @@
+// -----------------------------------------------------------------------------
+//
+/// The [Unicode normalization form](https://unicode.org/reports/tr15/) that is
+/// applied to keywords, when indexing and when searching. Normalization
+/// ensures that equivalent characters match, regardless of how they were
+/// encoded.
+///
+/// Normalization requires the `icu_normalizer` (default) or the
+/// `unicode-normalization` feature. If neither feature is enabled, keywords
+/// are not normalized.
+///
+/// For more information on the setting the normalization form in a
+/// `SearchIndex` type see: [`SearchIndexBuilder`].
+///
+/// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum NormalizationForm {
+    /// Canonical composition. Precomposed and decomposed characters match
+    /// (i.e. `é` and `e` followed by a combining acute accent.)
+    Nfc = 0,
+
+    /// Canonical decomposition. Characters are split into their base
+    /// character and combining marks.
+    Nfd = 1,
+
+    /// Compatibility composition. In addition to `Nfc`, compatibility
+    /// characters match their plain equivalents (i.e. `ﬁ` and `fi`, or
+    /// full-width and ASCII characters.)
+    Nfkc = 2,
+
+    /// Compatibility decomposition. Like `Nfkc`, but characters are split
+    /// into their base character and combining marks.
+    Nfkd = 3,
+}
@@ -0,0 +1,36 @@
+// -----------------------------------------------------------------------------
+//
+/// Indicium `simple` search can match keywords that sound alike, even when
+/// they're spelled differently (i.e. `Smyth` for `Smith`, or `Kathryn` for
+/// `Catherine`). Each keyword is given a phonetic code, and keywords with the
+/// same code are matches.
+///
+/// Only keywords made entirely of letters are coded. The algorithms are
+/// designed for English names and words.
+///
+/// For more information on the setting the phonetic algorithm in a
+/// `SearchIndex` type see: [`SearchIndexBuilder`].
+///
+/// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum PhoneticAlgorithm {
+    /// [Soundex](https://en.wikipedia.org/wiki/Soundex) codes a keyword as its
+    /// first letter and three digits (i.e. `S530` for `Smith`). It's the
+    /// loosest of the algorithms: keywords must share their first letter, but
+    /// many unrelated keywords share a code.
+    Soundex = 0,
+
+    /// [Metaphone](https://en.wikipedia.org/wiki/Metaphone) codes a keyword by
+    /// the sounds of its letters (i.e. `K0RN` for both `Catherine` and
+    /// `Kathryn`), so keywords needn't share their first letter.
+    #[default]
+    Metaphone = 1,
+
+    /// [Double Metaphone](https://en.wikipedia.org/wiki/Metaphone#Double_Metaphone)
+    /// is an improved Metaphone that gives keywords a second code for another
+    /// way they may be pronounced (i.e. `Schmidt` sounds like both `XMT` and
+    /// `SMT`). It handles more spelling rules and non-English names.
+    DoubleMetaphone = 2,
+}
@@ -0,0 +1,7 @@
+mod parser;
+mod query_node;
+mod search_query;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::simple::query::query_node::QueryNode;
@@ -0,0 +1,400 @@
+use crate::simple::{internal::string_keywords::SplitContext, QueryNode, SearchIndex};
+use kstring::KString;
+
+// -----------------------------------------------------------------------------
+//
+/// The maximum nesting depth of groups and exclusions. Deeper groups are
+/// flattened, and deeper exclusions are ignored, so that a hostile query such
+/// as 10,000 opening parentheses can't overflow the stack.
+const MAXIMUM_DEPTH: usize = 64;
+
+// -----------------------------------------------------------------------------
+//
+/// A lexical token in a search query string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    /// An opening parenthesis: `(`
+    LeftParen,
+    /// A closing parenthesis: `)`
+    RightParen,
+    /// A leading minus sign, i.e. the `-` in `-thread`.
+    Minus,
+    /// The contents of a quoted string, without the quotes.
+    Quoted(String),
+    /// Any other run of characters. This includes the `AND`, `OR` and `NOT`
+    /// operators.
+    Word(String),
+} // Token
+
+// -----------------------------------------------------------------------------
+//
+/// Splits a search query string into tokens. Unbalanced quotes are closed at
+/// the end of the string.
+fn tokenize(string: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut chars = string.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '(' => tokens.push(Token::LeftParen),
+            ')' => tokens.push(Token::RightParen),
+            '"' => {
+                let quoted: String = chars.by_ref().take_while(|char| *char != '"').collect();
+                tokens.push(Token::Quoted(quoted));
+            } // '"'
+            // A minus sign is only an operator at the beginning of a term:
+            '-' if chars.peek().is_some_and(|next| !next.is_whitespace()) => {
+                tokens.push(Token::Minus);
+            } // '-'
+            // A regular expression may contain parentheses and quotes, so it's
+            // read up to the closing slash:
+            #[cfg(feature = "regex")]
+            '/' => {
+                let expression: String = chars.by_ref().take_while(|char| *char != '/').collect();
+                tokens.push(Token::Word(format!("/{expression}/")));
+            } // '/'
+            char if char.is_whitespace() => { /* Do nothing */ },
+            char => {
+                let mut word = String::from(char);
+                while let Some(next) = chars.next_if(|next| {
+                    !next.is_whitespace() && !matches!(next, '(' | ')' | '"')
+                }) {
+                    word.push(next);
+                } // while
+                tokens.push(Token::Word(word));
+            } // char
+        } // match
+    } // while
+
+    tokens
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// A recursive descent parser for search query strings. The grammar is:
+///
+/// ```text
+/// or      = and ( "OR" and )*
+/// and     = unary ( "AND"? unary )*
+/// unary   = ( "NOT" | "-" ) unary | near
+/// near    = primary ( "NEAR/" distance primary )*
+/// primary = "(" or ")" | field? ( quoted | term )
+/// ```
+struct Parser<'i, K: Ord> {
+    /// The search index, used for normalizing and splitting keywords, and for
+    /// recognizing field names.
+    search_index: &'i SearchIndex<K>,
+    /// The tokens from the search query string.
+    tokens: Vec<Token>,
+    /// The current position in the tokens.
+    position: usize,
+} // Parser
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> Parser<'_, K> {
+    /// Returns the current token without consuming it.
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    } // fn
+
+    /// Consumes and returns the current token.
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    } // fn
+
+    /// Returns whether the current token is the given operator word.
+    fn peek_operator(&self, operator: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(word)) if word == operator)
+    } // fn
+
+    /// Parses `or = and ( "OR" and )*`
+    ///
+    /// `depth` is the number of groups and exclusions that the expression is
+    /// nested in.
+    fn parse_or(&mut self, depth: usize) -> Option<QueryNode> {
+        let mut nodes: Vec<QueryNode> = Vec::new();
+        nodes.extend(self.parse_and(depth));
+
+        while self.peek_operator("OR") {
+            self.position += 1;
+            nodes.extend(self.parse_and(depth));
+        } // while
+
+        simplify(nodes, QueryNode::Or)
+    } // fn
+
+    /// Parses `and = unary ( "AND"? unary )*`
+    fn parse_and(&mut self, depth: usize) -> Option<QueryNode> {
+        let mut nodes: Vec<QueryNode> = Vec::new();
+
+        loop {
+            match self.peek() {
+                None | Some(Token::RightParen) => break,
+                Some(Token::Word(word)) if word == "OR" => break,
+                Some(Token::Word(word)) if word == "AND" => self.position += 1,
+                Some(_) => nodes.extend(self.parse_unary(depth)),
+            } // match
+        } // loop
+
+        simplify(nodes, QueryNode::And)
+    } // fn
+
+    /// Parses `unary = ( "NOT" | "-" ) unary | near`
+    fn parse_unary(&mut self, depth: usize) -> Option<QueryNode> {
+        if depth >= MAXIMUM_DEPTH {
+            // Too deep. Any more exclusions are ignored:
+            while self.peek() == Some(&Token::Minus) || self.peek_operator("NOT") {
+                self.position += 1;
+            } // while
+        } else if self.peek() == Some(&Token::Minus) || self.peek_operator("NOT") {
+            self.position += 1;
+            return self
+                .parse_unary(depth + 1)
+                .map(|node| QueryNode::Not(Box::new(node)));
+        } // if
+
+        self.parse_near(depth)
+    } // fn
+
+    /// Parses `near = primary ( "NEAR/" distance primary )*`
+    fn parse_near(&mut self, depth: usize) -> Option<QueryNode> {
+        let mut node = self.parse_primary(depth);
+
+        while let Some(distance) = self.peek_near() {
+            self.position += 1;
+            let right = self.parse_primary(depth);
+            // If either operand was dropped (i.e. it only contained excluded
+            // keywords), keep the other operand. An operand that only contains
+            // exclusions has no positions to be near, so it's combined with
+            // the other operand using `AND` instead:
+            node = match (node, right) {
+                (Some(left), Some(right)) if is_exclusion(&left) || is_exclusion(&right) =>
+                    Some(QueryNode::And(vec![left, right])),
+                (Some(left), Some(right)) =>
+                    Some(QueryNode::Near(Box::new(left), Box::new(right), distance)),
+                (left, right) => left.or(right),
+            }; // match
+        } // while
+
+        node
+    } // fn
+
+    /// Returns the distance, if the current token is a `NEAR/n` operator.
+    fn peek_near(&self) -> Option<usize> {
+        match self.peek() {
+            Some(Token::Word(word)) => word.strip_prefix("NEAR/")?.parse().ok(),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Parses `primary = "(" or ")" | field? ( quoted | term )`
+    fn parse_primary(&mut self, depth: usize) -> Option<QueryNode> {
+        match self.next()? {
+            // Too deep. The parenthesis is ignored, and the group's contents
+            // are parsed as part of the enclosing expression:
+            Token::LeftParen if depth >= MAXIMUM_DEPTH => None,
+            Token::LeftParen => {
+                let node = self.parse_or(depth + 1);
+                // Consume the closing parenthesis, if there is one:
+                if self.peek() == Some(&Token::RightParen) {
+                    self.position += 1;
+                } // if
+                node
+            } // LeftParen
+            Token::Quoted(quoted) => self.phrase(&quoted),
+            Token::Word(word) => {
+                // A field name followed by a colon applies to the rest of the
+                // term (`title:dawn`) or to the following group or quoted
+                // phrase (`title:"silver dawn"`):
+                if let Some((field, rest)) = word.split_once(':') {
+                    if let Some(field) = self.field(field) {
+                        let node = if rest.is_empty() {
+                            match self.peek() {
+                                Some(Token::LeftParen | Token::Quoted(_)) => self.parse_primary(depth),
+                                _ => None,
+                            } // match
+                        } else {
+                            self.term(rest)
+                        }; // if
+                        return node.map(|node| QueryNode::Field(field.to_string(), Box::new(node)));
+                    } // if
+                } // if
+                self.term(&word)
+            } // Word
+            // A stray closing parenthesis or minus sign is ignored:
+            Token::RightParen | Token::Minus => None,
+        } // match
+    } // fn
+
+    /// Returns the normalized field name, if the field exists in the search
+    /// index.
+    fn field(&self, field: &str) -> Option<KString> {
+        let field = KString::from_ref(&self.search_index.normalize(field));
+        self.search_index.field_b_tree_maps.contains_key(&field).then_some(field)
+    } // fn
+
+    /// Converts a word from the search query into a node. A trailing asterisk
+    /// makes it a prefix. Other wildcards, or slashes around the word, make it
+    /// a pattern.
+    #[allow(clippy::option_if_let_else)] // `map_or_else` is illegible
+    fn term(&self, word: &str) -> Option<QueryNode> {
+        // Regular expressions are checked here, but compiled when the query is
+        // evaluated:
+        #[cfg(feature = "regex")]
+        if let Some(expression) = word.strip_prefix('/').and_then(|word| word.strip_suffix('/')) {
+            return self.search_index
+                .regex_pattern(expression)
+                .map(|_pattern| QueryNode::Regex(expression.to_string()));
+        } // if
+
+        let stem = word.strip_suffix('*');
+
+        if word.contains('?') || stem.unwrap_or(word).contains('*') {
+            return self.search_index
+                .wildcard_pattern(word)
+                .map(|_pattern| QueryNode::Wildcard(self.search_index.normalize(word).into_owned()));
+        } // if
+
+        match stem {
+            Some(stem) => {
+                // Prefixes are normalized and filtered like keywords are:
+                let stem = self.search_index.normalize(stem).into_owned();
+                self.search_index
+                    .filter_keyword(std::borrow::Cow::Owned(stem))
+                    .filter(|stem| !stem.is_empty())
+                    .map(|stem| QueryNode::Prefix(stem.into_owned()))
+            } // Some
+            None => self.phrase(word),
+        } // match
+    } // fn
+
+    /// Converts a string into a node, according to the number of keywords it
+    /// contains. Excluded keywords are dropped.
+    fn phrase(&self, string: &str) -> Option<QueryNode> {
+        let mut keywords: Vec<String> = self.search_index
+            .string_keywords(string, &SplitContext::Searching)
+            .into_iter()
+            .map(|keyword| keyword.to_string())
+            .collect();
+
+        match keywords.len() {
+            0 => None,
+            1 => keywords.pop().map(QueryNode::Keyword),
+            _ => Some(QueryNode::Phrase(keywords)),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns whether the node only contains exclusions, i.e. `-thread` or
+/// `(-thread -dawn)`.
+fn is_exclusion(node: &QueryNode) -> bool {
+    match node {
+        QueryNode::Not(_) => true,
+        QueryNode::And(nodes) => nodes.iter().all(is_exclusion),
+        _ => false,
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Collapses a list of nodes: an empty list is nothing, and a single node
+/// doesn't need an `And` or `Or` wrapper.
+fn simplify(
+    mut nodes: Vec<QueryNode>,
+    wrapper: fn(Vec<QueryNode>) -> QueryNode,
+) -> Option<QueryNode> {
+    match nodes.len() {
+        0 => None,
+        1 => nodes.pop(),
+        _ => Some(wrapper(nodes)),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+    /// Parses a search query string into a [`QueryNode`] tree. The query
+    /// language supports:
+    ///
+    /// * Keywords. Keywords next to each other must all match: `silver dawn`
+    /// * Quoted phrases: `"silver dawn"`
+    /// * Exclusions, with a leading minus sign or `NOT`: `-thread`
+    /// * Alternatives, with `OR`: `red OR blue`
+    /// * An explicit `AND`: `silver AND dawn`
+    /// * Grouping, with parentheses: `(red OR blue) dawn`
+    /// * Prefixes, with a trailing asterisk: `conq*`
+    /// * Wildcards, where `?` matches any one character and `*` matches any
+    ///   number of characters: `colo?r` or `inv-2024-*`
+    /// * Regular expressions between slashes, with the `regex` feature:
+    ///   `/^ab\d{3}$/`
+    /// * Proximity, with `NEAR/n`: `silver NEAR/3 dawn` matches records where
+    ///   no more than 3 keywords are between `silver` and `dawn`
+    /// * Named fields, with a colon: `title:dawn` or `title:"silver dawn"`
+    ///
+    /// Groups and exclusions may be nested up to 64 deep. Deeper parentheses
+    /// are ignored, as are deeper `NOT`s and minus signs.
+    ///
+    /// Operators must be upper-case. `OR` binds more loosely than `AND`, so
+    /// `a b OR c` means `(a b) OR c`. `NEAR/n` binds more tightly than `NOT`,
+    /// so `-a NEAR/2 b` means `NOT (a NEAR/2 b)`. Keywords are normalized and
+    /// split the same way they are for `search`, and excluded keywords are
+    /// dropped.
+    ///
+    /// Wildcards and regular expressions are matched against whole keywords,
+    /// and may match no more than `SearchIndexBuilder::max_pattern_expansions`
+    /// keywords each.
+    ///
+    /// Phrases and `NEAR/n` only check keyword positions if they're being
+    /// indexed. See `SearchIndexBuilder::index_positions`.
+    ///
+    /// Returns `None` if the query doesn't contain anything to search for.
+    ///
+    /// [`QueryNode`]: enum.QueryNode.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{QueryNode, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// assert_eq!(
+    ///     search_index.parse_query(r#""Silver Dawn" -thread (red OR blue)"#),
+    ///     Some(QueryNode::And(vec![
+    ///         QueryNode::Phrase(vec!["silver".to_string(), "dawn".to_string()]),
+    ///         QueryNode::Not(Box::new(QueryNode::Keyword("thread".to_string()))),
+    ///         QueryNode::Or(vec![
+    ///             QueryNode::Keyword("red".to_string()),
+    ///             QueryNode::Keyword("blue".to_string()),
+    ///         ]),
+    ///     ])),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parse_query(&self, string: &str) -> Option<QueryNode> {
+        let mut parser = Parser {
+            search_index: self,
+            tokens: tokenize(string),
+            position: 0,
+        }; // Parser
+
+        // Parse the entire string. A stray closing parenthesis ends the
+        // current expression, so skip it and carry on:
+        let mut nodes: Vec<QueryNode> = Vec::new();
+
+        while parser.peek().is_some() {
+            nodes.extend(parser.parse_or(0));
+            if parser.peek() == Some(&Token::RightParen) {
+                parser.position += 1;
+            } // if
+        } // while
+
+        simplify(nodes, QueryNode::And)
+    } // fn
+} // impl
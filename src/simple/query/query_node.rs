@@ -0,0 +1,69 @@
+// -----------------------------------------------------------------------------
+//
+/// A node in a parsed search query. Produced by the
+/// [`SearchIndex::parse_query`] method and evaluated by the
+/// [`SearchIndex::search_query`] method.
+///
+/// Keywords in the nodes have been normalized and split according to the
+/// search index's settings, so they can be compared against the keywords in
+/// the search index directly.
+///
+/// Some nodes depend on which features are enabled (i.e. `Regex`) so matches
+/// on this enum need a wildcard arm.
+///
+/// [`SearchIndex::parse_query`]: struct.SearchIndex.html#method.parse_query
+/// [`SearchIndex::search_query`]: struct.SearchIndex.html#method.search_query
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum QueryNode {
+    /// Every child node must match. Produced by `AND`, or by terms that are
+    /// simply placed next to each other, i.e. `silver dawn`.
+    And(Vec<Self>),
+
+    /// At least one child node must match. Produced by `OR`, i.e.
+    /// `red OR blue`.
+    Or(Vec<Self>),
+
+    /// The child node must not match. Produced by `NOT` or a leading minus
+    /// sign, i.e. `-thread`.
+    Not(Box<Self>),
+
+    /// The keywords must appear as a phrase. Produced by quotes, i.e.
+    /// `"silver dawn"`, or by a single term that contains several keywords,
+    /// i.e. `anglo-saxon`.
+    ///
+    /// Keyword order is only checked if keyword positions are being indexed.
+    /// Otherwise, the keywords may appear anywhere in the record.
+    Phrase(Vec<String>),
+
+    /// Both nodes must match, with no more than the given number of keywords
+    /// between them, in either order. Produced by `NEAR/n`, i.e.
+    /// `silver NEAR/3 dawn`. A group operand, i.e. `(silver dawn) NEAR/1
+    /// thread`, spans from its first keyword to its last.
+    ///
+    /// Distances are only checked if keyword positions are being indexed.
+    /// Otherwise, this works like `And`.
+    Near(Box<Self>, Box<Self>, usize),
+
+    /// Matches any keyword that begins with the string. Produced by a trailing
+    /// asterisk, i.e. `conq*`.
+    Prefix(String),
+
+    /// Matches any keyword that fits the wildcard pattern, where `?` stands
+    /// for any one character and `*` for any number of characters. Produced
+    /// by a term with a wildcard that isn't only trailing, i.e. `colo?r`.
+    Wildcard(String),
+
+    /// Matches any keyword that the regular expression matches. Produced by a
+    /// term between slashes, i.e. `/^ab\d{3}$/`. This requires the `regex`
+    /// feature.
+    #[cfg(feature = "regex")]
+    Regex(String),
+
+    /// Matches the keyword exactly.
+    Keyword(String),
+
+    /// The child node must match in the named field. Produced by a field name
+    /// followed by a colon, i.e. `title:dawn` or `title:"silver dawn"`.
+    Field(String, Box<Self>),
+} // QueryNode
@@ -0,0 +1,271 @@
+use crate::simple::{internal::SearchTopScores, QueryNode, ScoringType, SearchIndex};
+use kstring::KString;
+use std::{collections::{BTreeMap, BTreeSet}, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
+    /// This search function parses the search string using the query language
+    /// described in [`SearchIndex::parse_query`], and returns the keys of the
+    /// records that satisfy the query. Each resulting key can then be used to
+    /// retrieve the full record from its collection.
+    ///
+    /// Unlike `search`, the search string decides how keywords are combined:
+    /// `"silver dawn" -thread (red OR blue)` returns records that contain the
+    /// phrase `silver dawn`, do not contain `thread`, and contain `red` or
+    /// `blue`.
+    ///
+    /// Search keywords must be an exact match, unless they end with an
+    /// asterisk. Fuzzy matching is not used.
+    ///
+    /// If a `ScoringType` other than `HitCount` or field weights have been set,
+    /// the results are returned in order of descending relevance, using the
+    /// keywords that the records were required or allowed to contain.
+    /// Otherwise, the results are returned in key order.
+    ///
+    /// [`SearchIndex::parse_query`]: struct.SearchIndex.html#method.parse_query
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Silver Dawn in red");
+    /// search_index.insert(&1, &"Silver Dawn in blue thread");
+    /// search_index.insert(&2, &"Silver Moon in blue");
+    /// search_index.insert(&3, &"Silver Dawn in blue");
+    ///
+    /// let search_results = search_index.search_query(r#""silver dawn" -thread (red OR blue)"#);
+    /// assert_eq!(search_results, vec![&0, &3]);
+    ///
+    /// let search_results = search_index.search_query("silv* blue -thread");
+    /// assert_eq!(search_results, vec![&2, &3]);
+    /// ```
+    #[tracing::instrument(level = "trace", name = "query search", skip(self))]
+    pub fn search_query(&'a self, string: &str) -> Vec<&'a K> {
+        // Parse the search string. If there's nothing to search for, there are
+        // no results:
+        let Some(query) = self.parse_query(string) else {
+            return Vec::new();
+        }; // let
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("searching: {:?}", query);
+
+        let search_results: BTreeSet<&'a K> = self.evaluate_query(&query, None);
+
+        // If field weights have been set, get the keyword maps for each
+        // weighted field:
+        let weighted_fields = self.weighted_fields();
+
+        // Without relevance scoring, return the results in key order:
+        if !self.is_scored(weighted_fields.as_ref()) {
+            return search_results
+                .into_iter()
+                .take(self.maximum_search_results)
+                .collect();
+        } // if
+
+        // Otherwise, score each result by the keywords that it was required or
+        // allowed to contain. Excluded keywords don't count:
+        let mut keywords: Vec<&str> = Vec::new();
+        positive_keywords(&query, &mut keywords);
+
+        // This structure will track the top scoring keys:
+        let mut top_scores: SearchTopScores<K, f64> =
+            SearchTopScores::with_capacity(self.maximum_search_results);
+
+        for key in search_results {
+            let score: f64 = keywords
+                .iter()
+                .filter(|keyword| self.b_tree_map
+                    .get(**keyword)
+                    .is_some_and(|keys| keys.contains(key)))
+                .map(|keyword| self.keyword_score(weighted_fields.as_ref(), keyword, key))
+                .sum();
+            top_scores.insert(key, score);
+        } // for
+
+        // Return the search results to the user:
+        top_scores
+            .results()
+            .map(|(key, _score)| key)
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of the records that satisfy the query node. If `field`
+    /// is set, the query node is evaluated against the named field's keyword
+    /// map, rather than the main keyword map.
+    ///
+    /// Note: This function is lower-level and for internal use only. It does
+    /// not observe any settings such as _maximum results_. These constraints
+    /// should be observed at higher levels.
+    fn evaluate_query(&'a self, query: &QueryNode, field: Option<&str>) -> BTreeSet<&'a K> {
+        // Get the keyword map for the field, or the main keyword map. If the
+        // field does not exist, no records can match:
+        let b_tree_map: &'a BTreeMap<KString, BTreeSet<K>> = match field {
+            Some(field) => match self.field_b_tree_maps.get(field) {
+                Some(b_tree_map) => b_tree_map,
+                None => return BTreeSet::new(),
+            }, // Some
+            None => &self.b_tree_map,
+        }; // match
+
+        match query {
+            QueryNode::Keyword(keyword) => b_tree_map
+                .get(keyword.as_str())
+                .map(|keys| keys.iter().collect())
+                .unwrap_or_default(),
+
+            // Find the records that contain all of the phrase's keywords, and
+            // then check that they're in order:
+            QueryNode::Phrase(keywords) => keywords
+                .iter()
+                .map(|keyword| b_tree_map
+                    .get(keyword.as_str())
+                    .map(|keys| keys.iter().collect::<BTreeSet<&K>>())
+                    .unwrap_or_default())
+                .reduce(|search_results, keys| &search_results & &keys)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|key| self.matches_positions(query, field, key))
+                .collect(),
+
+            // Union the keys for every keyword that starts with the prefix:
+            QueryNode::Prefix(prefix) => b_tree_map
+                .range(KString::from_ref(prefix)..)
+                .take_while(|(keyword, _keys)| keyword.starts_with(prefix.as_str()))
+                .flat_map(|(_keyword, keys)| keys)
+                .collect(),
+
+            // Union the keys for every keyword that matches the pattern:
+            #[cfg(feature = "regex")]
+            QueryNode::Regex(_) => self.evaluate_pattern(query, b_tree_map),
+            QueryNode::Wildcard(_) => self.evaluate_pattern(query, b_tree_map),
+
+            QueryNode::Or(nodes) => nodes
+                .iter()
+                .flat_map(|node| self.evaluate_query(node, field))
+                .collect(),
+
+            QueryNode::And(nodes) => {
+                // Exclusions are subtracted from the intersection of the other
+                // nodes, rather than evaluated against every record:
+                let (exclusions, inclusions): (Vec<&QueryNode>, Vec<&QueryNode>) = nodes
+                    .iter()
+                    .partition(|node| matches!(node, QueryNode::Not(_)));
+
+                let search_results: BTreeSet<&'a K> = if inclusions.is_empty() {
+                    self.all_keys()
+                } else {
+                    inclusions
+                        .into_iter()
+                        .map(|node| self.evaluate_query(node, field))
+                        .reduce(|search_results, keys| &search_results & &keys)
+                        .unwrap_or_default()
+                }; // if
+
+                exclusions
+                    .into_iter()
+                    .filter_map(|node| match node {
+                        QueryNode::Not(node) => Some(node),
+                        _ => None,
+                    }) // filter_map
+                    .fold(search_results, |search_results, node| {
+                        &search_results - &self.evaluate_query(node, field)
+                    }) // fold
+            } // And
+
+            QueryNode::Not(node) => &self.all_keys() - &self.evaluate_query(node, field),
+
+            // Find the records that match both operands, and then check that
+            // they're near each other:
+            QueryNode::Near(left, right, _distance) => (&self.evaluate_query(left, field)
+                & &self.evaluate_query(right, field))
+                .into_iter()
+                .filter(|key| self.matches_positions(query, field, key))
+                .collect(),
+
+            // Field names in the query have already been normalized:
+            QueryNode::Field(field, node) => self.evaluate_query(node, Some(field)),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of the records that contain a keyword matching a
+    /// `Wildcard` or `Regex` node, in the given keyword map.
+    fn evaluate_pattern(
+        &'a self,
+        query: &QueryNode,
+        b_tree_map: &'a BTreeMap<KString, BTreeSet<K>>,
+    ) -> BTreeSet<&'a K> {
+        self.query_pattern(query)
+            .map(|pattern| pattern
+                .keywords(b_tree_map, self.maximum_pattern_expansions)
+                .flat_map(|(_keyword, keys)| keys)
+                .collect())
+            .unwrap_or_default()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the key of every record in the search index. This is what
+    /// exclusions are subtracted from when a query only contains exclusions,
+    /// i.e. `-thread`.
+    ///
+    /// If the `dump_keyword` is set, its keys are used. Otherwise, if the
+    /// `Bm25` or `TfIdf` scoring type is selected, the keys of the scoring
+    /// statistics are used. Failing both, every keyword's keys are walked,
+    /// which visits every posting in the search index. Set a `dump_keyword`
+    /// to avoid this cost if exclusion-only queries are common.
+    fn all_keys(&'a self) -> BTreeSet<&'a K> {
+        // If the `dump_keyword` is set, it's attached to every record:
+        if let Some(keys) = self.dump_keyword
+            .as_ref()
+            .and_then(|dump_keyword| self.b_tree_map.get(dump_keyword))
+        {
+            return keys.iter().collect();
+        } // if
+
+        // If relevance scoring is enabled, the scoring statistics have a
+        // length for every record. Records that were inserted before the
+        // scoring type was set have no statistics, so an empty map is skipped:
+        let lengths = &self.scoring_statistics.lengths;
+        if self.scoring_type != ScoringType::HitCount && !lengths.is_empty() {
+            return lengths.keys().collect();
+        } // if
+
+        // Otherwise, walk every keyword's keys:
+        self.b_tree_map.values().flatten().collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Collects the keywords that records were required or allowed to contain.
+/// These are used for relevance scoring. Keywords inside of a `Not` node,
+/// prefixes, and patterns are skipped.
+fn positive_keywords<'q>(query: &'q QueryNode, keywords: &mut Vec<&'q str>) {
+    match query {
+        QueryNode::Keyword(keyword) => keywords.push(keyword),
+        QueryNode::Phrase(phrase) => keywords.extend(phrase.iter().map(String::as_str)),
+        QueryNode::And(nodes) | QueryNode::Or(nodes) => nodes
+            .iter()
+            .for_each(|node| positive_keywords(node, keywords)),
+        QueryNode::Near(left, right, _distance) => {
+            positive_keywords(left, keywords);
+            positive_keywords(right, keywords);
+        } // Near
+        QueryNode::Field(_field, node) => positive_keywords(node, keywords),
+        #[cfg(feature = "regex")]
+        QueryNode::Regex(_) => { /* Do nothing */ },
+        QueryNode::Not(_) | QueryNode::Prefix(_) | QueryNode::Wildcard(_) => { /* Do nothing */ },
+    } // match
+} // fn
@@ -12,7 +12,7 @@ use rustc_hash::FxHashSet as HashSet;
 use std::collections::HashSet;
 
 // Static dependencies:
-use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use crate::simple::{indexable::Indexable, search_index::SearchIndex, FuzzyCandidates, ScoringType};
 use kstring::KString;
 
 // -----------------------------------------------------------------------------
@@ -104,7 +104,22 @@ impl<K: Clone + Ord> SearchIndex<K> {
     #[tracing::instrument(level = "trace", name = "search index remove", skip(self, key, value))]
     pub fn remove(&mut self, key: &K, value: &dyn Indexable) {
         // Get all keywords for the `Indexable` record:
-        let mut keywords: HashSet<KString> = self.indexable_keywords(value);
+        let record_keywords = self.indexable_keywords(value);
+        let mut keywords: HashSet<KString> = record_keywords.keywords;
+
+        // Remove the statistics used for relevance scoring, if required:
+        if self.scoring_type != ScoringType::HitCount {
+            self.scoring_statistics.remove(key, keywords.iter());
+        } // if
+
+        // Remove the keyword positions used for phrase and proximity
+        // searches, if required:
+        if self.index_positions {
+            self.positional_index.remove(None, key, record_keywords.positions.keys());
+            for (field, positions) in &record_keywords.field_positions {
+                self.positional_index.remove(Some(field), key, positions.keys());
+            } // for
+        } // if
 
         // If `dump_keyword` feature is turned on, ensure that all records are
         // detached from this special keyword:
@@ -128,7 +143,44 @@ impl<K: Clone + Ord> SearchIndex<K> {
 
             if is_empty {
                 self.b_tree_map.remove(&normalized_keyword);
+                // Remove the keyword's grams for infix matching, if required:
+                if self.infix_matching {
+                    self.trigram_index.remove(&normalized_keyword);
+                } // if
+                // Remove the keyword's deletions for fuzzy matching, if
+                // required:
+                if self.fuzzy_candidates == FuzzyCandidates::Deletions {
+                    self.deletion_index.remove(&normalized_keyword, self.fuzzy_distance);
+                } // if
+                // Remove the keyword's phonetic codes for phonetic matching,
+                // if required:
+                if let Some(algorithm) = &self.phonetic_matching {
+                    self.phonetic_index.remove(&normalized_keyword, algorithm);
+                } // if
             } // if
         } // for_each
+
+        // Iterate over the keywords for each named field:
+        for (field, keywords) in record_keywords.fields {
+            let Some(b_tree_map) = self.field_b_tree_maps.get_mut(&field) else {
+                continue;
+            }; // let
+
+            for keyword in keywords {
+                let is_empty = b_tree_map.get_mut(&keyword).is_some_and(|keys| {
+                    keys.remove(key);
+                    keys.is_empty()
+                }); // is_some_and
+
+                if is_empty {
+                    b_tree_map.remove(&keyword);
+                } // if
+            } // for
+
+            // If there are no keywords left for this field, remove the field:
+            if b_tree_map.is_empty() {
+                self.field_b_tree_maps.remove(&field);
+            } // if
+        } // for
     } // fn
 } // impl
@@ -0,0 +1,43 @@
+// -----------------------------------------------------------------------------
+//
+/// Indicium `simple` search provides three ways of scoring search results for
+/// relevance. Scores are used to rank `Or`, `And`, and `Live` search results.
+///
+/// The `Bm25` and `TfIdf` scoring types require extra statistics about each
+/// record: the number of keywords in the record, and how many times each
+/// keyword appears in it. These statistics are only gathered when one of these
+/// scoring types is selected, so the scoring type should be set before any
+/// records are inserted into the search index.
+///
+/// For more information on the setting the scoring type in a `SearchIndex`
+/// type see: [`SearchIndexBuilder`].
+///
+/// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[repr(u8)]
+pub enum ScoringType {
+    /// Each keyword that a record contains counts as one hit. `Or` search
+    /// results are ranked by their number of hits, while `And` and `Live`
+    /// search results are returned in key order.
+    ///
+    /// This is the lightest scoring type and doesn't require any extra
+    /// statistics.
+    HitCount = 0,
+
+    /// [Okapi BM25](https://en.wikipedia.org/wiki/Okapi_BM25) ranking. Keywords
+    /// that are rare in the search index score higher than common keywords, a
+    /// keyword that's repeated in a record scores higher (with diminishing
+    /// returns), and matches in shorter records score higher than matches in
+    /// longer records.
+    ///
+    /// The `k1` and `b` parameters may be tuned with the
+    /// `SearchIndexBuilder::bm25_parameters` method.
+    Bm25 = 1,
+
+    /// [TF-IDF](https://en.wikipedia.org/wiki/Tf%E2%80%93idf) ranking.
+    /// Keywords that are rare in the search index score higher than common
+    /// keywords, and a keyword that's repeated in a record scores higher.
+    /// Unlike `Bm25`, the length of the record is not considered.
+    TfIdf = 2,
+}
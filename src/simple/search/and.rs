@@ -1,5 +1,6 @@
-use crate::simple::internal::string_keywords::SplitContext;
-use std::{collections::BTreeSet, hash::Hash};
+use crate::simple::{internal::{fuzzy_fallback::Substitutes, patterns::KeywordPattern, SearchTopScores}, FuzzyStrategy};
+use kstring::KString;
+use std::{collections::{BTreeMap, BTreeSet}, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -91,32 +92,33 @@ impl<K: Hash + Ord> crate::simple::search_index::SearchIndex<K> {
     pub(crate) fn and_search(
         &self,
         maximum_search_results: usize,
-        string: &str
+        keywords: &[KString],
+        patterns: &[KeywordPattern],
+        strategy: Option<&FuzzyStrategy>,
     ) -> Vec<&K> {
-        // Split search `String` into keywords (according to the `SearchIndex`
-        // settings). `string_keywords` will **not** allow "use entire string as
-        // a keyword," even if enabled in user settings:
-        let keywords: Vec<kstring::KString> =
-            self.string_keywords(string, &SplitContext::Searching);
-
         // For debug builds:
         #[cfg(debug_assertions)]
-        tracing::debug!("searching: {:?}", keywords);
+        tracing::debug!("searching: {:?} {:?}", keywords, patterns);
 
         // This `BTreeSet` is used to contain the search results:
         let mut search_results = BTreeSet::<&K>::new();
 
+        // The keys for each keyword that was substituted with its fuzzy
+        // matches, and the number of fuzzy matches that may still be used:
+        let mut fuzzy_results: Vec<Substitutes<'_, K>> = Vec::new();
+        let mut expansions: usize = self.maximum_fuzzy_expansions;
+
         // Get each keyword from our `BTreeMap`, and intersect the resulting
         // keys with our current keys:
         for keyword in keywords {
             // Attempt to retrieve keyword from search index. If keyword
             // found, intersect keyword records with search results records.
             // If keyword not found, empty search results:
-            if let Some(keyword_results) = self.b_tree_map.get(&keyword) {
+            if let Some(keyword_results) = self.b_tree_map.get(keyword) {
                 // Check if `search_results` is already populated:
                 search_results = if search_results.is_empty() {
                     self
-                        .internal_keyword_search(&keyword)
+                        .internal_keyword_search(keyword)
                         .collect()
                 } else {
                     search_results
@@ -131,6 +133,23 @@ impl<K: Hash + Ord> crate::simple::search_index::SearchIndex<K> {
                         // become the new `search_results`:
                         .collect()
                 } // if
+            } else if let Some(keys) = self.fuzzy_fallback(keyword, &mut expansions, strategy) {
+                // The keyword isn't in the search index, but phonetic or fuzzy
+                // matches may be substituted for it:
+                search_results = if search_results.is_empty() {
+                    keys.keys().copied().collect()
+                } else {
+                    search_results
+                        .into_iter()
+                        .filter(|key| keys.contains_key(key))
+                        .collect()
+                }; // if
+
+                if search_results.is_empty() {
+                    return Vec::new();
+                } // if
+
+                fuzzy_results.push(keys);
             } else {
                 search_results.clear();
                 return search_results
@@ -140,6 +159,64 @@ impl<K: Hash + Ord> crate::simple::search_index::SearchIndex<K> {
             } // if
         } // for_each
 
+        // Intersect the keys for each wildcard or regular expression term.
+        // The keyword that each key matched is kept for relevance scoring:
+        let mut pattern_results: Vec<BTreeMap<&K, &KString>> = Vec::with_capacity(patterns.len());
+
+        for pattern in patterns {
+            let keys: BTreeMap<&K, &KString> = self.pattern_search(pattern);
+
+            search_results = if keywords.is_empty() && pattern_results.is_empty() {
+                keys.keys().copied().collect()
+            } else {
+                search_results
+                    .into_iter()
+                    .filter(|key| keys.contains_key(key))
+                    .collect()
+            }; // if
+
+            pattern_results.push(keys);
+        } // for
+
+        // If field weights have been set, get the keyword maps for each
+        // weighted field:
+        let weighted_fields = self.weighted_fields();
+
+        // If a scoring type other than `HitCount` (or field weights) are in
+        // use, return the search results in order of relevance:
+        if self.is_scored(weighted_fields.as_ref()) {
+            // This structure will track the top scoring keys:
+            let mut top_scores: SearchTopScores<K, f64> =
+                SearchTopScores::with_capacity(maximum_search_results);
+
+            // Score each resulting key by summing its keyword scores. Keywords
+            // that were substituted with their fuzzy matches aren't in the
+            // search index, so the matches are scored instead, scaled by their
+            // similarity:
+            for key in search_results {
+                let score: f64 = keywords
+                    .iter()
+                    .filter(|keyword| self.b_tree_map.contains_key(*keyword))
+                    .chain(pattern_results.iter().filter_map(|keys| keys.get(key).copied()))
+                    .map(|keyword| self.keyword_score(weighted_fields.as_ref(), keyword, key))
+                    .chain(fuzzy_results
+                        .iter()
+                        .filter_map(|keys| keys.get(key))
+                        .map(|(substitute, similarity)| {
+                            self.keyword_score(weighted_fields.as_ref(), substitute, key) * similarity
+                        })) // chain
+                    .sum();
+
+                top_scores.insert(key, score);
+            } // for
+
+            // Return the top scoring keys:
+            return top_scores
+                .results()
+                .map(|(key, _score)| key)
+                .collect()
+        } // if
+
         // Return search results:
         search_results
             .into_iter()
@@ -1,11 +1,22 @@
 #![allow(unused_mut)]
 
-use crate::simple::internal::string_keywords::SplitContext;
 use kstring::KString;
-use std::{collections::BTreeSet, hash::Hash};
+use crate::simple::{
+    internal::{
+        field_weights::WeightedFields,
+        fuzzy_fallback::Substitutes,
+        phonetics::PHONETIC_SIMILARITY,
+        SearchTopScores,
+    },
+    FuzzyStrategy,
+};
+use std::{collections::{BTreeMap, BTreeSet}, hash::Hash};
 
-#[cfg(any(feature = "strsim", feature = "eddie", feature = "rapidfuzz"))]
-use crate::simple::internal::fuzzers::Fuzzy;
+// -----------------------------------------------------------------------------
+//
+/// The phonetic or fuzzy matches that were substituted for the last (partial)
+/// keyword, their keys, and their similarity scores.
+type LastSubstitutes<'s, K> = Vec<(&'s KString, &'s BTreeSet<K>, f64)>;
 
 // -----------------------------------------------------------------------------
 
@@ -26,8 +37,13 @@ impl<K: Hash + Ord> crate::simple::SearchIndex<K> {
     /// keywords must be present in a record for it to be returned as a result.
     ///
     /// Search only supports exact keyword matches. For `Live` searches, fuzzy
-    /// matching is only applied to the last keyword. Also, consider providing
-    /// the `autocomplete` feature to your users for a better experience.
+    /// matching is only applied to the last keyword, unless `fuzzy_fallback`
+    /// is set. Also, consider providing the `autocomplete` feature to your
+    /// users for a better experience.
+    ///
+    /// If field weights or a scoring type other than `HitCount` have been set,
+    /// the search results are ranked by relevance. Otherwise, they're returned
+    /// in key order.
     ///
     /// Basic usage:
     ///
@@ -100,154 +116,270 @@ impl<K: Hash + Ord> crate::simple::SearchIndex<K> {
     ///
     /// assert_eq!(search_results, vec![&2]);
     /// ```
+    #[allow(clippy::too_many_lines)]
     #[tracing::instrument(level = "trace", name = "live search", skip(self))]
     pub(crate) fn search_live(
         &self,
         maximum_search_results: usize,
-        string: &str
-    ) -> BTreeSet<&K> {
-        // Split search `String` into keywords according to the `SearchIndex`
-        // settings. Force "use entire string as a keyword" option off:
-        let mut keywords: Vec<KString> =
-            self.string_keywords(string, &SplitContext::Searching);
-
+        mut keywords: Vec<KString>,
+        strategy: Option<&FuzzyStrategy>,
+    ) -> Vec<&K> {
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!("searching: {:?}", keywords);
 
         // Pop the last keyword off the list - the keyword that we'll be
         // autocompleting:
-        keywords.pop().map_or_else(BTreeSet::new, |last_keyword| {
-            if keywords.is_empty() {
-                let mut search_results: BTreeSet<&K> = self
-                    .b_tree_map
-                    // Get matching keywords starting with (partial) keyword
-                    // string:
-                    .range(last_keyword.clone()..)
-                    // We did not specify an end bound for our `range`
-                    // function (see above.) `range` will return _every_
-                    // keyword greater than the supplied keyword. The below
-                    // `take_while` will effectively break iteration when we
-                    // reach a keyword that does not start with our supplied
-                    // (partial) keyword.
-                    .take_while(|(keyword, _keys)| keyword.starts_with(&*last_keyword))
-                    // Only return `maximum_search_results` number of keys:
-                    .take(maximum_search_results)
-                    // We're not interested in the `keyword` since we're
-                    // returning `&K` keys. Return only `&K` from the tuple.
-                    // Flatten the `BTreeSet<K>` from each autocomplete
-                    // keyword option into our collection:
-                    .flat_map(|(_keyword, keys)| keys)
-                    // Collect all keyword search results into a `BTreeSet`:
+        let Some(last_keyword) = keywords.pop() else {
+            return Vec::new();
+        }; // let
+
+        // If field weights have been set, get the keyword maps for each
+        // weighted field:
+        let weighted_fields = self.weighted_fields();
+
+        // Without relevance scoring, search results are returned in key order
+        // and can be cut short early. With relevance scoring, every candidate
+        // must be collected so that the best ones can be returned:
+        let is_scored = self.is_scored(weighted_fields.as_ref());
+        let maximum_candidates = if is_scored {
+            self.maximum_keys_per_keyword
+        } else {
+            maximum_search_results
+        }; // if
+
+        // The phonetic or fuzzy matches that were substituted for the
+        // keywords, for relevance scoring:
+        let mut substitutes: Vec<Substitutes<'_, K>> = Vec::new();
+        let mut last_substitutes: LastSubstitutes<'_, K> = Vec::new();
+
+        let search_results: BTreeSet<&K> = if keywords.is_empty() {
+            let mut search_results: BTreeSet<&K> = self
+                // Get matching keywords starting with (partial) keyword
+                // string, followed by keywords containing it if infix
+                // matching is on:
+                .partial_keywords(&last_keyword)
+                // Only return `maximum_search_results` number of keys:
+                .take(maximum_candidates)
+                // We're not interested in the `keyword` since we're
+                // returning `&K` keys. Return only `&K` from the tuple.
+                // Flatten the `BTreeSet<K>` from each autocomplete
+                // keyword option into our collection:
+                .flat_map(|(_keyword, keys)| keys)
+                // Collect all keyword search results into a `BTreeSet`:
+                .collect();
+
+            // If phonetic matching is enabled and there are no search results,
+            // use the keys of the keywords that sound like the user's keyword:
+            if search_results.is_empty() {
+                last_substitutes = self
+                    .phonetic_keywords(&[], &BTreeSet::new(), &last_keyword)
+                    .into_iter()
+                    .map(|(keyword, keys)| (keyword, keys, PHONETIC_SIMILARITY))
+                    .collect();
+
+                search_results = last_substitutes
+                    .iter()
+                    .flat_map(|(_keyword, keys, _similarity)| *keys)
+                    .take(maximum_candidates)
+                    .collect();
+            } // if
+
+            // If the search results are ranked, the fuzzy matches are kept
+            // with their similarity scores, so they can be scored:
+            #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+            if is_scored && search_results.is_empty() {
+                last_substitutes = self.fuzzy_similarities(strategy, &[], &BTreeSet::new(), &last_keyword);
+
+                search_results = last_substitutes
+                    .iter()
+                    .flat_map(|(_keyword, keys, _similarity)| *keys)
+                    .take(self.maximum_search_results)
+                    .collect();
+            } // if
+
+            // If fuzzy matching is enabled, this will examine the search results.
+            // If the search results are empty, it will use fuzzy matching to find
+            // closest alternatives:
+            #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+            self.fuzzy_live_keyword(strategy, &mut search_results, &last_keyword);
+
+            // Return search results to caller:
+            search_results
+        } else {
+            // Perform `And` search for entire string, excluding the
+            // last (partial) keyword. If `fuzzy_fallback` is set, these
+            // keywords may be substituted with their fuzzy matches:
+            let mut expansions: usize = self.maximum_fuzzy_expansions;
+            let search_results: BTreeSet<&K> = self.internal_fuzzy_and_search(
+                keywords.as_slice(),
+                &mut expansions,
+                strategy,
+                &mut substitutes,
+            ); // internal_fuzzy_and_search
+
+            // Get keys for the last (partial) keyword:
+            let mut last_results: BTreeSet<&K> = self
+                // Get matching keywords starting with (partial) keyword
+                // string, followed by keywords containing it if infix
+                // matching is on:
+                .partial_keywords(&last_keyword)
+                // Only keep this autocompletion if hasn't already been
+                // used as a keyword:
+                .filter(|(keyword, _keys)| !keywords.contains(keyword))
+                // We're not interested in the `keyword` since we're
+                // returning `&K` keys. Return only `&K` from the tuple.
+                // Flatten the `BTreeSet<K>` from each autocomplete
+                // keyword option into individual `K` keys:
+                .flat_map(|(_key, value)| value)
+                // Intersect the key results from the autocomplete
+                // options (produced from this iterator) with the search
+                // results produced above:
+                .filter(|key| search_results.contains(key))
+                // Only return `maximum_search_results` number of keys:
+                .take(maximum_candidates)
+                // Collect all keyword autocompletions into a
+                // `BTreetSet`:
+                .collect();
+
+            // If phonetic matching is enabled and there are no search results,
+            // use the keys of the keywords that sound like the last keyword:
+            if last_results.is_empty() {
+                last_substitutes = self
+                    .phonetic_keywords(&keywords, &search_results, &last_keyword)
+                    .into_iter()
+                    .map(|(keyword, keys)| (keyword, keys, PHONETIC_SIMILARITY))
                     .collect();
 
-                // If `rapidfuzz` fuzzy matching enabled, this will examine the
-                // search results. If the search results are empty, it will use
-                // fuzzy matching to find closest alternatives:
-                #[cfg(feature = "rapidfuzz")]
-                crate::simple::internal::fuzzers::Rapidfuzz::live_search_keyword(
-                    self,
-                    &mut search_results,
-                    &last_keyword,
-                );
-
-                // If `strsim` fuzzy matching enabled, this will examine the
-                // search results. If the search results are empty, it will use
-                // fuzzy matching to find closest alternatives:
-                #[cfg(feature = "strsim")]
-                crate::simple::internal::fuzzers::Strsim::live_search_keyword(
-                    self,
-                    &mut search_results,
-                    &last_keyword,
-                );
-
-                // If `eddie` fuzzy matching enabled, this will examine the
-                // search results. If the search results are empty, it will use
-                // fuzzy matching to find closest alternatives:
-                #[cfg(feature = "eddie")]
-                crate::simple::internal::fuzzers::Eddie::live_search_keyword(
-                    self,
-                    &mut search_results,
-                    &last_keyword,
-                );
-
-                // Return search results to caller:
-                search_results
-            } else {
-                // Perform `And` search for entire string, excluding the
-                // last (partial) keyword:
-                let search_results: BTreeSet<&K> = self
-                    .internal_and_search(keywords.as_slice());
-
-                // Get keys for the last (partial) keyword:
-                let mut last_results: BTreeSet<&K> = self.b_tree_map
-                    // Get matching keywords starting with (partial) keyword
-                    // string:
-                    .range(last_keyword.clone()..)
-                    // We did not specify an end bound for our `range`
-                    // function (see above.) `range` will return _every_
-                    // keyword greater than the supplied keyword. The below
-                    // `take_while` will effectively break iteration when we
-                    // reach a keyword that does not start with our supplied
-                    // (partial) keyword.
-                    .take_while(|(keyword, _keys)| keyword.starts_with(&*last_keyword))
-                    // Only keep this autocompletion if hasn't already been
-                    // used as a keyword:
-                    .filter(|(keyword, _keys)| !keywords.contains(keyword))
-                    // We're not interested in the `keyword` since we're
-                    // returning `&K` keys. Return only `&K` from the tuple.
-                    // Flatten the `BTreeSet<K>` from each autocomplete
-                    // keyword option into individual `K` keys:
-                    .flat_map(|(_key, value)| value)
-                    // Intersect the key results from the autocomplete
-                    // options (produced from this iterator) with the search
-                    // results produced above:
+                last_results = last_substitutes
+                    .iter()
+                    .flat_map(|(_keyword, keys, _similarity)| *keys)
                     .filter(|key| search_results.contains(key))
-                    // Only return `maximum_search_results` number of keys:
-                    .take(maximum_search_results)
-                    // Collect all keyword autocompletions into a
-                    // `BTreetSet`:
+                    .take(maximum_candidates)
                     .collect();
+            } // if
 
-                // If `rapidfuzz` fuzzy matching enabled, this will examine the
-                // search results. If the search results are empty, it will use
-                // fuzzy matching to find closest alternatives:
-                #[cfg(feature = "rapidfuzz")]
-                crate::simple::internal::fuzzers::Rapidfuzz::live_search_context(
-                    self,
-                    &search_results,
-                    &keywords,
-                    &mut last_results,
-                    &last_keyword,
-                );
-
-                // If `strsim` fuzzy matching enabled, this will examine the
-                // search results. If the search results are empty, it will use
-                // fuzzy matching to find closest alternatives:
-                #[cfg(feature = "strsim")]
-                crate::simple::internal::fuzzers::Strsim::live_search_context(
-                    self,
-                    &search_results,
-                    &keywords,
-                    &mut last_results,
-                    &last_keyword,
-                );
-
-                // If `eddie` fuzzy matching enabled, this will examine the
-                // search results. If the search results are empty, it will use
-                // fuzzy matching to find closest alternatives:
-                #[cfg(feature = "eddie")]
-                crate::simple::internal::fuzzers::Eddie::live_search_context(
-                    self,
-                    &search_results,
-                    &keywords,
-                    &mut last_results,
-                    &last_keyword,
-                );
-
-                // Return search results to caller:
-                last_results
+            // If the search results are ranked, the fuzzy matches are kept
+            // with their similarity scores, so they can be scored:
+            #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+            if is_scored && last_results.is_empty() {
+                last_substitutes = self.fuzzy_similarities(strategy, &keywords, &search_results, &last_keyword);
+
+                last_results = last_substitutes
+                    .iter()
+                    .flat_map(|(_keyword, keys, _similarity)| *keys)
+                    .take(self.maximum_search_results)
+                    .collect();
             } // if
-        }) // if
+
+            // If fuzzy matching is enabled, this will examine the search results.
+            // If the search results are empty, it will use fuzzy matching to find
+            // closest alternatives:
+            #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+            self.fuzzy_live_context(
+                strategy,
+                &search_results,
+                &keywords,
+                &mut last_results,
+                &last_keyword,
+            );
+
+            // Return search results to caller:
+            last_results
+        }; // if
+
+        // Rank the search results by relevance, if a scoring type other than
+        // `HitCount` (or field weights) are in use:
+        if is_scored {
+            self.rank_live(
+                weighted_fields.as_ref(),
+                &keywords,
+                &last_keyword,
+                &substitutes,
+                &last_substitutes,
+                &search_results,
+                maximum_search_results,
+            )
+        } else {
+            search_results.into_iter().collect()
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Ranks `Live` search results by their relevance. A record's score is the
+    /// sum of the scores of the whole keywords it contains, plus the best
+    /// score of the keywords that start with the last (partial) keyword.
+    /// Records with the same score are returned in key order.
+    ///
+    /// Keywords that were substituted with their phonetic or fuzzy matches
+    /// are scored as the best match that the record contains, scaled by the
+    /// match's similarity. This ranks them below the keywords themselves.
+    #[allow(clippy::too_many_arguments)]
+    fn rank_live<'a>(
+        &'a self,
+        weighted_fields: Option<&WeightedFields<'a, K>>,
+        keywords: &[KString],
+        last_keyword: &KString,
+        substitutes: &[Substitutes<'a, K>],
+        last_substitutes: &LastSubstitutes<'a, K>,
+        search_results: &BTreeSet<&'a K>,
+        maximum_search_results: usize,
+    ) -> Vec<&'a K> {
+        // Find the best score of the autocompleted keywords for each
+        // resulting key:
+        let mut last_weights: BTreeMap<&K, f64> = BTreeMap::new();
+
+        self.partial_keywords(last_keyword)
+            .filter(|(keyword, _keys)| !keywords.contains(keyword))
+            .for_each(|(keyword, keys)| keys
+                .iter()
+                .filter(|key| search_results.contains(key))
+                .for_each(|key| {
+                    let weight = self.keyword_score(weighted_fields, keyword, key);
+                    let best = last_weights.entry(key).or_default();
+                    *best = best.max(weight);
+                })); // for_each
+
+        // The same goes for the matches that were substituted for the last
+        // keyword, scaled by their similarity:
+        for (keyword, keys, similarity) in last_substitutes {
+            keys.iter()
+                .filter(|key| search_results.contains(key))
+                .for_each(|key| {
+                    let weight = self.keyword_score(weighted_fields, keyword, key) * similarity;
+                    let best = last_weights.entry(key).or_default();
+                    *best = best.max(weight);
+                }); // for_each
+        } // for
+
+        // This structure will track the top scoring keys:
+        let mut top_scores: SearchTopScores<K, f64> =
+            SearchTopScores::with_capacity(maximum_search_results);
+
+        // Score each resulting key. Keywords that were substituted aren't in
+        // the search index, so their matches are scored instead:
+        for key in search_results {
+            let score: f64 = keywords
+                .iter()
+                .filter(|keyword| self.b_tree_map.contains_key(*keyword))
+                .map(|keyword| self.keyword_score(weighted_fields, keyword, key))
+                .chain(substitutes
+                    .iter()
+                    .filter_map(|keys| keys.get(key))
+                    .map(|(substitute, similarity)| {
+                        self.keyword_score(weighted_fields, substitute, key) * similarity
+                    })) // chain
+                .sum::<f64>()
+                + last_weights.get(key).copied().unwrap_or_default();
+
+            top_scores.insert(key, score);
+        } // for
+
+        // Return the top scoring keys in order of relevance:
+        top_scores
+            .results()
+            .map(|(key, _score)| key)
+            .collect()
     } // fn
 } // impl
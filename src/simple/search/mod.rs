@@ -2,11 +2,18 @@ mod and;
 mod keyword;
 mod live;
 mod or;
+mod scored;
 
 // -----------------------------------------------------------------------------
 
-use crate::simple::{SearchIndex, SearchType};
-use std::hash::Hash;
+use crate::simple::{
+    internal::{patterns::KeywordPattern, string_keywords::SplitContext, synonym_keywords::SynonymTerm},
+    FuzzyStrategy,
+    SearchIndex,
+    SearchType,
+};
+use kstring::KString;
+use std::{borrow::Cow, collections::BTreeSet, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -93,15 +100,13 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
     /// ```
     #[tracing::instrument(level = "trace", name = "search", skip(self))]
     pub fn search(&'a self, string: &'a str) -> Vec<&'a K> {
-        let search_results: Vec<&'a K> = match self.search_type {
-            SearchType::And => self.and_search(self.maximum_search_results, string),
-            SearchType::Keyword => self.search_keyword(self.maximum_search_results, string),
-            SearchType::Live => self
-                .search_live(self.maximum_search_results, string)
-                .into_iter()
-                .collect(),
-            SearchType::Or => self.search_or(self.maximum_search_results, string),
-        }; // match
+        let search_results: Vec<&'a K> =
+            self.internal_search(
+                &self.search_type,
+                self.search_strategy.as_ref(),
+                self.maximum_search_results,
+                string,
+            ); // internal_search
 
         // For debug builds:
         #[cfg(debug_assertions)]
@@ -194,15 +199,13 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
     /// ```
     #[tracing::instrument(level = "trace", name = "search", skip(self))]
     pub fn search_type(&'a self, search_type: &SearchType, string: &'a str) -> Vec<&'a K> {
-        let search_results: Vec<&'a K> = match search_type {
-            SearchType::And => self.and_search(self.maximum_search_results, string),
-            SearchType::Keyword => self.search_keyword(self.maximum_search_results, string),
-            SearchType::Live => self
-                .search_live(self.maximum_search_results, string)
-                .into_iter()
-                .collect(),
-            SearchType::Or => self.search_or(self.maximum_search_results, string),
-        }; // match
+        let search_results: Vec<&'a K> =
+            self.internal_search(
+                search_type,
+                self.search_strategy.as_ref(),
+                self.maximum_search_results,
+                string,
+            ); // internal_search
 
         // For debug builds:
         #[cfg(debug_assertions)]
@@ -306,15 +309,74 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
         maximum_search_results: usize,
         string: &'a str,
     ) -> Vec<&'a K> {
-        let search_results: Vec<&'a K> = match search_type {
-            SearchType::And => self.and_search(maximum_search_results, string),
-            SearchType::Keyword => self.search_keyword(maximum_search_results, string),
-            SearchType::Live => self
-                .search_live(maximum_search_results, string)
-                .into_iter()
-                .collect(),
-            SearchType::Or => self.search_or(maximum_search_results, string),
-        }; // match
+        let search_results: Vec<&'a K> =
+            self.internal_search(
+                search_type,
+                self.search_strategy.as_ref(),
+                maximum_search_results,
+                string,
+            ); // internal_search
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!(
+            "{} search results for \"{}\".",
+            search_results.len(),
+            string,
+        ); // debug!
+
+        search_results
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This search method works like `search_with`, but keywords are fuzzy
+    /// matched with the given strategy rather than the index's
+    /// `search_strategy`. This allows a different string similarity metric
+    /// (or combination of metrics) to be used for a single search. See
+    /// [`FuzzyStrategy`] for more information.
+    ///
+    /// [`FuzzyStrategy`]: struct.FuzzyStrategy.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rapidfuzz")] {
+    /// # use indicium::simple::{FuzzyStrategy, RapidfuzzMetric, SearchIndex, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"William Rufus");
+    ///
+    /// let strategy = FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler)
+    ///     .with(RapidfuzzMetric::Osa, 0.5);
+    ///
+    /// let search_results = search_index.search_with_strategy(
+    ///     &SearchType::Live,
+    ///     &strategy,
+    ///     "wiliam",
+    /// );
+    ///
+    /// assert_eq!(search_results, vec![&1]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    #[tracing::instrument(level = "trace", name = "search", skip(self))]
+    pub fn search_with_strategy(
+        &'a self,
+        search_type: &SearchType,
+        strategy: &FuzzyStrategy,
+        string: &'a str,
+    ) -> Vec<&'a K> {
+        let search_results: Vec<&'a K> =
+            self.internal_search(
+                search_type,
+                Some(strategy),
+                self.maximum_search_results,
+                string,
+            ); // internal_search
 
         // For debug builds:
         #[cfg(debug_assertions)]
@@ -326,4 +388,249 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
 
         search_results
     } // fn
-} // impl
\ No newline at end of file
+
+    // -------------------------------------------------------------------------
+    //
+    /// Dispatches the search string to the search method for the given
+    /// `SearchType`. Field-restricted terms (i.e. `title:dawn`) are separated
+    /// from the search string, and are used to filter the search results.
+    fn internal_search(
+        &'a self,
+        search_type: &SearchType,
+        strategy: Option<&FuzzyStrategy>,
+        maximum_search_results: usize,
+        string: &str,
+    ) -> Vec<&'a K> {
+        // Separate any field-restricted terms from the search string:
+        let (field_terms, string) = self.field_terms(string);
+
+        // If there are no field-restricted terms, perform a regular search:
+        if field_terms.is_empty() {
+            return self.synonym_search(search_type, strategy, maximum_search_results, &string);
+        } // if
+
+        // Intersect the keys for every field-restricted term. For live
+        // searches, a trailing field term is treated as a partial keyword:
+        let field_results: BTreeSet<&'a K> = field_terms
+            .iter()
+            .map(|field_term| self.internal_field_search(
+                &field_term.field,
+                &field_term.string,
+                field_term.is_last && *search_type == SearchType::Live,
+            ))
+            .reduce(|search_results, keys| &search_results & &keys)
+            .unwrap_or_default();
+
+        // If the search string only contained field-restricted terms, return
+        // the field results:
+        if string.trim().is_empty() {
+            return field_results
+                .into_iter()
+                .take(maximum_search_results)
+                .collect();
+        } // if
+
+        // Otherwise, search for the rest of the string and only keep the
+        // results that also satisfy the field-restricted terms. The internal
+        // table limit is used so that filtering doesn't starve the results:
+        let search_results: Vec<&'a K> =
+            self.synonym_search(search_type, strategy, self.maximum_keys_per_keyword, &string);
+
+        search_results
+            .into_iter()
+            .filter(|key| field_results.contains(key))
+            .take(maximum_search_results)
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Dispatches the search string to the search method for the given
+    /// `SearchType`. If the search string contains phrases that have
+    /// synonyms, records that match the search string with any of those
+    /// phrases replaced by one of their synonyms are also returned. Results
+    /// for the search string itself are returned first, followed by the
+    /// results that were only found through synonyms, in key order.
+    fn synonym_search(
+        &'a self,
+        search_type: &SearchType,
+        strategy: Option<&FuzzyStrategy>,
+        maximum_search_results: usize,
+        string: &str,
+    ) -> Vec<&'a K> {
+        // Separate any wildcard and regular expression terms from the search
+        // string. Only `And` and `Or` searches recognize them:
+        let (patterns, string) = if matches!(search_type, SearchType::And | SearchType::Or) {
+            self.pattern_terms(string)
+        } else {
+            (Vec::new(), Cow::Borrowed(string))
+        }; // if
+
+        // The `Keyword` search type uses the entire string as its keyword:
+        let keywords: Vec<KString> = if *search_type == SearchType::Keyword {
+            vec![KString::from(self.normalize(&string).into_owned())]
+        } else {
+            self.string_keywords(&string, &SplitContext::Searching)
+        }; // if
+
+        let terms: Vec<SynonymTerm> = self.synonym_terms(&keywords);
+
+        // If there are no synonyms for the search string, a regular search is
+        // all that's needed:
+        if terms.is_empty() {
+            return self.keyword_search_type(search_type, strategy, maximum_search_results, keywords, &patterns);
+        } // if
+
+        let mut search_results: Vec<&'a K> = self.keyword_search_type(
+            search_type,
+            strategy,
+            maximum_search_results,
+            keywords.clone(),
+            &patterns,
+        ); // keyword_search_type
+
+        if search_results.len() >= maximum_search_results {
+            return search_results;
+        } // if
+
+        // Only keep the synonym hits that weren't found by the search string
+        // itself:
+        let found: BTreeSet<&'a K> = search_results.iter().copied().collect();
+        let remaining: usize = maximum_search_results - search_results.len();
+
+        search_results.extend(
+            self.synonym_keys(search_type, strategy, &keywords, &terms, &patterns)
+                .into_iter()
+                .filter(|key| !found.contains(key))
+                .take(remaining)
+        ); // extend
+
+        search_results
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of the records that match the search keywords, where
+    /// each phrase that has synonyms may also be matched by any of its
+    /// synonyms. This is done in one pass: each keyword is only looked up
+    /// once, rather than once for every combination of synonyms.
+    ///
+    /// Synonyms must match exactly, except that the last keyword of a `Live`
+    /// search is a partial keyword. Keywords that don't have synonyms are
+    /// matched like they are by the `And` search type, including the fuzzy
+    /// fallback. For `Or` searches, only the synonyms are looked up, since the
+    /// search string's own keywords have already been searched for.
+    fn synonym_keys(
+        &'a self,
+        search_type: &SearchType,
+        strategy: Option<&FuzzyStrategy>,
+        keywords: &[KString],
+        terms: &[SynonymTerm],
+        patterns: &[KeywordPattern],
+    ) -> BTreeSet<&'a K> {
+        // The last keyword of a `Live` search is a partial keyword:
+        let is_live = *search_type == SearchType::Live;
+
+        // Returns the keys of the records that contain every keyword in a
+        // phrase. If `is_partial` is set, the phrase's last keyword may be the
+        // beginning of a keyword:
+        let phrase_keys = |phrase: &[KString], is_partial: bool| -> BTreeSet<&'a K> {
+            phrase
+                .iter()
+                .enumerate()
+                .map(|(index, keyword)| if is_partial && index + 1 == phrase.len() {
+                    self.partial_keywords(keyword)
+                        .flat_map(|(_keyword, keys)| keys)
+                        .take(self.maximum_keys_per_keyword)
+                        .collect()
+                } else {
+                    self.internal_keyword_search(keyword).collect()
+                }) // map
+                .reduce(|search_results, keys| &search_results & &keys)
+                .unwrap_or_default()
+        }; // closure
+
+        // Returns the keys of the records that contain a phrase or any of its
+        // synonyms:
+        let term_keys = |term: &SynonymTerm, include_phrase: bool| -> BTreeSet<&'a K> {
+            let is_partial = is_live && term.range.end == keywords.len();
+            include_phrase
+                .then(|| &keywords[term.range.clone()])
+                .into_iter()
+                .chain(term.alternatives.iter().map(Vec::as_slice))
+                .flat_map(|phrase| phrase_keys(phrase, is_partial))
+                .collect()
+        }; // closure
+
+        match search_type {
+            // The `Keyword` search type uses the entire string as its keyword,
+            // so each synonym is looked up as a whole:
+            SearchType::Keyword => terms
+                .iter()
+                .flat_map(|term| term.alternatives)
+                .flat_map(|alternative| self.internal_keyword_search(&alternative.join(" ")))
+                .collect(),
+
+            // Any synonym is a hit:
+            SearchType::Or => terms
+                .iter()
+                .flat_map(|term| term_keys(term, false))
+                .collect(),
+
+            // Every keyword, or one of its synonyms, must be found:
+            SearchType::And | SearchType::Live => {
+                // The keywords that aren't part of a phrase with synonyms:
+                let mut others: Vec<KString> = keywords
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _keyword)| !terms.iter().any(|term| term.range.contains(index)))
+                    .map(|(_index, keyword)| keyword.clone())
+                    .collect();
+
+                // The last keyword of a `Live` search is a partial keyword, if
+                // it isn't part of a phrase with synonyms:
+                let last_keys: Option<BTreeSet<&'a K>> = (is_live
+                    && terms.last().is_some_and(|term| term.range.end < keywords.len()))
+                    .then(|| others.pop())
+                    .flatten()
+                    .map(|last_keyword| phrase_keys(&[last_keyword], true));
+
+                let mut expansions: usize = self.maximum_fuzzy_expansions;
+
+                (!others.is_empty())
+                    .then(|| self.internal_fuzzy_and_search(&others, &mut expansions, strategy, &mut Vec::new()))
+                    .into_iter()
+                    .chain(last_keys)
+                    .chain(terms.iter().map(|term| term_keys(term, true)))
+                    .chain(patterns
+                        .iter()
+                        .map(|pattern| self.pattern_search(pattern).into_keys().collect()))
+                    .reduce(|search_results, keys| &search_results & &keys)
+                    .unwrap_or_default()
+            } // And
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches for keywords that have already been split from the search
+    /// string, using the search method for the given `SearchType`. Patterns
+    /// are only used by `And` and `Or` searches.
+    fn keyword_search_type(
+        &'a self,
+        search_type: &SearchType,
+        strategy: Option<&FuzzyStrategy>,
+        maximum_search_results: usize,
+        keywords: Vec<KString>,
+        patterns: &[KeywordPattern],
+    ) -> Vec<&'a K> {
+        match search_type {
+            SearchType::And => {
+                self.and_search(maximum_search_results, &keywords, patterns, strategy)
+            }
+            SearchType::Keyword => self.search_keyword(maximum_search_results, &keywords.join(" ")),
+            SearchType::Live => self.search_live(maximum_search_results, keywords, strategy),
+            SearchType::Or => self.search_or(maximum_search_results, keywords, patterns, strategy),
+        } // match
+    } // fn
+} // impl
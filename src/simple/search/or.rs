@@ -1,4 +1,4 @@
-use crate::simple::internal::{string_keywords::SplitContext, SearchTopScores};
+use crate::simple::{internal::{patterns::KeywordPattern, SearchTopScores}, FuzzyStrategy};
 use kstring::KString;
 
 // Conditionally select hash map type based on feature flags:
@@ -39,6 +39,9 @@ impl<'a, K: 'a + Hash + Ord> crate::simple::SearchIndex<K> {
     /// the top results. This conjuction uses more CPU resources than `And`
     /// because the keyword hits must be tallied and sorted.
     ///
+    /// If field weights have been set, a keyword hit counts as the weight of
+    /// the best field it was found in, rather than as one hit.
+    ///
     /// If your collection contains less than 10,000 records, `Or` might be a
     /// good place to start. To me, `Or` effectively feels like "using these
     /// keywords, find a record I might want" which works well if there aren't
@@ -116,46 +119,70 @@ impl<'a, K: 'a + Hash + Ord> crate::simple::SearchIndex<K> {
     pub(crate) fn search_or(
         &'a self,
         maximum_search_results: usize,
-        string: &'a str,
+        keywords: Vec<KString>,
+        patterns: &[KeywordPattern],
+        strategy: Option<&FuzzyStrategy>,
     ) -> Vec<&'a K> {
-        // Split search `String` into keywords (according to the `SearchIndex`
-        // settings). `string_keywords` will allow "use entire string as a
-        // keyword" if enabled in user settings:
-        let keywords: Vec<KString> =
-            self.string_keywords(string, &SplitContext::Searching);
-
         // For debug builds:
         #[cfg(debug_assertions)]
-        tracing::debug!("searching: {:?}", keywords);
+        tracing::debug!("searching: {:?} {:?}", keywords, patterns);
+
+        // If field weights have been set, get the keyword maps for each
+        // weighted field:
+        let weighted_fields = self.weighted_fields();
 
-        // This `HashMap` is used to count the number of hits for each
+        // This `HashMap` is used to tally the (weighted) hits for each
         // resulting key. This is so we can return search results in order of
         // relevance:
-        let mut hit_counts: HashMap<&K, usize> = HashMap::default();
+        let mut hit_counts: HashMap<&K, f64> = HashMap::default();
+
+        // The number of fuzzy matches that may still be substituted for
+        // keywords that aren't in the search index:
+        let mut expansions: usize = self.maximum_fuzzy_expansions;
 
         // Get each keyword from our search index, record the resulting keys in
         // a our `HashMap`, and track the hit-count for each key:
         for keyword in keywords {
+            // If the keyword isn't in the search index, its fuzzy matches
+            // count as the keyword. A hit is scored as the best fuzzy match
+            // that the record contains, scaled by its similarity:
+            if let Some(keys) = self.fuzzy_fallback(&keyword, &mut expansions, strategy) {
+                for (key, (substitute, similarity)) in keys {
+                    let weight = self.keyword_score(weighted_fields.as_ref(), substitute, key) * similarity;
+                    *hit_counts.entry(key).or_default() += weight;
+                } // for
+                continue;
+            } // if
+
             // Search for keyword in our `HashMap`:
             self.internal_keyword_search(&keyword)
                 // For each resulting key from the keyword search:
-                .for_each(|key| match hit_counts.get_mut(key) {
-                    // Add "hit" to counter for an already existing key:
-                    Some(result_entry) => *result_entry += 1,
-                    // No record for this key, initialize to one hit:
-                    None => {
-                        hit_counts.insert(key, 1);
-                    }
+                .for_each(|key| {
+                    // With the `HitCount` scoring type and no field weights,
+                    // every hit counts as one. Otherwise, a hit counts as the
+                    // keyword's relevance score for the record:
+                    let weight = self.keyword_score(weighted_fields.as_ref(), &keyword, key);
+                    // Add "hit" to counter for the key:
+                    *hit_counts.entry(key).or_default() += weight;
                 }); // for_each
         } // for_each
 
+        // Each wildcard or regular expression term counts as one keyword. A
+        // hit is scored as the first keyword that the record matched:
+        for pattern in patterns {
+            for (key, keyword) in self.pattern_search(pattern) {
+                let weight = self.keyword_score(weighted_fields.as_ref(), keyword, key);
+                *hit_counts.entry(key).or_default() += weight;
+            } // for
+        } // for
+
         // At this point, we have a list of resulting keys in a `HashMap`. The
-        // hash map value holds the number of times each key has been returned
-        // in the above keywords search.
+        // hash map value holds the (weighted) number of times each key has
+        // been returned in the above keywords search.
 
         // This structure will track the top scoring keys:
 
-        let mut top_scores: SearchTopScores<K> =
+        let mut top_scores: SearchTopScores<K, f64> =
             SearchTopScores::with_capacity(maximum_search_results);
 
         // Populate the top scores by iterating over each key's tally-count:
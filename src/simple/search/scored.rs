@@ -0,0 +1,342 @@
+use crate::simple::{
+    internal::{field_weights::WeightedFields, string_keywords::SplitContext},
+    KeywordMatch,
+    MatchInfo,
+    MatchType,
+    SearchIndex,
+    SearchType,
+};
+use kstring::KString;
+use std::{collections::{BTreeMap, BTreeSet}, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// A keyword from the user's search string, and the ways it may have been
+/// matched by the search.
+struct SearchTerm<'a, K: Ord> {
+    /// The normalized keyword from the search string.
+    keyword: KString,
+
+    /// The named field the keyword was restricted to, and its keyword map.
+    field: Option<(&'a KString, &'a BTreeMap<KString, BTreeSet<K>>)>,
+
+    /// Whether the keyword may match the beginning of an index keyword. This
+    /// is the last (partial) keyword in `Live` searches.
+    partial: bool,
+
+    /// Whether the keyword may be substituted using phonetic or fuzzy
+    /// matching. This is only the case for the last keyword in `Live`
+    /// searches.
+    fuzzy: bool,
+
+    /// Phonetic and fuzzy matching substitutes for the keyword, with how they
+    /// were matched. Only gathered when a search result couldn't otherwise be
+    /// explained.
+    substitutes: Option<Vec<(&'a KString, &'a BTreeSet<K>, MatchType)>>,
+} // SearchTerm
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
+    // -------------------------------------------------------------------------
+    //
+    /// The `search_scored` function works just like `search`, but each
+    /// resulting key is returned with its relevance score and a description
+    /// of how it was matched. This allows a user interface to display a
+    /// confidence level, or apply its own cut-offs.
+    ///
+    /// The score is the sum of the scores of the matched keywords, according
+    /// to the search index's [`ScoringType`] and field weights. With the
+    /// default settings, each matched keyword scores `1.0`, and fuzzy matches
+    /// are scaled by their similarity score.
+    ///
+    /// The [`MatchInfo`] lists which of the search string's keywords matched
+    /// the record exactly, by prefix, or by fuzzy substitution.
+    ///
+    /// Results are returned in the same order as `search`.
+    ///
+    /// [`ScoringType`]: enum.ScoringType.html
+    /// [`MatchInfo`]: struct.MatchInfo.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{MatchType, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    ///
+    /// let search_results = search_index.search_scored("harold go");
+    /// assert_eq!(search_results.len(), 1);
+    ///
+    /// let (key, score, match_info) = &search_results[0];
+    /// assert_eq!(*key, &0);
+    /// assert_eq!(*score, 2.0);
+    /// assert_eq!(match_info.keywords[0].match_type, MatchType::Exact);
+    /// assert_eq!(match_info.keywords[1].match_type, MatchType::Prefix);
+    /// assert_eq!(match_info.keywords[1].index_keyword, "godwinson");
+    /// ```
+    #[tracing::instrument(level = "trace", name = "scored search", skip(self))]
+    pub fn search_scored(&'a self, string: &str) -> Vec<(&'a K, f64, MatchInfo)> {
+        self.search_scored_with(&self.search_type, self.maximum_search_results, string)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This function works just like `search_type`, but each resulting key is
+    /// returned with its relevance score and a description of how it was
+    /// matched. See `search_scored` for more information.
+    #[tracing::instrument(level = "trace", name = "scored search", skip(self))]
+    pub fn search_scored_type(
+        &'a self,
+        search_type: &SearchType,
+        string: &str,
+    ) -> Vec<(&'a K, f64, MatchInfo)> {
+        self.search_scored_with(search_type, self.maximum_search_results, string)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This function works just like `search_with`, but each resulting key is
+    /// returned with its relevance score and a description of how it was
+    /// matched. See `search_scored` for more information.
+    #[tracing::instrument(level = "trace", name = "scored search", skip(self))]
+    pub fn search_scored_with(
+        &'a self,
+        search_type: &SearchType,
+        maximum_search_results: usize,
+        string: &str,
+    ) -> Vec<(&'a K, f64, MatchInfo)> {
+        // Perform the search as usual:
+        let search_results: Vec<&'a K> =
+            self.internal_search(
+                search_type,
+                self.search_strategy.as_ref(),
+                maximum_search_results,
+                string,
+            ); // internal_search
+
+        // Gather the keywords from the search string, and how they may have
+        // been matched:
+        let mut search_terms = self.search_terms(search_type, string);
+
+        // If field weights have been set, get the keyword maps for each
+        // weighted field:
+        let weighted_fields = self.weighted_fields();
+
+        // Explain each search result:
+        search_results
+            .into_iter()
+            .map(|key| {
+                let keywords: Vec<KeywordMatch> = (0..search_terms.len())
+                    .filter_map(|index| self.keyword_match(
+                        weighted_fields.as_ref(),
+                        &mut search_terms,
+                        index,
+                        key,
+                    ))
+                    .collect();
+
+                let score: f64 = keywords
+                    .iter()
+                    .map(|keyword_match| keyword_match.score)
+                    .sum();
+
+                (key, score, MatchInfo { keywords })
+            }) // map
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Splits the search string into search terms, mirroring the way the
+    /// search methods split the string for the given `SearchType`.
+    fn search_terms(
+        &'a self,
+        search_type: &SearchType,
+        string: &str,
+    ) -> Vec<SearchTerm<'a, K>> {
+        // Separate any field-restricted terms from the search string:
+        let (field_terms, string) = self.field_terms(string);
+
+        // `Keyword` searches use the whole search string as the keyword. The
+        // other search types split the string into keywords:
+        let keywords: Vec<KString> = if *search_type == SearchType::Keyword {
+            let keyword = self.normalize(&string);
+            if keyword.is_empty() {
+                Vec::new()
+            } else {
+                vec![KString::from_ref(&keyword)]
+            } // if
+        } else {
+            self.string_keywords(&string, &SplitContext::Searching)
+        }; // if
+
+        let is_live = *search_type == SearchType::Live;
+        let last_index = keywords.len().saturating_sub(1);
+
+        let mut search_terms: Vec<SearchTerm<'a, K>> = keywords
+            .into_iter()
+            .enumerate()
+            .map(|(index, keyword)| SearchTerm {
+                keyword,
+                field: None,
+                partial: is_live && index == last_index,
+                fuzzy: is_live && index == last_index,
+                substitutes: None,
+            }) // map
+            .collect();
+
+        // Add the keywords from each field-restricted term:
+        for field_term in field_terms {
+            let Some((field, b_tree_map)) = self.field_b_tree_maps.get_key_value(&field_term.field) else {
+                continue;
+            }; // let
+
+            let keywords = self.string_keywords(&field_term.string, &SplitContext::Searching);
+            let last_index = keywords.len().saturating_sub(1);
+
+            search_terms.extend(keywords
+                .into_iter()
+                .enumerate()
+                .map(|(index, keyword)| SearchTerm {
+                    keyword,
+                    field: Some((field, b_tree_map)),
+                    partial: is_live && field_term.is_last && index == last_index,
+                    fuzzy: false,
+                    substitutes: None,
+                }) // map
+            ); // extend
+        } // for
+
+        search_terms
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Describes how the search term at `index` matched the record, if it did.
+    fn keyword_match(
+        &'a self,
+        weighted_fields: Option<&WeightedFields<'a, K>>,
+        search_terms: &mut [SearchTerm<'a, K>],
+        index: usize,
+        key: &K,
+    ) -> Option<KeywordMatch> {
+        let search_term = &search_terms[index];
+        let b_tree_map = search_term.field.map_or(&self.b_tree_map, |(_field, map)| map);
+        let field = search_term.field.map(|(field, _map)| field.to_string());
+
+        // Exact keyword match:
+        if b_tree_map.get(&search_term.keyword).is_some_and(|keys| keys.contains(key)) {
+            return Some(KeywordMatch {
+                search_keyword: search_term.keyword.to_string(),
+                index_keyword: search_term.keyword.to_string(),
+                field,
+                match_type: MatchType::Exact,
+                score: self.keyword_score(weighted_fields, &search_term.keyword, key),
+            }); // KeywordMatch
+        } // if
+
+        // Prefix match. If several index keywords begin with the search
+        // keyword, report the best scoring one:
+        if search_term.partial {
+            let best = b_tree_map
+                .range(search_term.keyword.clone()..)
+                .take_while(|(keyword, _keys)| keyword.starts_with(search_term.keyword.as_str()))
+                .filter(|(_keyword, keys)| keys.contains(key))
+                .map(|(keyword, _keys)| (keyword, self.keyword_score(weighted_fields, keyword, key)))
+                .reduce(|best, next| if next.1 > best.1 { next } else { best });
+
+            if let Some((index_keyword, score)) = best {
+                return Some(KeywordMatch {
+                    search_keyword: search_term.keyword.to_string(),
+                    index_keyword: index_keyword.to_string(),
+                    field,
+                    match_type: MatchType::Prefix,
+                    score,
+                }); // KeywordMatch
+            } // if
+        } // if
+
+        // Phonetic or fuzzy match. The substitutes are gathered the same way
+        // `Live` searches gather them, and only once per search term:
+        if search_term.fuzzy {
+            if search_term.substitutes.is_none() {
+                let substitutes = self.substitutes(search_terms, index);
+                search_terms[index].substitutes = Some(substitutes);
+            } // if
+
+            let search_term = &search_terms[index];
+
+            return search_term.substitutes
+                .as_ref()
+                .and_then(|substitutes| substitutes
+                    .iter()
+                    .find(|(_keyword, keys, _match_type)| keys.contains(key)))
+                .map(|(keyword, _keys, match_type)| KeywordMatch {
+                    search_keyword: search_term.keyword.to_string(),
+                    index_keyword: keyword.to_string(),
+                    field,
+                    match_type: match_type.clone(),
+                    score: match match_type {
+                        MatchType::Fuzzy(similarity) => self.keyword_score(weighted_fields, keyword, key) * similarity,
+                        _ => self.keyword_score(weighted_fields, keyword, key),
+                    }, // match
+                }); // map
+        } // if
+
+        None
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Gathers the phonetic and fuzzy matching substitutes for the search term
+    /// at `index`, in the context of the other (unrestricted) search terms.
+    /// Phonetic matches come first, like they do in `Live` searches.
+    fn substitutes(
+        &'a self,
+        search_terms: &[SearchTerm<'a, K>],
+        index: usize,
+    ) -> Vec<(&'a KString, &'a BTreeSet<K>, MatchType)> {
+        let preceding_keywords: Vec<KString> = search_terms
+            .iter()
+            .enumerate()
+            .filter(|(other, search_term)| *other != index && search_term.field.is_none())
+            .map(|(_other, search_term)| search_term.keyword.clone())
+            .collect();
+
+        let last_keyword = &search_terms[index].keyword;
+
+        // Only keywords attached to the other search terms' results are
+        // considered, if there are other search terms:
+        let preceding_results: BTreeSet<&K> = if preceding_keywords.is_empty() {
+            BTreeSet::new()
+        } else {
+            self.internal_and_search(&preceding_keywords)
+        }; // if
+
+        #[allow(unused_mut, reason = "compiler complains when fuzzy matching is off")]
+        let mut substitutes: Vec<(&KString, &BTreeSet<K>, MatchType)> = self
+            .phonetic_keywords(&preceding_keywords, &preceding_results, last_keyword)
+            .into_iter()
+            .map(|(keyword, keys)| (keyword, keys, MatchType::Phonetic))
+            .collect();
+
+        #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+        substitutes.extend(
+            self.fuzzy_similarities(
+                self.search_strategy.as_ref(),
+                &preceding_keywords,
+                &preceding_results,
+                last_keyword,
+            ) // fuzzy_similarities
+            .into_iter()
+            .map(|(keyword, keys, similarity)| (keyword, keys, MatchType::Fuzzy(similarity))),
+        ); // extend
+
+        substitutes
+    } // fn
+} // impl
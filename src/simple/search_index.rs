@@ -1,6 +1,22 @@
-use crate::simple::{AutocompleteType, EddieMetric, RapidfuzzMetric, SearchType, StrsimMetric};
+// Conditionally select hash set type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashSet<T> = std::collections::HashSet<T, gxhash::GxBuildHasher>;
+
+#[cfg(feature = "ahash")]
+use ahash::HashSet;
+
+#[cfg(feature = "rustc-hash")]
+use rustc_hash::FxHashSet as HashSet;
+
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash"), not(feature = "rustc-hash")))]
+use std::collections::HashSet;
+
+// Static dependencies:
+use crate::simple::{AutocompleteType, CaseFolding, EddieMetric, FuzzyCandidates, FuzzyStrategy, NormalizationForm, PhoneticAlgorithm, RapidfuzzMetric, ScoringType, SearchType, StrsimMetric, TokenFilter, Tokenizer};
+use crate::simple::internal::{deletions::DeletionIndex, phonetics::PhoneticIndex, positions::PositionalIndex, scoring::ScoringStatistics, trigrams::TrigramIndex};
 use kstring::KString;
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 
 // -----------------------------------------------------------------------------
 //
@@ -15,11 +31,47 @@ use std::collections::{BTreeMap, BTreeSet};
 /// implement the `insert`, `replace`, `remove`, etc. methods for this new
 /// `struct` type that will update both the collection and search index. This
 /// will ensure that both your collection and index are always synchronized.
+///
+/// With the `serde` feature, a populated search index can be serialized and
+/// later deserialized, rather than being rebuilt from its collection. All
+/// settings are preserved, except for a custom `Tokenizer` and any
+/// `TokenFilter`s. Only whether these are set is serialized, and
+/// deserializing a search index that used them fails. To save such a search
+/// index, use a snapshot and give the tokenizer and token filters to
+/// `SearchIndex::read_snapshot_with` when loading it.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[allow(clippy::struct_excessive_bools)] // The settings are independent
 pub struct SearchIndex<K: Ord> {
     /// Search index data structure.
     pub(crate) b_tree_map: BTreeMap<KString, BTreeSet<K>>,
 
+    /// Search index data structures for named fields. There is one keyword map for
+    /// each field name returned by `Indexable::fields`. This allows searches to be
+    /// restricted to a field (i.e. `title:dawn`). Strings with an empty field name
+    /// are not recorded here.
+    pub(crate) field_b_tree_maps: BTreeMap<KString, BTreeMap<KString, BTreeSet<K>>>,
+
+    /// Per-record statistics used by the `Bm25` and `TfIdf` scoring types.
+    /// These are only maintained when one of these scoring types is selected.
+    pub(crate) scoring_statistics: ScoringStatistics<K>,
+
+    /// Keyword positions for each record, used for phrase and proximity searches.
+    /// These are only maintained when positional indexing has been turned on.
+    pub(crate) positional_index: PositionalIndex<K>,
+
+    /// The grams of every keyword, used for infix and suffix matching. These are
+    /// only maintained when infix matching has been turned on.
+    pub(crate) trigram_index: TrigramIndex,
+
+    /// The deletions of every keyword, used for typo-tolerant fuzzy matching.
+    /// These are only maintained when the fuzzy candidates are `Deletions`.
+    pub(crate) deletion_index: DeletionIndex,
+
+    /// The phonetic codes of every keyword, used for matching keywords that
+    /// sound alike. These are only maintained when phonetic matching is set.
+    pub(crate) phonetic_index: PhoneticIndex,
+
     /// The `SearchType` for searches. This setting may be manually overridden
     /// by using the `search_type` method.
     pub(crate) search_type: SearchType,
@@ -28,6 +80,37 @@ pub struct SearchIndex<K: Ord> {
     /// overridden by using the `autocompletion_type` method.
     pub(crate) autocomplete_type: AutocompleteType,
 
+    /// The `ScoringType` used to rank search results for relevance.
+    pub(crate) scoring_type: ScoringType,
+
+    /// The `k1` parameter for `Bm25` scoring. Controls how quickly repeated
+    /// keywords in a record stop adding to its score.
+    pub(crate) bm25_k1: f64,
+
+    /// The `b` parameter for `Bm25` scoring. Controls how much longer records
+    /// are penalized. `0.0` disables length normalization.
+    pub(crate) bm25_b: f64,
+
+    /// Whether keyword positions are recorded when records are inserted. This
+    /// allows quoted phrases to match keywords in order, and enables `NEAR/n`
+    /// proximity searches.
+    pub(crate) index_positions: bool,
+
+    /// Indicates whether `Live` searches and autocompletion also match keywords
+    /// that contain the last (partial) keyword, rather than only keywords that
+    /// start with it. The `trigram_index` is only maintained when this is set.
+    pub(crate) infix_matching: bool,
+
+    /// Indicates whether `And` and `Or` searches recognize wildcard terms (i.e.
+    /// `colo?r`) and, with the `regex` feature, regular expression terms (i.e.
+    /// `/^ab\d{3}$/`). Each term is matched against the keywords in `b_tree_map`.
+    pub(crate) pattern_matching: bool,
+
+    /// Maximum number of keywords that a single wildcard or regular expression
+    /// term may match. Any further keywords are ignored, so that a pattern like
+    /// `a*` doesn't have to visit every key in the search index.
+    pub(crate) maximum_pattern_expansions: usize,
+
     /// Used for the `eddie` optional feature. The `EddieMetric` is used to
     /// select the string similarity metric (or algorithm) for fuzzy matching.
     pub(crate) eddie_metric: Option<EddieMetric>,
@@ -52,13 +135,72 @@ pub struct SearchIndex<K: Ord> {
     /// `1.0` means the strings are the same.
     pub(crate) fuzzy_minimum_score: f64,
 
+    /// How the keywords that a user's keyword is fuzzy matched against are chosen.
+    /// The `deletion_index` is only maintained for `FuzzyCandidates::Deletions`.
+    pub(crate) fuzzy_candidates: FuzzyCandidates,
+
+    /// The maximum number of edits between a user's keyword and a fuzzy matched
+    /// keyword, for `FuzzyCandidates::Deletions`.
+    pub(crate) fuzzy_distance: usize,
+
+    /// Used for the `eddie`, `rapidfuzz`, and `strsim` optional features. If set,
+    /// `And`, `Or`, and `Live` searches substitute fuzzy matches for every search
+    /// keyword that is not in the search index.
+    pub(crate) fuzzy_fallback: bool,
+
+    /// The maximum number of fuzzy matched keywords that may be substituted for
+    /// the keywords in one search, when `fuzzy_fallback` is set.
+    pub(crate) maximum_fuzzy_expansions: usize,
+
+    /// Used for the `eddie`, `rapidfuzz`, and `strsim` optional features. The
+    /// fuzzy matching strategy for autocompletion. If `None`, the crates' own metric
+    /// settings are used.
+    pub(crate) autocomplete_strategy: Option<FuzzyStrategy>,
+
+    /// Used for the `eddie`, `rapidfuzz`, and `strsim` optional features. The
+    /// fuzzy matching strategy for substituting search keywords. If `None`, the
+    /// crates' own metric settings are used.
+    pub(crate) search_strategy: Option<FuzzyStrategy>,
+
+    /// If set, keywords that sound alike are matched with this phonetic algorithm,
+    /// before fuzzy matching. The `phonetic_index` is only maintained when set.
+    pub(crate) phonetic_matching: Option<PhoneticAlgorithm>,
+
     /// Characters used to split strings into keywords.
     pub(crate) split_pattern: Option<Vec<char>>,
 
+    /// Splits strings into keywords. If set, this is used instead of the
+    /// `split_pattern`. Only whether it's set is serialized.
+    #[cfg_attr(feature = "serde", serde(
+        default,
+        serialize_with = "serialize_tokenizer",
+        deserialize_with = "deserialize_tokenizer",
+    ))]
+    pub(crate) tokenizer: Option<Arc<dyn Tokenizer>>,
+
+    /// Filters that are run in order on every keyword, after strings are split and
+    /// normalized. Only whether any are set is serialized.
+    #[cfg_attr(feature = "serde", serde(
+        default,
+        serialize_with = "serialize_token_filters",
+        deserialize_with = "deserialize_token_filters",
+    ))]
+    pub(crate) token_filters: Vec<Arc<dyn TokenFilter>>,
+
     /// Indicates whether the search index is case sensitive or not. If set to
     /// false (case insensitive), all keywords will be normalized to lower case.
     pub(crate) case_sensitive: bool,
 
+    /// The Unicode normalization form that is applied to keywords.
+    pub(crate) normalization_form: NormalizationForm,
+
+    /// How keywords are converted to a common case, if the search index is case
+    /// insensitive.
+    pub(crate) case_folding: CaseFolding,
+
+    /// Removes combining marks, such as accents, from keywords.
+    pub(crate) remove_combining_marks: bool,
+
     /// Minimum keyword length (in chars or codepoints) to be indexed.
     pub(crate) minimum_keyword_length: usize,
 
@@ -71,7 +213,7 @@ pub struct SearchIndex<K: Ord> {
     pub(crate) maximum_string_length: Option<usize>,
 
     /// Keywords that should not be indexed.
-    pub(crate) exclude_keywords: Option<Vec<KString>>,
+    pub(crate) exclude_keywords: Option<HashSet<KString>>,
 
     /// Maximum number of auto-complete options to return.
     pub(crate) maximum_autocomplete_options: usize,
@@ -90,19 +232,84 @@ pub struct SearchIndex<K: Ord> {
     /// for a user inadvertently trigger this behaviour.
     pub(crate) dump_keyword: Option<KString>,
 
+    /// Relevance weights for named fields. A keyword found in a field with a
+    /// higher weight will rank its record higher in `Or` and `Live` searches.
+    /// Fields that aren't listed have a weight of `1.0`. The field names are
+    /// normalized when the search index is built.
+    pub(crate) field_weights: Option<BTreeMap<KString, f64>>,
+
+    /// Synonyms that are added to search strings. Each key is a phrase's keywords,
+    /// joined with spaces, and each value holds the keywords of its synonyms.
+    pub(crate) synonyms: BTreeMap<KString, Vec<Vec<KString>>>,
+
     /// The `empty_b_tree_set` allows us to trick the compiler into returning an
     /// empty `impl Iterator` with no memory allocations when there are no keys
     /// associated with a keyword.
     ///
     /// Without this, we would get a "distinct uses of `impl Trait` result in
     /// different opaque types" error.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) empty_b_tree_set: BTreeSet<K>,
 
-    /// A normalizer for performing composing Unicode normalization.
+    /// A normalizer for performing composing Unicode normalization. This is
+    /// not serialized. It's reconstructed when the search index is
+    /// deserialized.
     #[cfg(feature = "icu_normalizer")]
+    #[cfg_attr(feature = "serde", serde(skip, default = "icu_normalizer::ComposingNormalizer::new_nfkc"))]
     pub(crate) icu_normalizer: icu_normalizer::ComposingNormalizerBorrowed<'static>,
 } // SearchIndex
 
+// -----------------------------------------------------------------------------
+//
+/// Serializes whether the search index has a custom tokenizer.
+#[cfg(feature = "serde")]
+#[allow(clippy::ref_option, reason = "signature required by `serialize_with`")]
+fn serialize_tokenizer<S: serde::Serializer>(
+    tokenizer: &Option<Arc<dyn Tokenizer>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bool(tokenizer.is_some())
+} // fn
+
+/// Refuses to deserialize a search index that had a custom tokenizer, since
+/// search strings would be split differently than its records were.
+#[cfg(feature = "serde")]
+fn deserialize_tokenizer<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Arc<dyn Tokenizer>>, D::Error> {
+    if serde::Deserialize::deserialize(deserializer)? {
+        Err(serde::de::Error::custom(
+            "search index used a custom tokenizer, which can't be deserialized",
+        ))
+    } else {
+        Ok(None)
+    } // if
+} // fn
+
+/// Serializes whether the search index has token filters.
+#[cfg(feature = "serde")]
+fn serialize_token_filters<S: serde::Serializer>(
+    token_filters: &[Arc<dyn TokenFilter>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bool(!token_filters.is_empty())
+} // fn
+
+/// Refuses to deserialize a search index that had token filters, since search
+/// strings would be filtered differently than its records were.
+#[cfg(feature = "serde")]
+fn deserialize_token_filters<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Arc<dyn TokenFilter>>, D::Error> {
+    if serde::Deserialize::deserialize(deserializer)? {
+        Err(serde::de::Error::custom(
+            "search index used token filters, which can't be deserialized",
+        ))
+    } else {
+        Ok(Vec::new())
+    } // if
+} // fn
+
 // -----------------------------------------------------------------------------
 //
 // Trait Implementations
@@ -111,15 +318,40 @@ impl<K: Ord + Clone> Clone for SearchIndex<K> {
     fn clone(&self) -> Self {
         Self {
             b_tree_map: self.b_tree_map.clone(),
+            field_b_tree_maps: self.field_b_tree_maps.clone(),
+            scoring_statistics: self.scoring_statistics.clone(),
+            positional_index: self.positional_index.clone(),
+            trigram_index: self.trigram_index.clone(),
+            deletion_index: self.deletion_index.clone(),
+            phonetic_index: self.phonetic_index.clone(),
             search_type: self.search_type.clone(),
             autocomplete_type: self.autocomplete_type.clone(),
+            scoring_type: self.scoring_type.clone(),
+            bm25_k1: self.bm25_k1,
+            bm25_b: self.bm25_b,
+            index_positions: self.index_positions,
+            infix_matching: self.infix_matching,
+            pattern_matching: self.pattern_matching,
+            maximum_pattern_expansions: self.maximum_pattern_expansions,
             eddie_metric: self.eddie_metric.clone(),
             rapidfuzz_metric: self.rapidfuzz_metric.clone(),
             strsim_metric: self.strsim_metric.clone(),
             fuzzy_length: self.fuzzy_length,
             fuzzy_minimum_score: self.fuzzy_minimum_score,
+            fuzzy_candidates: self.fuzzy_candidates.clone(),
+            fuzzy_distance: self.fuzzy_distance,
+            fuzzy_fallback: self.fuzzy_fallback,
+            maximum_fuzzy_expansions: self.maximum_fuzzy_expansions,
+            autocomplete_strategy: self.autocomplete_strategy.clone(),
+            search_strategy: self.search_strategy.clone(),
+            phonetic_matching: self.phonetic_matching.clone(),
             split_pattern: self.split_pattern.clone(),
+            tokenizer: self.tokenizer.clone(),
+            token_filters: self.token_filters.clone(),
             case_sensitive: self.case_sensitive,
+            normalization_form: self.normalization_form.clone(),
+            case_folding: self.case_folding.clone(),
+            remove_combining_marks: self.remove_combining_marks,
             minimum_keyword_length: self.minimum_keyword_length,
             maximum_keyword_length: self.maximum_keyword_length,
             maximum_string_length: self.maximum_string_length,
@@ -128,6 +360,8 @@ impl<K: Ord + Clone> Clone for SearchIndex<K> {
             maximum_search_results: self.maximum_search_results,
             maximum_keys_per_keyword: self.maximum_keys_per_keyword,
             dump_keyword: self.dump_keyword.clone(),
+            field_weights: self.field_weights.clone(),
+            synonyms: self.synonyms.clone(),
             empty_b_tree_set: self.empty_b_tree_set.clone(),
             #[cfg(feature = "icu_normalizer")]
             icu_normalizer: icu_normalizer::ComposingNormalizer::new_nfkc(),
@@ -138,15 +372,44 @@ impl<K: Ord + Clone> Clone for SearchIndex<K> {
 impl<K: Ord + PartialEq> PartialEq for SearchIndex<K> {
     fn eq(&self, other: &Self) -> bool {
         self.b_tree_map == other.b_tree_map
+            && self.field_b_tree_maps == other.field_b_tree_maps
+            && self.scoring_statistics == other.scoring_statistics
+            && self.positional_index == other.positional_index
+            && self.trigram_index == other.trigram_index
+            && self.deletion_index == other.deletion_index
+            && self.phonetic_index == other.phonetic_index
             && self.search_type == other.search_type
             && self.autocomplete_type == other.autocomplete_type
+            && self.scoring_type == other.scoring_type
+            && self.bm25_k1 == other.bm25_k1
+            && self.bm25_b == other.bm25_b
+            && self.index_positions == other.index_positions
+            && self.infix_matching == other.infix_matching
+            && self.pattern_matching == other.pattern_matching
+            && self.maximum_pattern_expansions == other.maximum_pattern_expansions
             && self.eddie_metric == other.eddie_metric
             && self.rapidfuzz_metric == other.rapidfuzz_metric
             && self.strsim_metric == other.strsim_metric
             && self.fuzzy_length == other.fuzzy_length
             && self.fuzzy_minimum_score == other.fuzzy_minimum_score
+            && self.fuzzy_candidates == other.fuzzy_candidates
+            && self.fuzzy_distance == other.fuzzy_distance
+            && self.fuzzy_fallback == other.fuzzy_fallback
+            && self.maximum_fuzzy_expansions == other.maximum_fuzzy_expansions
+            && self.autocomplete_strategy == other.autocomplete_strategy
+            && self.search_strategy == other.search_strategy
+            && self.phonetic_matching == other.phonetic_matching
             && self.split_pattern == other.split_pattern
+            && self.tokenizer.as_ref().map(|tokenizer| format!("{tokenizer:?}"))
+                == other.tokenizer.as_ref().map(|tokenizer| format!("{tokenizer:?}"))
+            && self.token_filters.len() == other.token_filters.len()
+            && self.token_filters.iter().zip(&other.token_filters).all(|(filter, other)|
+                format!("{filter:?}") == format!("{other:?}")
+            ) // all
             && self.case_sensitive == other.case_sensitive
+            && self.normalization_form == other.normalization_form
+            && self.case_folding == other.case_folding
+            && self.remove_combining_marks == other.remove_combining_marks
             && self.minimum_keyword_length == other.minimum_keyword_length
             && self.maximum_keyword_length == other.maximum_keyword_length
             && self.maximum_string_length == other.maximum_string_length
@@ -155,7 +418,12 @@ impl<K: Ord + PartialEq> PartialEq for SearchIndex<K> {
             && self.maximum_search_results == other.maximum_search_results
             && self.maximum_keys_per_keyword == other.maximum_keys_per_keyword
             && self.dump_keyword == other.dump_keyword
+            && self.field_weights == other.field_weights
+            && self.synonyms == other.synonyms
             && self.empty_b_tree_set == other.empty_b_tree_set
+        // Note: tokenizers and token filters are compared by their `Debug`
+        // output, since trait objects can't be compared directly.
+        //
         // Note: icu_normalizer is intentionally excluded since
         // ComposingNormalizer doesn't implement PartialEq
     }
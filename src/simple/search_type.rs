@@ -14,6 +14,7 @@
 /// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
 /// [`SearchIndex::new()`]: struct.SearchIndex.html#method.new
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[repr(u8)]
 pub enum SearchType {
     /// Interactive `Live` search allows for "search as you type." It is a
@@ -29,7 +30,12 @@ pub enum SearchType {
     ///
     /// You may change & control the ordering of your records by manually
     /// implementing the [Ord](https://doc.rust-lang.org/std/cmp/trait.Ord.html)
-    /// trait for your `K` key.
+    /// trait for your `K` key. If field weights have been set with
+    /// `SearchIndexBuilder::field_weights` or a [`ScoringType`] other than
+    /// `HitCount`, the results are instead returned in order of descending
+    /// relevance.
+    ///
+    /// [`ScoringType`]: enum.ScoringType.html
     ///
     /// This conjuction uses the most CPU resources because the search results
     /// must be gathered for several autocompletion options.
@@ -49,6 +55,10 @@ pub enum SearchType {
     /// implementing the [Ord](https://doc.rust-lang.org/std/cmp/trait.Ord.html)
     /// trait for your `K` key.
     ///
+    /// If field weights or a scoring type other than `HitCount` have been set,
+    /// the results are instead returned in order of descending relevance. See
+    /// [`ScoringType`](enum.ScoringType.html).
+    ///
     /// This conjuction uses less CPU resources than `Or`.
     ///
     /// The `And` search feels more like "use my keywords to filter out the
@@ -66,6 +76,9 @@ pub enum SearchType {
     ///
     /// The results are returned in order of descending relevance. Records
     /// containing both keywords `this` and `that` will be the top results.
+    /// Keyword hits may be weighted by field with
+    /// `SearchIndexBuilder::field_weights`, and scored with
+    /// [`ScoringType`](enum.ScoringType.html).
     ///
     /// This conjuction uses more CPU resources than `And` because the keyword
     /// hits must be tallied and sorted.
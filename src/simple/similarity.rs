@@ -0,0 +1,55 @@
+// -----------------------------------------------------------------------------
+//
+/// A `Similarity` scores how alike two strings are. It's used to find the
+/// fuzzy matches of a user's keyword among the search index's keywords, for
+/// autocompletion, `Live` searches, `fuzzy_fallback`, and scored searches.
+///
+/// The built-in metrics ([`RapidfuzzMetric`], [`StrsimMetric`],
+/// [`EddieMetric`]) implement this trait, as do [`FuzzyMetric`] and
+/// [`FuzzyStrategy`]. A custom similarity (i.e. one that weighs keyboard
+/// distance or phonetics for a specific domain) can be used by implementing
+/// this trait and adding it to a `FuzzyStrategy`:
+///
+/// ```rust
+/// # use indicium::simple::{FuzzyStrategy, SearchIndex, SearchIndexBuilder, Similarity};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// /// Scores strings by the share of chars that they have in common.
+/// #[derive(Debug)]
+/// struct SharedChars;
+///
+/// impl Similarity for SharedChars {
+///     fn similarity(&self, a: &str, b: &str) -> f64 {
+///         let shared = a.chars().filter(|char| b.contains(*char)).count();
+///         shared as f64 / a.chars().count().max(b.chars().count()) as f64
+///     }
+/// }
+///
+/// # #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))] {
+/// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+///     .autocomplete_strategy(Some(FuzzyStrategy::custom(SharedChars)))
+///     .build();
+///
+/// search_index.insert(&0, &"Aspirin");
+/// search_index.insert(&1, &"Atenolol");
+///
+/// assert_eq!(search_index.autocomplete("asprini"), vec!["aspirin".to_string()]);
+/// # }
+/// ```
+///
+/// Fuzzy matching requires the `eddie`, `rapidfuzz`, or `strsim` feature,
+/// even if only custom similarities are used.
+///
+/// [`RapidfuzzMetric`]: crate::simple::RapidfuzzMetric
+/// [`StrsimMetric`]: crate::simple::StrsimMetric
+/// [`EddieMetric`]: crate::simple::EddieMetric
+/// [`FuzzyMetric`]: crate::simple::FuzzyMetric
+/// [`FuzzyStrategy`]: crate::simple::FuzzyStrategy
+pub trait Similarity: std::fmt::Debug + Send + Sync {
+    /// Calculates the normalized similarity of two strings. The score should
+    /// be between `0.0` and `1.0` (inclusive), where `1.0` means the strings
+    /// are the same. Scores are compared against the `fuzzy_minimum_score`
+    /// setting.
+    #[must_use]
+    fn similarity(&self, a: &str, b: &str) -> f64;
+} // Similarity
@@ -0,0 +1,233 @@
+use crate::simple::snapshot::{SnapshotError, SnapshotKey};
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// Reads the primitive values of the snapshot format. This mirrors the
+/// `Encoder`. Every read is bounds-checked, so truncated or damaged snapshots
+/// return an error rather than panicking.
+pub struct Decoder<'b, K> {
+    /// The unread part of the snapshot.
+    bytes: &'b [u8],
+
+    /// The snapshot's key table, once it has been read. Keys are looked up by
+    /// their ordinal.
+    pub(crate) keys: Vec<K>,
+} // Decoder
+
+// -----------------------------------------------------------------------------
+
+impl<'b, K: Clone + Ord + SnapshotKey> Decoder<'b, K> {
+    /// Instantiates a decoder for the snapshot bytes.
+    pub(crate) const fn new(bytes: &'b [u8]) -> Self {
+        Self {
+            bytes,
+            keys: Vec::new(),
+        } // Decoder
+    } // fn
+
+    /// Reads raw bytes of the given length.
+    pub(crate) fn raw(&mut self, length: usize) -> Result<&'b [u8], SnapshotError> {
+        if length > self.bytes.len() {
+            return Err(SnapshotError::Corrupt("unexpected end of snapshot"));
+        } // if
+
+        let (bytes, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(bytes)
+    } // fn
+
+    /// Reads a single byte.
+    pub(crate) fn u8(&mut self) -> Result<u8, SnapshotError> {
+        Ok(self.raw(1)?[0])
+    } // fn
+
+    /// Reads a boolean.
+    pub(crate) fn bool(&mut self) -> Result<bool, SnapshotError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(SnapshotError::Corrupt("invalid boolean")),
+        } // match
+    } // fn
+
+    /// Reads a LEB128 variable-length integer.
+    pub(crate) fn varint(&mut self) -> Result<u64, SnapshotError> {
+        let mut value: u64 = 0;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            let bits = u64::from(byte & 0x7f);
+            // The tenth byte may only hold the integer's highest bit:
+            if shift == 63 && bits > 1 {
+                break;
+            } // if
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            } // if
+        } // for
+
+        Err(SnapshotError::Corrupt("invalid integer"))
+    } // fn
+
+    /// Reads a variable-length integer as a `usize`.
+    pub(crate) fn usize(&mut self) -> Result<usize, SnapshotError> {
+        usize::try_from(self.varint()?)
+            .map_err(|_| SnapshotError::Corrupt("integer is too large for this platform"))
+    } // fn
+
+    /// Reads a float from 8 little-endian bytes.
+    pub(crate) fn f64(&mut self) -> Result<f64, SnapshotError> {
+        let bytes: [u8; 8] = self.raw(8)?
+            .try_into()
+            .map_err(|_| SnapshotError::Corrupt("invalid float"))?;
+        Ok(f64::from_le_bytes(bytes))
+    } // fn
+
+    /// Reads bytes that are preceded by their length.
+    pub(crate) fn bytes(&mut self) -> Result<&'b [u8], SnapshotError> {
+        let length = self.usize()?;
+        self.raw(length)
+    } // fn
+
+    /// Reads a string that is preceded by its length in bytes.
+    pub(crate) fn str(&mut self) -> Result<&'b str, SnapshotError> {
+        std::str::from_utf8(self.bytes()?)
+            .map_err(|_| SnapshotError::Corrupt("invalid UTF-8 in string"))
+    } // fn
+
+    /// Reads an optional value.
+    pub(crate) fn option<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, SnapshotError>,
+    ) -> Result<Option<T>, SnapshotError> {
+        if self.bool()? {
+            read(self).map(Some)
+        } else {
+            Ok(None)
+        } // if
+    } // fn
+
+    /// Reads a key using its `SnapshotKey` implementation. This is only used
+    /// for the key table.
+    pub(crate) fn key(&mut self) -> Result<K, SnapshotError> {
+        K::read_key(self.bytes()?)
+            .ok_or(SnapshotError::Corrupt("invalid key in the key table"))
+    } // fn
+
+    /// Reads a key ordinal delta, and returns the key's ordinal.
+    fn ordinal(&mut self, previous: usize) -> Result<usize, SnapshotError> {
+        previous
+            .checked_add(self.usize()?)
+            .filter(|ordinal| *ordinal < self.keys.len())
+            .ok_or(SnapshotError::Corrupt("key ordinal is out of range"))
+    } // fn
+
+    /// Reads a front-coded keyword dictionary, and the value that follows each
+    /// keyword. Keywords must be in ascending order.
+    pub(crate) fn dictionary<V>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<V, SnapshotError>,
+    ) -> Result<BTreeMap<KString, V>, SnapshotError> {
+        let length = self.usize()?;
+
+        let mut dictionary: BTreeMap<KString, V> = BTreeMap::new();
+        let mut keyword: Vec<u8> = Vec::new();
+
+        for _ in 0..length {
+            let shared = self.usize()?;
+            if shared > keyword.len() {
+                return Err(SnapshotError::Corrupt("invalid keyword prefix"));
+            } // if
+            keyword.truncate(shared);
+            keyword.extend_from_slice(self.bytes()?);
+
+            let current = std::str::from_utf8(&keyword)
+                .map_err(|_| SnapshotError::Corrupt("invalid UTF-8 in keyword"))?;
+
+            if dictionary.last_key_value().is_some_and(|(last, _value)| last.as_str() >= current) {
+                return Err(SnapshotError::Corrupt("keyword dictionary is not sorted"));
+            } // if
+
+            let current = KString::from_ref(current);
+            let value = read(self)?;
+            dictionary.insert(current, value);
+        } // for
+
+        Ok(dictionary)
+    } // fn
+
+    /// Reads a posting list of delta-encoded key ordinals.
+    pub(crate) fn postings(&mut self) -> Result<BTreeSet<K>, SnapshotError> {
+        let length = self.usize()?;
+
+        let mut postings: BTreeSet<K> = BTreeSet::new();
+        let mut previous: usize = 0;
+
+        for index in 0..length {
+            let ordinal = self.ordinal(previous)?;
+            // Ordinals must be ascending:
+            if index > 0 && ordinal == previous {
+                return Err(SnapshotError::Corrupt("posting list is not sorted"));
+            } // if
+            postings.insert(self.keys[ordinal].clone());
+            previous = ordinal;
+        } // for
+
+        Ok(postings)
+    } // fn
+
+    /// Reads a map of delta-encoded key ordinals, and the value that follows
+    /// each key.
+    pub(crate) fn key_map<V>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<V, SnapshotError>,
+    ) -> Result<BTreeMap<K, V>, SnapshotError> {
+        let length = self.usize()?;
+
+        let mut map: BTreeMap<K, V> = BTreeMap::new();
+        let mut previous: usize = 0;
+
+        for index in 0..length {
+            let ordinal = self.ordinal(previous)?;
+            // Ordinals must be ascending:
+            if index > 0 && ordinal == previous {
+                return Err(SnapshotError::Corrupt("key map is not sorted"));
+            } // if
+            let value = read(self)?;
+            map.insert(self.keys[ordinal].clone(), value);
+            previous = ordinal;
+        } // for
+
+        Ok(map)
+    } // fn
+
+    /// Reads a list of delta-encoded keyword positions.
+    pub(crate) fn positions(&mut self) -> Result<Vec<usize>, SnapshotError> {
+        let length = self.usize()?;
+
+        let mut positions: Vec<usize> = Vec::new();
+        let mut previous: usize = 0;
+
+        for _ in 0..length {
+            let position = previous
+                .checked_add(self.usize()?)
+                .ok_or(SnapshotError::Corrupt("keyword position is out of range"))?;
+            positions.push(position);
+            previous = position;
+        } // for
+
+        Ok(positions)
+    } // fn
+
+    /// Checks that the entire snapshot has been read.
+    pub(crate) fn finish(self) -> Result<(), SnapshotError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(SnapshotError::Corrupt("unexpected data after the end of the snapshot"))
+        } // if
+    } // fn
+} // impl
@@ -0,0 +1,212 @@
+use crate::simple::snapshot::{SnapshotError, SnapshotKey};
+use kstring::KString;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{BufWriter, Write},
+};
+
+// -----------------------------------------------------------------------------
+//
+/// Writes the primitive values of the snapshot format. Integers are written as
+/// variable-length integers (LEB128), and keys are written as ordinals into the
+/// snapshot's sorted key table.
+pub struct Encoder<'k, K, W: Write> {
+    /// The destination of the snapshot.
+    writer: BufWriter<W>,
+
+    /// Every key in the search index, in sorted order. A key's position in
+    /// this table is its ordinal.
+    keys: Vec<&'k K>,
+
+    /// A reusable buffer for converting keys to bytes.
+    buffer: Vec<u8>,
+} // Encoder
+
+// -----------------------------------------------------------------------------
+
+impl<'k, K: Ord + SnapshotKey, W: Write> Encoder<'k, K, W> {
+    /// Instantiates an encoder for the writer. The keys must be sorted and
+    /// unique.
+    pub(crate) fn new(writer: W, keys: Vec<&'k K>) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            keys,
+            buffer: Vec::new(),
+        } // Encoder
+    } // fn
+
+    /// Writes raw bytes, without their length.
+    pub(crate) fn raw(&mut self, bytes: &[u8]) -> Result<(), SnapshotError> {
+        Ok(self.writer.write_all(bytes)?)
+    } // fn
+
+    /// Writes a single byte.
+    pub(crate) fn u8(&mut self, value: u8) -> Result<(), SnapshotError> {
+        self.raw(&[value])
+    } // fn
+
+    /// Writes a boolean as a single byte.
+    pub(crate) fn bool(&mut self, value: bool) -> Result<(), SnapshotError> {
+        self.u8(u8::from(value))
+    } // fn
+
+    /// Writes an unsigned integer as a LEB128 variable-length integer. Small
+    /// numbers, such as posting list deltas, only take a single byte.
+    pub(crate) fn varint(&mut self, mut value: u64) -> Result<(), SnapshotError> {
+        let mut bytes = [0_u8; 10];
+        let mut length = 0;
+
+        loop {
+            #[allow(clippy::cast_possible_truncation)]
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes[length] = byte;
+                length += 1;
+                break;
+            } // if
+            bytes[length] = byte | 0x80;
+            length += 1;
+        } // loop
+
+        self.raw(&bytes[..length])
+    } // fn
+
+    /// Writes a `usize` as a variable-length integer.
+    pub(crate) fn usize(&mut self, value: usize) -> Result<(), SnapshotError> {
+        self.varint(value as u64)
+    } // fn
+
+    /// Writes a float as 8 little-endian bytes.
+    pub(crate) fn f64(&mut self, value: f64) -> Result<(), SnapshotError> {
+        self.raw(&value.to_le_bytes())
+    } // fn
+
+    /// Writes bytes, preceded by their length.
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) -> Result<(), SnapshotError> {
+        self.usize(bytes.len())?;
+        self.raw(bytes)
+    } // fn
+
+    /// Writes a string, preceded by its length in bytes.
+    pub(crate) fn str(&mut self, string: &str) -> Result<(), SnapshotError> {
+        self.bytes(string.as_bytes())
+    } // fn
+
+    /// Writes an optional value, preceded by a byte indicating whether it's
+    /// present.
+    pub(crate) fn option<T>(
+        &mut self,
+        value: Option<T>,
+        write: impl FnOnce(&mut Self, T) -> Result<(), SnapshotError>,
+    ) -> Result<(), SnapshotError> {
+        match value {
+            Some(value) => {
+                self.bool(true)?;
+                write(self, value)
+            } // Some
+            None => self.bool(false),
+        } // match
+    } // fn
+
+    /// Writes a key using its `SnapshotKey` implementation. This is only used
+    /// for the key table. Everywhere else, keys are written as ordinals.
+    pub(crate) fn key(&mut self, key: &K) -> Result<(), SnapshotError> {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        key.write_key(&mut buffer);
+        let result = self.bytes(&buffer);
+        self.buffer = buffer;
+        result
+    } // fn
+
+    /// Returns the key's position in the key table.
+    fn ordinal(&self, key: &K) -> Result<usize, SnapshotError> {
+        self.keys
+            .binary_search(&key)
+            .map_err(|_| SnapshotError::Corrupt("key is missing from the key table"))
+    } // fn
+
+    /// Writes a sorted keyword dictionary. Each keyword is front-coded: only
+    /// the length of the prefix it shares with the previous keyword, and the
+    /// rest of its bytes, are written. Each keyword is followed by its value.
+    pub(crate) fn dictionary<V>(
+        &mut self,
+        dictionary: &BTreeMap<KString, V>,
+        mut write: impl FnMut(&mut Self, &V) -> Result<(), SnapshotError>,
+    ) -> Result<(), SnapshotError> {
+        self.usize(dictionary.len())?;
+
+        let mut previous: &[u8] = &[];
+
+        for (keyword, value) in dictionary {
+            let keyword = keyword.as_bytes();
+            let shared = previous
+                .iter()
+                .zip(keyword)
+                .take_while(|(previous, current)| previous == current)
+                .count();
+            self.usize(shared)?;
+            self.bytes(&keyword[shared..])?;
+            write(self, value)?;
+            previous = keyword;
+        } // for
+
+        Ok(())
+    } // fn
+
+    /// Writes a posting list as delta-encoded key ordinals.
+    pub(crate) fn postings(&mut self, keys: &BTreeSet<K>) -> Result<(), SnapshotError> {
+        self.usize(keys.len())?;
+
+        let mut previous: usize = 0;
+
+        for key in keys {
+            let ordinal = self.ordinal(key)?;
+            self.usize(ordinal - previous)?;
+            previous = ordinal;
+        } // for
+
+        Ok(())
+    } // fn
+
+    /// Writes a map of keys to values. Keys are written as delta-encoded
+    /// ordinals, and each key is followed by its value.
+    pub(crate) fn key_map<V>(
+        &mut self,
+        map: &BTreeMap<K, V>,
+        mut write: impl FnMut(&mut Self, &V) -> Result<(), SnapshotError>,
+    ) -> Result<(), SnapshotError> {
+        self.usize(map.len())?;
+
+        let mut previous: usize = 0;
+
+        for (key, value) in map {
+            let ordinal = self.ordinal(key)?;
+            self.usize(ordinal - previous)?;
+            write(self, value)?;
+            previous = ordinal;
+        } // for
+
+        Ok(())
+    } // fn
+
+    /// Writes a list of ascending keyword positions as deltas.
+    pub(crate) fn positions(&mut self, positions: &[usize]) -> Result<(), SnapshotError> {
+        self.usize(positions.len())?;
+
+        let mut previous: usize = 0;
+
+        for position in positions {
+            self.usize(position - previous)?;
+            previous = *position;
+        } // for
+
+        Ok(())
+    } // fn
+
+    /// Flushes the snapshot to the writer.
+    pub(crate) fn finish(mut self) -> Result<(), SnapshotError> {
+        Ok(self.writer.flush()?)
+    } // fn
+} // impl
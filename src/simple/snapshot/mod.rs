@@ -0,0 +1,130 @@
+//! A compact, versioned binary snapshot format for `SearchIndex`. Loading a
+//! snapshot is much faster than re-indexing every record.
+//!
+//! A snapshot is laid out as follows. Integers are LEB128 variable-length
+//! integers unless noted otherwise:
+//!
+//! 1. **Header.** The `INDICIUM` magic bytes, the format version, and the
+//!    settings that the search index uses but that can't be saved in a
+//!    snapshot. A snapshot that needs any of them is refused by
+//!    `read_snapshot`, rather than loaded into a search index that silently
+//!    behaves differently.
+//! 2. **Build features.** The fuzzy matching crates and the Unicode
+//!    normalization crate that the search index was built with. These are
+//!    validated when the snapshot is loaded.
+//! 3. **Configuration.** Every `SearchIndex` setting, except for a custom
+//!    `Tokenizer`, any `TokenFilter`s, and the custom `Similarity` metrics
+//!    of fuzzy matching strategies, which are only recorded in the header.
+//! 4. **Key table.** Every key in the search index, in sorted order, written
+//!    with the `SnapshotKey` trait. Everywhere else, keys are written as their
+//!    ordinal in this table.
+//! 5. **Keyword dictionary.** Every keyword in sorted, front-coded form, each
+//!    followed by its posting list of delta-encoded key ordinals.
+//! 6. **Named fields.** A keyword dictionary for each named field.
+//! 7. **Scoring statistics.** Record lengths, keyword frequencies, and the
+//!    number of records that contain each keyword for the `Bm25` and `TfIdf`
+//!    scoring types.
+//! 8. **Keyword positions.** Delta-encoded keyword positions for phrase and
+//!    proximity searches.
+
+mod decoder;
+mod encoder;
+mod read;
+mod snapshot_error;
+mod snapshot_key;
+mod write;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::simple::snapshot::snapshot_error::SnapshotError;
+pub use crate::simple::snapshot::snapshot_key::SnapshotKey;
+
+// -----------------------------------------------------------------------------
+//
+/// The bytes that every snapshot begins with.
+const MAGIC: &[u8; 8] = b"INDICIUM";
+
+/// The version of the snapshot format. This is incremented whenever the format
+/// changes in a way that older versions of `indicium` can't read.
+const FORMAT_VERSION: u32 = 2;
+
+// -----------------------------------------------------------------------------
+//
+/// The header bit for a custom `Tokenizer`.
+const CUSTOM_TOKENIZER: u8 = 1 << 0;
+
+/// The header bit for a chain of `TokenFilter`s.
+const TOKEN_FILTERS: u8 = 1 << 1;
+
+/// The header bit for a fuzzy matching strategy with custom `Similarity`
+/// metrics.
+const CUSTOM_SIMILARITY: u8 = 1 << 2;
+
+/// Returns the header bits of the settings that a search index uses but that
+/// can't be saved in a snapshot.
+fn unsaved_settings<K: Ord>(search_index: &crate::simple::SearchIndex<K>) -> u8 {
+    let mut bits: u8 = 0;
+
+    if search_index.tokenizer.is_some() {
+        bits |= CUSTOM_TOKENIZER;
+    } // if
+
+    if !search_index.token_filters.is_empty() {
+        bits |= TOKEN_FILTERS;
+    } // if
+
+    if [&search_index.autocomplete_strategy, &search_index.search_strategy]
+        .into_iter()
+        .flatten()
+        .any(|strategy| !strategy.custom.is_empty())
+    {
+        bits |= CUSTOM_SIMILARITY;
+    } // if
+
+    bits
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The names of the fuzzy matching crates, indexed by their identifier in the
+/// snapshot. The snapshot stores the crates that it uses as bits, where each
+/// crate's bit is `1 << identifier`.
+const FUZZY_MATCHING: [&str; 3] = ["eddie", "rapidfuzz", "strsim"];
+
+/// Returns the bits of the fuzzy matching crates that this build uses.
+const fn build_fuzzy_matching() -> u8 {
+    let mut bits: u8 = 0;
+
+    if cfg!(feature = "eddie") {
+        bits |= 1 << 0;
+    } // if
+
+    if cfg!(feature = "rapidfuzz") {
+        bits |= 1 << 1;
+    } // if
+
+    if cfg!(feature = "strsim") {
+        bits |= 1 << 2;
+    } // if
+
+    bits
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The names of the Unicode normalization crates, indexed by their identifier
+/// in the snapshot.
+const NORMALIZATION: [&str; 3] = ["none", "unicode-normalization", "icu_normalizer"];
+
+/// Returns the identifier of the Unicode normalization crate that this build
+/// uses. Keywords are stored in normalized form, so a snapshot can only be
+/// loaded by a build that normalizes the same way.
+const fn build_normalization() -> u8 {
+    if cfg!(feature = "icu_normalizer") {
+        2
+    } else if cfg!(feature = "unicode-normalization") {
+        1
+    } else {
+        0
+    } // if
+} // fn
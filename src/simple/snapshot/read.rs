@@ -0,0 +1,603 @@
+use crate::simple::{
+    internal::{
+        deletions::DeletionIndex,
+        phonetics::PhoneticIndex,
+        positions::PositionalIndex,
+        scoring::ScoringStatistics,
+        trigrams::TrigramIndex,
+    },
+    snapshot::{
+        build_fuzzy_matching,
+        build_normalization,
+        decoder::Decoder,
+        SnapshotError,
+        SnapshotKey,
+        CUSTOM_SIMILARITY,
+        CUSTOM_TOKENIZER,
+        FORMAT_VERSION,
+        FUZZY_MATCHING,
+        MAGIC,
+        NORMALIZATION,
+        TOKEN_FILTERS,
+    },
+    AutocompleteType,
+    CaseFolding,
+    EddieMetric,
+    FuzzyCandidates,
+    FuzzyMetric,
+    FuzzyStrategy,
+    NormalizationForm,
+    PhoneticAlgorithm,
+    RapidfuzzMetric,
+    ScoringType,
+    SearchIndex,
+    SearchType,
+    StrsimMetric,
+    TokenFilter,
+    Tokenizer,
+};
+use kstring::KString;
+use std::{collections::{BTreeMap, BTreeSet}, io::Read, sync::Arc};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord + SnapshotKey> SearchIndex<K> {
+    /// Loads a search index from a snapshot that was written by
+    /// `write_snapshot`. The entire snapshot is read into memory before it's
+    /// decoded.
+    ///
+    /// # Errors
+    ///
+    /// * `SnapshotError::Io` if the reader fails.
+    ///
+    /// * `SnapshotError::InvalidMagic` or `SnapshotError::UnsupportedVersion`
+    ///   if the data isn't a snapshot this version of `indicium` can read.
+    ///
+    /// * `SnapshotError::FeatureMismatch` if the snapshot was written by a
+    ///   build with a different fuzzy matching or Unicode normalization crate.
+    ///
+    /// * `SnapshotError::MissingTokenizer` or `SnapshotError::MissingTokenFilters`
+    ///   if the search index used a custom tokenizer or token filters. These
+    ///   can't be saved in a snapshot, and must be given to
+    ///   `read_snapshot_with` instead.
+    ///
+    /// * `SnapshotError::CustomSimilarity` if the search index used custom
+    ///   similarity metrics. These can't be saved in a snapshot.
+    ///
+    /// * `SnapshotError::Corrupt` if the snapshot is truncated or damaged.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SnapshotError};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<String> = SearchIndex::default();
+    /// search_index.insert(&"harold".to_string(), &"Harold Godwinson");
+    ///
+    /// let mut snapshot: Vec<u8> = Vec::new();
+    /// search_index.write_snapshot(&mut snapshot).unwrap();
+    ///
+    /// let loaded: SearchIndex<String> = SearchIndex::read_snapshot(snapshot.as_slice()).unwrap();
+    /// assert_eq!(loaded.search("harold"), vec![&"harold".to_string()]);
+    ///
+    /// // Anything else is rejected:
+    /// let error = SearchIndex::<String>::read_snapshot("not a snapshot".as_bytes());
+    /// assert!(matches!(error, Err(SnapshotError::InvalidMagic)));
+    /// ```
+    #[tracing::instrument(level = "trace", name = "read snapshot", skip(reader))]
+    pub fn read_snapshot(reader: impl Read) -> Result<Self, SnapshotError> {
+        Self::read_snapshot_with(reader, None, Vec::new())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Loads a search index that used a custom `Tokenizer` or `TokenFilter`s
+    /// from a snapshot. These can't be saved in a snapshot, so the same
+    /// tokenizer and token filters must be given again, in the same order.
+    /// See `read_snapshot` for more information.
+    ///
+    /// # Errors
+    ///
+    /// See `read_snapshot`. Also returns `SnapshotError::UnexpectedTokenizer`
+    /// or `SnapshotError::UnexpectedTokenFilters` if a tokenizer or token
+    /// filters are given, but the search index didn't use them.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{CharTokenizer, SearchIndex, SearchIndexBuilder, SnapshotError, Tokenizer};
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::sync::Arc;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .tokenizer(CharTokenizer::new(vec!['/']))
+    ///     .build();
+    /// search_index.insert(&0, &"usr/local/bin");
+    ///
+    /// let mut snapshot: Vec<u8> = Vec::new();
+    /// search_index.write_snapshot(&mut snapshot).unwrap();
+    ///
+    /// // The tokenizer must be given again:
+    /// let error = SearchIndex::<usize>::read_snapshot(snapshot.as_slice());
+    /// assert!(matches!(error, Err(SnapshotError::MissingTokenizer)));
+    ///
+    /// let tokenizer: Arc<dyn Tokenizer> = Arc::new(CharTokenizer::new(vec!['/']));
+    /// let loaded: SearchIndex<usize> =
+    ///     SearchIndex::read_snapshot_with(snapshot.as_slice(), Some(tokenizer), Vec::new()).unwrap();
+    /// assert_eq!(loaded.search("local"), vec![&0]);
+    /// ```
+    #[tracing::instrument(level = "trace", name = "read snapshot", skip(reader, tokenizer, token_filters))]
+    pub fn read_snapshot_with(
+        mut reader: impl Read,
+        tokenizer: Option<Arc<dyn Tokenizer>>,
+        token_filters: Vec<Arc<dyn TokenFilter>>,
+    ) -> Result<Self, SnapshotError> {
+        let mut bytes: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_snapshot_bytes_with(&bytes, tokenizer, token_filters)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Loads a search index from snapshot bytes that are already in memory,
+    /// such as a memory-mapped file. See `read_snapshot` for more information.
+    ///
+    /// # Errors
+    ///
+    /// See `read_snapshot`. `SnapshotError::Io` is never returned.
+    pub fn from_snapshot_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        Self::from_snapshot_bytes_with(bytes, None, Vec::new())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Loads a search index that used a custom `Tokenizer` or `TokenFilter`s
+    /// from snapshot bytes that are already in memory. See
+    /// `read_snapshot_with` for more information.
+    ///
+    /// # Errors
+    ///
+    /// See `read_snapshot_with`. `SnapshotError::Io` is never returned.
+    #[allow(clippy::too_many_lines)]
+    #[tracing::instrument(level = "trace", name = "read snapshot", skip(bytes, tokenizer, token_filters))]
+    pub fn from_snapshot_bytes_with(
+        bytes: &[u8],
+        tokenizer: Option<Arc<dyn Tokenizer>>,
+        token_filters: Vec<Arc<dyn TokenFilter>>,
+    ) -> Result<Self, SnapshotError> {
+        let mut decoder: Decoder<K> = Decoder::new(bytes);
+
+        // Header:
+        if decoder.raw(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+            return Err(SnapshotError::InvalidMagic);
+        } // if
+
+        let version = decoder.varint()?;
+        if version != u64::from(FORMAT_VERSION) {
+            return Err(SnapshotError::UnsupportedVersion {
+                found: u32::try_from(version).unwrap_or(u32::MAX),
+                supported: FORMAT_VERSION,
+            }); // UnsupportedVersion
+        } // if
+
+        // Settings that the search index used, but that weren't saved. The
+        // tokenizer and token filters must be given by the caller instead:
+        let unsaved = decoder.u8()?;
+        if unsaved & !(CUSTOM_TOKENIZER | TOKEN_FILTERS | CUSTOM_SIMILARITY) != 0 {
+            return Err(SnapshotError::Corrupt("unknown unsaved settings"));
+        } else if unsaved & CUSTOM_SIMILARITY != 0 {
+            return Err(SnapshotError::CustomSimilarity);
+        } // if
+
+        match (unsaved & CUSTOM_TOKENIZER != 0, tokenizer.is_some()) {
+            (true, false) => return Err(SnapshotError::MissingTokenizer),
+            (false, true) => return Err(SnapshotError::UnexpectedTokenizer),
+            _ => {},
+        } // match
+
+        match (unsaved & TOKEN_FILTERS != 0, !token_filters.is_empty()) {
+            (true, false) => return Err(SnapshotError::MissingTokenFilters),
+            (false, true) => return Err(SnapshotError::UnexpectedTokenFilters),
+            _ => {},
+        } // match
+
+        // Build features. Every fuzzy matching crate that the snapshot uses
+        // must be enabled in this build. Keywords must be normalized the same
+        // way:
+        let fuzzy_matching = decoder.u8()?;
+        let missing = fuzzy_matching & !build_fuzzy_matching();
+        if missing != 0 {
+            return Err(SnapshotError::FeatureMismatch {
+                setting: "fuzzy matching",
+                snapshot: fuzzy_matching_name(missing)?,
+                build: fuzzy_matching_name(build_fuzzy_matching()).unwrap_or("none"),
+            }); // FeatureMismatch
+        } // if
+
+        let normalization = decoder.u8()?;
+        if normalization != build_normalization() {
+            return Err(SnapshotError::FeatureMismatch {
+                setting: "unicode normalization",
+                snapshot: feature_name(&NORMALIZATION, normalization)?,
+                build: NORMALIZATION[usize::from(build_normalization())],
+            }); // FeatureMismatch
+        } // if
+
+        // Configuration:
+        let search_type = search_type(decoder.u8()?)?;
+        let autocomplete_type = autocomplete_type(decoder.u8()?)?;
+        let scoring_type = scoring_type(decoder.u8()?)?;
+        let bm25_k1 = decoder.f64()?;
+        let bm25_b = decoder.f64()?;
+        let index_positions = decoder.bool()?;
+        let infix_matching = decoder.bool()?;
+        let pattern_matching = decoder.bool()?;
+        let maximum_pattern_expansions = decoder.usize()?;
+        let eddie_metric = decoder.option(|decoder| eddie_metric(decoder.u8()?))?;
+        let rapidfuzz_metric = decoder.option(|decoder| rapidfuzz_metric(decoder.u8()?))?;
+        let strsim_metric = decoder.option(|decoder| strsim_metric(decoder.u8()?))?;
+        let fuzzy_length = decoder.usize()?;
+        let fuzzy_minimum_score = decoder.f64()?;
+        let fuzzy_candidates = fuzzy_candidates(decoder.u8()?)?;
+        let fuzzy_distance = decoder.usize()?;
+        let fuzzy_fallback = decoder.bool()?;
+        let maximum_fuzzy_expansions = decoder.usize()?;
+        let autocomplete_strategy = decoder.option(fuzzy_strategy)?;
+        let search_strategy = decoder.option(fuzzy_strategy)?;
+        let phonetic_matching = decoder.option(|decoder| phonetic_algorithm(decoder.u8()?))?;
+        let split_pattern = decoder.option(|decoder| {
+            (0..decoder.usize()?)
+                .map(|_| u32::try_from(decoder.varint()?)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or(SnapshotError::Corrupt("invalid character in split pattern")))
+                .collect::<Result<Vec<char>, SnapshotError>>()
+        })?;
+        let case_sensitive = decoder.bool()?;
+        let normalization_form = normalization_form(decoder.u8()?)?;
+        let case_folding = case_folding(decoder.u8()?)?;
+        let remove_combining_marks = decoder.bool()?;
+        let minimum_keyword_length = decoder.usize()?;
+        let maximum_keyword_length = decoder.usize()?;
+        let maximum_string_length = decoder.option(Decoder::usize)?;
+        let exclude_keywords = decoder.option(|decoder| {
+            (0..decoder.usize()?)
+                .map(|_| decoder.str().map(KString::from_ref))
+                .collect::<Result<_, SnapshotError>>()
+        })?;
+        let maximum_autocomplete_options = decoder.usize()?;
+        let maximum_search_results = decoder.usize()?;
+        let maximum_keys_per_keyword = decoder.usize()?;
+        let dump_keyword = decoder.option(|decoder| decoder.str().map(KString::from_ref))?;
+        let field_weights = decoder.option(|decoder| {
+            (0..decoder.usize()?)
+                .map(|_| Ok((KString::from_ref(decoder.str()?), decoder.f64()?)))
+                .collect::<Result<BTreeMap<KString, f64>, SnapshotError>>()
+        })?;
+        let synonyms = decoder.dictionary(|decoder| {
+            (0..decoder.usize()?)
+                .map(|_| (0..decoder.usize()?)
+                    .map(|_| decoder.str().map(KString::from_ref))
+                    .collect::<Result<Vec<KString>, SnapshotError>>())
+                .collect::<Result<Vec<Vec<KString>>, SnapshotError>>()
+        })?;
+
+        // Key table. Keys must be unique and in ascending order:
+        let length = decoder.usize()?;
+        let mut keys: Vec<K> = Vec::new();
+        for _ in 0..length {
+            let key = decoder.key()?;
+            if keys.last().is_some_and(|last| *last >= key) {
+                return Err(SnapshotError::Corrupt("key table is not sorted"));
+            } // if
+            keys.push(key);
+        } // for
+        decoder.keys = keys;
+
+        // Keyword dictionary and posting lists:
+        let b_tree_map = decoder.dictionary(Decoder::postings)?;
+
+        // Named fields:
+        let field_b_tree_maps = decoder.dictionary(|decoder| {
+            decoder.dictionary(Decoder::postings)
+        })?;
+
+        // Scoring statistics:
+        let lengths = decoder.key_map(Decoder::usize)?;
+        let frequencies = decoder.dictionary(|decoder| decoder.key_map(Decoder::usize))?;
+        let document_frequencies = decoder.dictionary(Decoder::usize)?;
+        let total_length = lengths
+            .values()
+            .try_fold(0_usize, |total, length| total.checked_add(*length))
+            .ok_or(SnapshotError::Corrupt("record lengths are out of range"))?;
+
+        // Keyword positions:
+        let keywords = decoder.dictionary(|decoder| decoder.key_map(Decoder::positions))?;
+        let fields = decoder.dictionary(|decoder| {
+            decoder.dictionary(|decoder| decoder.key_map(Decoder::positions))
+        })?;
+
+        decoder.finish()?;
+
+        // The trigram index isn't stored. It's rebuilt from the keywords:
+        let trigram_index = if infix_matching {
+            TrigramIndex::from_keywords(b_tree_map.keys())
+        } else {
+            TrigramIndex::default()
+        }; // if
+
+        // Neither is the deletion index:
+        let deletion_index = if fuzzy_candidates == FuzzyCandidates::Deletions {
+            DeletionIndex::from_keywords(b_tree_map.keys(), fuzzy_distance)
+        } else {
+            DeletionIndex::default()
+        }; // if
+
+        // Nor is the phonetic index:
+        let phonetic_index = phonetic_matching.as_ref().map_or_else(
+            PhoneticIndex::default,
+            |algorithm| PhoneticIndex::from_keywords(b_tree_map.keys(), algorithm),
+        ); // map_or_else
+
+        Ok(Self {
+            b_tree_map,
+            field_b_tree_maps,
+            scoring_statistics: ScoringStatistics { lengths, total_length, frequencies, document_frequencies },
+            positional_index: PositionalIndex { keywords, fields },
+            trigram_index,
+            deletion_index,
+            phonetic_index,
+            search_type,
+            autocomplete_type,
+            scoring_type,
+            bm25_k1,
+            bm25_b,
+            index_positions,
+            infix_matching,
+            pattern_matching,
+            maximum_pattern_expansions,
+            eddie_metric,
+            rapidfuzz_metric,
+            strsim_metric,
+            fuzzy_length,
+            fuzzy_minimum_score,
+            fuzzy_candidates,
+            fuzzy_distance,
+            fuzzy_fallback,
+            maximum_fuzzy_expansions,
+            autocomplete_strategy,
+            search_strategy,
+            phonetic_matching,
+            split_pattern,
+            tokenizer,
+            token_filters,
+            case_sensitive,
+            normalization_form,
+            case_folding,
+            remove_combining_marks,
+            minimum_keyword_length,
+            maximum_keyword_length,
+            maximum_string_length,
+            exclude_keywords,
+            maximum_autocomplete_options,
+            maximum_search_results,
+            maximum_keys_per_keyword,
+            dump_keyword,
+            field_weights,
+            synonyms,
+            empty_b_tree_set: BTreeSet::new(),
+            #[cfg(feature = "icu_normalizer")]
+            icu_normalizer: icu_normalizer::ComposingNormalizer::new_nfkc(),
+        }) // SearchIndex
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Loads a search index from a snapshot file by memory-mapping it, rather
+    /// than reading it into a buffer first. See `read_snapshot` for more
+    /// information.
+    ///
+    /// The file must not be modified while it's being loaded.
+    ///
+    /// # Errors
+    ///
+    /// See `read_snapshot`.
+    #[cfg(feature = "memmap2")]
+    #[tracing::instrument(level = "trace", name = "read snapshot", skip(path))]
+    pub fn read_snapshot_file(path: impl AsRef<std::path::Path>) -> Result<Self, SnapshotError> {
+        Self::read_snapshot_file_with(path, None, Vec::new())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Loads a search index that used a custom `Tokenizer` or `TokenFilter`s
+    /// from a snapshot file by memory-mapping it. See `read_snapshot_with` for
+    /// more information.
+    ///
+    /// The file must not be modified while it's being loaded.
+    ///
+    /// # Errors
+    ///
+    /// See `read_snapshot_with`.
+    #[cfg(feature = "memmap2")]
+    #[tracing::instrument(level = "trace", name = "read snapshot", skip(path, tokenizer, token_filters))]
+    pub fn read_snapshot_file_with(
+        path: impl AsRef<std::path::Path>,
+        tokenizer: Option<Arc<dyn Tokenizer>>,
+        token_filters: Vec<Arc<dyn TokenFilter>>,
+    ) -> Result<Self, SnapshotError> {
+        let mmap = map_file(&std::fs::File::open(path)?)?;
+        Self::from_snapshot_bytes_with(&mmap, tokenizer, token_filters)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Memory-maps a snapshot file for reading.
+#[cfg(feature = "memmap2")]
+fn map_file(file: &std::fs::File) -> std::io::Result<memmap2::Mmap> {
+    // SAFETY: The mapping is only read while the search index is being
+    // decoded, and is dropped before `read_snapshot_file_with` returns. The caller
+    // is responsible for not modifying the file in the meantime.
+    unsafe { memmap2::Mmap::map(file) }
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the name of a build feature from its identifier in the snapshot.
+fn feature_name(names: &[&'static str], identifier: u8) -> Result<&'static str, SnapshotError> {
+    names
+        .get(usize::from(identifier))
+        .copied()
+        .ok_or(SnapshotError::Corrupt("unknown build feature"))
+} // fn
+
+/// Returns the name of the first fuzzy matching crate in the bits stored in
+/// the snapshot.
+fn fuzzy_matching_name(bits: u8) -> Result<&'static str, SnapshotError> {
+    FUZZY_MATCHING
+        .get(bits.trailing_zeros() as usize)
+        .copied()
+        .ok_or(SnapshotError::Corrupt("unknown build feature"))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a fuzzy matching strategy: the number of metrics, followed by each
+/// metric's crate identifier, metric, and weight. Custom metrics aren't
+/// saved.
+fn fuzzy_strategy<K: Clone + Ord + SnapshotKey>(
+    decoder: &mut Decoder<'_, K>,
+) -> Result<FuzzyStrategy, SnapshotError> {
+    let metrics = (0..decoder.usize()?)
+        .map(|_| {
+            let metric = fuzzy_metric(decoder.u8()?, decoder.u8()?)?;
+            Ok((metric, decoder.f64()?))
+        }) // map
+        .collect::<Result<Vec<(FuzzyMetric, f64)>, SnapshotError>>()?;
+
+    Ok(FuzzyStrategy { metrics, custom: Vec::new() })
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+// Conversions from the enum discriminants stored in the snapshot:
+
+const fn search_type(value: u8) -> Result<SearchType, SnapshotError> {
+    match value {
+        0 => Ok(SearchType::Live),
+        1 => Ok(SearchType::And),
+        2 => Ok(SearchType::Or),
+        3 => Ok(SearchType::Keyword),
+        _ => Err(SnapshotError::Corrupt("unknown search type")),
+    } // match
+} // fn
+
+const fn autocomplete_type(value: u8) -> Result<AutocompleteType, SnapshotError> {
+    match value {
+        0 => Ok(AutocompleteType::Context),
+        1 => Ok(AutocompleteType::Global),
+        2 => Ok(AutocompleteType::Keyword),
+        _ => Err(SnapshotError::Corrupt("unknown autocomplete type")),
+    } // match
+} // fn
+
+const fn scoring_type(value: u8) -> Result<ScoringType, SnapshotError> {
+    match value {
+        0 => Ok(ScoringType::HitCount),
+        1 => Ok(ScoringType::Bm25),
+        2 => Ok(ScoringType::TfIdf),
+        _ => Err(SnapshotError::Corrupt("unknown scoring type")),
+    } // match
+} // fn
+
+const fn normalization_form(value: u8) -> Result<NormalizationForm, SnapshotError> {
+    match value {
+        0 => Ok(NormalizationForm::Nfc),
+        1 => Ok(NormalizationForm::Nfd),
+        2 => Ok(NormalizationForm::Nfkc),
+        3 => Ok(NormalizationForm::Nfkd),
+        _ => Err(SnapshotError::Corrupt("unknown normalization form")),
+    } // match
+} // fn
+
+const fn case_folding(value: u8) -> Result<CaseFolding, SnapshotError> {
+    match value {
+        0 => Ok(CaseFolding::Lowercase),
+        1 => Ok(CaseFolding::Full),
+        2 => Ok(CaseFolding::Turkic),
+        _ => Err(SnapshotError::Corrupt("unknown case folding")),
+    } // match
+} // fn
+
+const fn fuzzy_candidates(value: u8) -> Result<FuzzyCandidates, SnapshotError> {
+    match value {
+        0 => Ok(FuzzyCandidates::Prefix),
+        1 => Ok(FuzzyCandidates::Deletions),
+        _ => Err(SnapshotError::Corrupt("unknown fuzzy candidates")),
+    } // match
+} // fn
+
+const fn phonetic_algorithm(value: u8) -> Result<PhoneticAlgorithm, SnapshotError> {
+    match value {
+        0 => Ok(PhoneticAlgorithm::Soundex),
+        1 => Ok(PhoneticAlgorithm::Metaphone),
+        2 => Ok(PhoneticAlgorithm::DoubleMetaphone),
+        _ => Err(SnapshotError::Corrupt("unknown phonetic algorithm")),
+    } // match
+} // fn
+
+const fn eddie_metric(value: u8) -> Result<EddieMetric, SnapshotError> {
+    match value {
+        0 => Ok(EddieMetric::Levenshtein),
+        1 => Ok(EddieMetric::DamerauLevenshtein),
+        2 => Ok(EddieMetric::Jaro),
+        3 => Ok(EddieMetric::JaroWinkler),
+        _ => Err(SnapshotError::Corrupt("unknown eddie metric")),
+    } // match
+} // fn
+
+const fn rapidfuzz_metric(value: u8) -> Result<RapidfuzzMetric, SnapshotError> {
+    match value {
+        0 => Ok(RapidfuzzMetric::DamerauLevenshtein),
+        1 => Ok(RapidfuzzMetric::Hamming),
+        2 => Ok(RapidfuzzMetric::Indel),
+        3 => Ok(RapidfuzzMetric::Jaro),
+        4 => Ok(RapidfuzzMetric::JaroWinkler),
+        5 => Ok(RapidfuzzMetric::LcsSeq),
+        6 => Ok(RapidfuzzMetric::Levenshtein),
+        7 => Ok(RapidfuzzMetric::Osa),
+        8 => Ok(RapidfuzzMetric::Postfix),
+        9 => Ok(RapidfuzzMetric::Prefix),
+        10 => Ok(RapidfuzzMetric::KeyboardQwerty),
+        11 => Ok(RapidfuzzMetric::KeyboardAzerty),
+        12 => Ok(RapidfuzzMetric::KeyboardQwertz),
+        _ => Err(SnapshotError::Corrupt("unknown rapidfuzz metric")),
+    } // match
+} // fn
+
+// The match only has the error arm without fuzzy matching:
+#[allow(unused_variables, clippy::missing_const_for_fn)]
+fn fuzzy_metric(crate_identifier: u8, value: u8) -> Result<FuzzyMetric, SnapshotError> {
+    match crate_identifier {
+        #[cfg(feature = "eddie")]
+        0 => Ok(FuzzyMetric::Eddie(eddie_metric(value)?)),
+        #[cfg(feature = "rapidfuzz")]
+        1 => Ok(FuzzyMetric::Rapidfuzz(rapidfuzz_metric(value)?)),
+        #[cfg(feature = "strsim")]
+        2 => Ok(FuzzyMetric::Strsim(strsim_metric(value)?)),
+        _ => Err(SnapshotError::Corrupt("unknown fuzzy metric")),
+    } // match
+} // fn
+
+const fn strsim_metric(value: u8) -> Result<StrsimMetric, SnapshotError> {
+    match value {
+        0 => Ok(StrsimMetric::DamerauLevenshtein),
+        1 => Ok(StrsimMetric::Jaro),
+        2 => Ok(StrsimMetric::JaroWinkler),
+        3 => Ok(StrsimMetric::Levenshtein),
+        4 => Ok(StrsimMetric::SorensenDice),
+        _ => Err(SnapshotError::Corrupt("unknown strsim metric")),
+    } // match
+} // fn
@@ -0,0 +1,107 @@
+// -----------------------------------------------------------------------------
+//
+/// An error that occurred while writing or reading a `SearchIndex` snapshot.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// The snapshot could not be written or read.
+    Io(std::io::Error),
+
+    /// The data does not begin with the snapshot header. It's probably not a
+    /// `SearchIndex` snapshot.
+    InvalidMagic,
+
+    /// The snapshot was written in a format version that this version of
+    /// `indicium` can't read.
+    UnsupportedVersion {
+        /// The format version of the snapshot.
+        found: u32,
+        /// The format version that this version of `indicium` reads.
+        supported: u32,
+    },
+
+    /// The snapshot was written by a build with different feature-dependent
+    /// settings. For example, the snapshot was written with `strsim` fuzzy
+    /// matching, but this build uses `rapidfuzz`. Loading the snapshot would
+    /// silently change how the search index behaves.
+    FeatureMismatch {
+        /// The setting that doesn't match, i.e. `fuzzy matching`.
+        setting: &'static str,
+        /// The setting in the snapshot, i.e. `strsim`.
+        snapshot: &'static str,
+        /// The setting in this build, i.e. `rapidfuzz`.
+        build: &'static str,
+    },
+
+    /// The search index used a custom `Tokenizer`, which can't be saved in a
+    /// snapshot. Loading the snapshot without it would split search strings
+    /// differently than the records were split. The tokenizer must be given
+    /// to `read_snapshot_with`.
+    MissingTokenizer,
+
+    /// The search index used `TokenFilter`s, which can't be saved in a
+    /// snapshot. Loading the snapshot without them would filter search
+    /// strings differently than the records were filtered. The token filters
+    /// must be given to `read_snapshot_with`.
+    MissingTokenFilters,
+
+    /// A `Tokenizer` was given to `read_snapshot_with`, but the search index
+    /// didn't use one.
+    UnexpectedTokenizer,
+
+    /// `TokenFilter`s were given to `read_snapshot_with`, but the search index
+    /// didn't use any.
+    UnexpectedTokenFilters,
+
+    /// The search index used a fuzzy matching strategy with custom
+    /// `Similarity` metrics, which can't be saved in a snapshot.
+    CustomSimilarity,
+
+    /// The snapshot is truncated or damaged. The description explains which
+    /// part of the snapshot could not be read.
+    Corrupt(&'static str),
+} // SnapshotError
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "snapshot i/o error: {error}"),
+            Self::InvalidMagic => write!(f, "data is not a search index snapshot"),
+            Self::UnsupportedVersion { found, supported } => write!(
+                f,
+                "snapshot format version {found} is not supported (expected version {supported})"
+            ),
+            Self::FeatureMismatch { setting, snapshot, build } => write!(
+                f,
+                "snapshot was written with `{snapshot}` {setting} but this build uses `{build}`"
+            ),
+            Self::MissingTokenizer => write!(f, "snapshot needs the search index's custom tokenizer"),
+            Self::MissingTokenFilters => write!(f, "snapshot needs the search index's token filters"),
+            Self::UnexpectedTokenizer => write!(f, "snapshot was written without a custom tokenizer"),
+            Self::UnexpectedTokenFilters => write!(f, "snapshot was written without token filters"),
+            Self::CustomSimilarity => write!(f, "custom similarity metrics can't be saved in a snapshot"),
+            Self::Corrupt(description) => write!(f, "snapshot is corrupt: {description}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<std::io::Error> for SnapshotError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    } // fn
+} // impl
@@ -0,0 +1,106 @@
+// -----------------------------------------------------------------------------
+//
+/// Converts a `SearchIndex` key to and from bytes for snapshots. This is
+/// implemented for `String` and the integer types. Implement it for your own
+/// key type to use `write_snapshot` and `read_snapshot` with it.
+///
+/// Each key's bytes are stored with their length, so `read_key` receives
+/// exactly the bytes that `write_key` produced.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::SnapshotKey;
+/// #
+/// #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+/// enum CollectionKey {
+///     Vec(u32),
+///     HashMap(String),
+/// }
+///
+/// impl SnapshotKey for CollectionKey {
+///     fn write_key(&self, buffer: &mut Vec<u8>) {
+///         match self {
+///             Self::Vec(index) => {
+///                 buffer.push(0);
+///                 index.write_key(buffer);
+///             }
+///             Self::HashMap(key) => {
+///                 buffer.push(1);
+///                 key.write_key(buffer);
+///             }
+///         }
+///     }
+///
+///     fn read_key(bytes: &[u8]) -> Option<Self> {
+///         match bytes.split_first()? {
+///             (0, rest) => u32::read_key(rest).map(Self::Vec),
+///             (1, rest) => String::read_key(rest).map(Self::HashMap),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+pub trait SnapshotKey: Sized {
+    /// Appends the key's bytes to the buffer.
+    fn write_key(&self, buffer: &mut Vec<u8>);
+
+    /// Reconstructs a key from the bytes written by `write_key`. Returns
+    /// `None` if the bytes aren't a valid key.
+    fn read_key(bytes: &[u8]) -> Option<Self>;
+} // SnapshotKey
+
+// -----------------------------------------------------------------------------
+
+impl SnapshotKey for String {
+    fn write_key(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(self.as_bytes());
+    } // fn
+
+    fn read_key(bytes: &[u8]) -> Option<Self> {
+        std::str::from_utf8(bytes).ok().map(str::to_string)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Integers are stored as fixed-width, little-endian bytes.
+macro_rules! snapshot_key_integer {
+    ($($integer:ty),*) => {$(
+        impl SnapshotKey for $integer {
+            fn write_key(&self, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(&self.to_le_bytes());
+            } // fn
+
+            fn read_key(bytes: &[u8]) -> Option<Self> {
+                bytes.try_into().ok().map(Self::from_le_bytes)
+            } // fn
+        } // impl
+    )*};
+} // macro_rules!
+
+snapshot_key_integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+// -----------------------------------------------------------------------------
+//
+/// `usize` and `isize` are stored as 64-bit integers, so snapshots can be
+/// moved between 32-bit and 64-bit platforms.
+impl SnapshotKey for usize {
+    fn write_key(&self, buffer: &mut Vec<u8>) {
+        (*self as u64).write_key(buffer);
+    } // fn
+
+    fn read_key(bytes: &[u8]) -> Option<Self> {
+        u64::read_key(bytes).and_then(|key| Self::try_from(key).ok())
+    } // fn
+} // impl
+
+impl SnapshotKey for isize {
+    fn write_key(&self, buffer: &mut Vec<u8>) {
+        (*self as i64).write_key(buffer);
+    } // fn
+
+    fn read_key(bytes: &[u8]) -> Option<Self> {
+        i64::read_key(bytes).and_then(|key| Self::try_from(key).ok())
+    } // fn
+} // impl
@@ -0,0 +1,240 @@
+use crate::simple::{
+    snapshot::{
+        build_fuzzy_matching,
+        build_normalization,
+        encoder::Encoder,
+        SnapshotError,
+        SnapshotKey,
+        unsaved_settings,
+        FORMAT_VERSION,
+        MAGIC,
+    },
+    FuzzyMetric,
+    FuzzyStrategy,
+    SearchIndex,
+};
+use kstring::KString;
+use std::{collections::BTreeSet, io::Write};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord + SnapshotKey> SearchIndex<K> {
+    /// Writes the search index to a compact binary snapshot. The snapshot
+    /// holds the search index's settings, keywords, and keys, so it can be
+    /// loaded with `read_snapshot` instead of re-indexing every record.
+    ///
+    /// The writer is buffered internally.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SnapshotError::Io` if the writer fails.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Edgar Ætheling");
+    ///
+    /// let mut snapshot: Vec<u8> = Vec::new();
+    /// search_index.write_snapshot(&mut snapshot).unwrap();
+    ///
+    /// let loaded: SearchIndex<usize> = SearchIndex::read_snapshot(snapshot.as_slice()).unwrap();
+    /// assert_eq!(loaded, search_index);
+    /// assert_eq!(loaded.search("edgar"), vec![&1]);
+    /// ```
+    #[allow(clippy::too_many_lines)]
+    #[tracing::instrument(level = "trace", name = "write snapshot", skip(self, writer))]
+    pub fn write_snapshot(&self, writer: impl Write) -> Result<(), SnapshotError> {
+        // Gather every key in the search index for the key table:
+        let mut keys: BTreeSet<&K> = BTreeSet::new();
+        keys.extend(self.b_tree_map.values().flatten());
+        self.field_b_tree_maps
+            .values()
+            .for_each(|b_tree_map| keys.extend(b_tree_map.values().flatten()));
+        keys.extend(self.scoring_statistics.lengths.keys());
+        self.scoring_statistics.frequencies
+            .values()
+            .for_each(|frequencies| keys.extend(frequencies.keys()));
+        self.positional_index.keywords
+            .values()
+            .for_each(|positions| keys.extend(positions.keys()));
+        self.positional_index.fields
+            .values()
+            .flat_map(|keywords| keywords.values())
+            .for_each(|positions| keys.extend(positions.keys()));
+
+        let keys: Vec<&K> = keys.into_iter().collect();
+        let mut encoder = Encoder::new(writer, keys.clone());
+
+        // Header:
+        encoder.raw(MAGIC)?;
+        encoder.varint(u64::from(FORMAT_VERSION))?;
+        encoder.u8(unsaved_settings(self))?;
+
+        // Build features. Only the fuzzy matching crates that the search
+        // index's settings use are recorded. If fuzzy matching has been
+        // turned off, the snapshot can be loaded by any build:
+        encoder.u8(self.fuzzy_matching() & build_fuzzy_matching())?;
+        encoder.u8(build_normalization())?;
+
+        // Configuration:
+        encoder.u8(self.search_type.clone() as u8)?;
+        encoder.u8(self.autocomplete_type.clone() as u8)?;
+        encoder.u8(self.scoring_type.clone() as u8)?;
+        encoder.f64(self.bm25_k1)?;
+        encoder.f64(self.bm25_b)?;
+        encoder.bool(self.index_positions)?;
+        encoder.bool(self.infix_matching)?;
+        encoder.bool(self.pattern_matching)?;
+        encoder.usize(self.maximum_pattern_expansions)?;
+        encoder.option(self.eddie_metric.clone(), |encoder, metric| encoder.u8(metric as u8))?;
+        encoder.option(self.rapidfuzz_metric.clone(), |encoder, metric| encoder.u8(metric as u8))?;
+        encoder.option(self.strsim_metric.clone(), |encoder, metric| encoder.u8(metric as u8))?;
+        encoder.usize(self.fuzzy_length)?;
+        encoder.f64(self.fuzzy_minimum_score)?;
+        encoder.u8(self.fuzzy_candidates.clone() as u8)?;
+        encoder.usize(self.fuzzy_distance)?;
+        encoder.bool(self.fuzzy_fallback)?;
+        encoder.usize(self.maximum_fuzzy_expansions)?;
+        encoder.option(self.autocomplete_strategy.as_ref(), fuzzy_strategy)?;
+        encoder.option(self.search_strategy.as_ref(), fuzzy_strategy)?;
+        encoder.option(self.phonetic_matching.clone(), |encoder, algorithm| encoder.u8(algorithm as u8))?;
+        encoder.option(self.split_pattern.as_ref(), |encoder, split_pattern| {
+            encoder.usize(split_pattern.len())?;
+            split_pattern
+                .iter()
+                .try_for_each(|char| encoder.varint(u64::from(*char)))
+        })?;
+        encoder.bool(self.case_sensitive)?;
+        encoder.u8(self.normalization_form.clone() as u8)?;
+        encoder.u8(self.case_folding.clone() as u8)?;
+        encoder.bool(self.remove_combining_marks)?;
+        encoder.usize(self.minimum_keyword_length)?;
+        encoder.usize(self.maximum_keyword_length)?;
+        encoder.option(self.maximum_string_length, Encoder::usize)?;
+        encoder.option(self.exclude_keywords.as_ref(), |encoder, exclude_keywords| {
+            // The excluded keywords are sorted, so that the snapshot doesn't
+            // depend on the hash set's iteration order:
+            let mut exclude_keywords: Vec<&KString> = exclude_keywords.iter().collect();
+            exclude_keywords.sort_unstable();
+
+            encoder.usize(exclude_keywords.len())?;
+            exclude_keywords
+                .into_iter()
+                .try_for_each(|keyword| encoder.str(keyword))
+        })?;
+        encoder.usize(self.maximum_autocomplete_options)?;
+        encoder.usize(self.maximum_search_results)?;
+        encoder.usize(self.maximum_keys_per_keyword)?;
+        encoder.option(self.dump_keyword.as_ref(), |encoder, keyword| encoder.str(keyword))?;
+        encoder.option(self.field_weights.as_ref(), |encoder, field_weights| {
+            encoder.usize(field_weights.len())?;
+            field_weights.iter().try_for_each(|(field, weight)| {
+                encoder.str(field)?;
+                encoder.f64(*weight)
+            })
+        })?;
+        encoder.dictionary(&self.synonyms, |encoder, alternatives| {
+            encoder.usize(alternatives.len())?;
+            alternatives.iter().try_for_each(|keywords| {
+                encoder.usize(keywords.len())?;
+                keywords.iter().try_for_each(|keyword| encoder.str(keyword))
+            })
+        })?;
+
+        // Key table:
+        encoder.usize(keys.len())?;
+        keys.into_iter().try_for_each(|key| encoder.key(key))?;
+
+        // Keyword dictionary and posting lists:
+        encoder.dictionary(&self.b_tree_map, Encoder::postings)?;
+
+        // Named fields:
+        encoder.dictionary(&self.field_b_tree_maps, |encoder, b_tree_map| {
+            encoder.dictionary(b_tree_map, Encoder::postings)
+        })?;
+
+        // Scoring statistics. The total length is the sum of the record
+        // lengths, so it isn't stored:
+        encoder.key_map(&self.scoring_statistics.lengths, |encoder, length| {
+            encoder.usize(*length)
+        })?;
+        encoder.dictionary(&self.scoring_statistics.frequencies, |encoder, frequencies| {
+            encoder.key_map(frequencies, |encoder, frequency| encoder.usize(*frequency))
+        })?;
+        encoder.dictionary(&self.scoring_statistics.document_frequencies, |encoder, records| {
+            encoder.usize(*records)
+        })?;
+
+        // Keyword positions:
+        encoder.dictionary(&self.positional_index.keywords, |encoder, keys| {
+            encoder.key_map(keys, |encoder, positions| encoder.positions(positions))
+        })?;
+        encoder.dictionary(&self.positional_index.fields, |encoder, keywords| {
+            encoder.dictionary(keywords, |encoder, keys| {
+                encoder.key_map(keys, |encoder, positions| encoder.positions(positions))
+            })
+        })?;
+
+        encoder.finish()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the bits of the fuzzy matching crates that the search index's
+    /// metric and strategy settings use.
+    fn fuzzy_matching(&self) -> u8 {
+        let metrics: u8 = u8::from(self.eddie_metric.is_some())
+            | u8::from(self.rapidfuzz_metric.is_some()) << 1
+            | u8::from(self.strsim_metric.is_some()) << 2;
+
+        self.autocomplete_strategy
+            .iter()
+            .chain(self.search_strategy.iter())
+            .flat_map(|strategy| &strategy.metrics)
+            .fold(metrics, |bits, (metric, _weight)| bits | 1 << fuzzy_metric(metric).0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Writes a fuzzy matching strategy: the number of metrics, followed by each
+/// metric's crate identifier, metric, and weight. Custom metrics aren't
+/// saved.
+fn fuzzy_strategy<K: Ord + SnapshotKey, W: Write>(
+    encoder: &mut Encoder<'_, K, W>,
+    strategy: &FuzzyStrategy,
+) -> Result<(), SnapshotError> {
+    encoder.usize(strategy.metrics.len())?;
+
+    for (metric, weight) in &strategy.metrics {
+        let (crate_identifier, metric) = fuzzy_metric(metric);
+        encoder.u8(crate_identifier)?;
+        encoder.u8(metric)?;
+        encoder.f64(*weight)?;
+    } // for
+
+    Ok(())
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the identifier of a fuzzy metric's crate (see `FUZZY_MATCHING`) and
+/// the metric's discriminant.
+// The match is empty without fuzzy matching:
+#[allow(clippy::missing_const_for_fn, clippy::uninhabited_references)]
+fn fuzzy_metric(metric: &FuzzyMetric) -> (u8, u8) {
+    match *metric {
+        #[cfg(feature = "eddie")]
+        FuzzyMetric::Eddie(ref metric) => (0, metric.clone() as u8),
+        #[cfg(feature = "rapidfuzz")]
+        FuzzyMetric::Rapidfuzz(ref metric) => (1, metric.clone() as u8),
+        #[cfg(feature = "strsim")]
+        FuzzyMetric::Strsim(ref metric) => (2, metric.clone() as u8),
+    } // match
+} // fn
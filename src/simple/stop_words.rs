@@ -0,0 +1,127 @@
+//! Stop word sets for each `Language`. These are minor words - articles,
+//! short conjunctions, and short prepositions - that aren't useful as search
+//! keywords. The words are given in their normalized (lower case) form.
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords that are excluded by default. This is a short list of English,
+/// French, and Spanish articles, conjunctions, and prepositions. It's kept
+/// separate from the per-language sets, so that a `SearchIndex::default()`
+/// excludes the same keywords as it always has.
+pub const DEFAULT_STOP_WORDS: &[&str] = &[
+    // Some English:
+    "a", "an", "and", "as", "at", "but", "by", "for", "if", "in", "nor", "of",
+    "off", "on", "or", "per", "so", "the", "to", "up", "via", "yet",
+    // Some French:
+    "de", "en", "et", "la", "le", "les", "ni", "ou", "par", "pour", "si", "sur",
+    "un", "une", "à",
+    // Some Spanish:
+    "asi", "así", "aun", "aún", "del", "el", "las", "los", "o", "para", "por",
+    "que", "sí", "tan", "una", "uno", "vía", "y",
+];
+
+// -----------------------------------------------------------------------------
+
+pub const ARABIC: &[&str] = &[
+    "أن", "أو", "إلى", "التي", "الذي", "ثم", "على", "عن", "في", "مع", "من",
+    "هذا", "هذه", "و",
+];
+
+pub const DANISH: &[&str] = &[
+    "af", "at", "de", "den", "det", "eller", "en", "et", "for", "fra", "i",
+    "med", "men", "og", "om", "på", "til", "ved",
+];
+
+pub const DUTCH: &[&str] = &[
+    "aan", "bij", "de", "den", "der", "door", "een", "en", "het", "in", "maar",
+    "met", "naar", "of", "om", "op", "over", "te", "tot", "uit", "van", "voor",
+];
+
+pub const ENGLISH: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "from", "if", "in",
+    "into", "nor", "of", "off", "on", "onto", "or", "per", "so", "the", "to",
+    "up", "via", "with", "yet",
+];
+
+pub const FINNISH: &[&str] = &[
+    "ei", "että", "ja", "joka", "jos", "kuin", "mutta", "niin", "se", "sekä",
+    "tai", "vai",
+];
+
+pub const FRENCH: &[&str] = &[
+    "au", "aux", "avec", "car", "chez", "dans", "de", "des", "donc", "du", "en",
+    "entre", "et", "la", "le", "les", "mais", "ni", "ou", "par", "pour", "que",
+    "sans", "si", "sous", "sur", "un", "une", "vers", "à",
+];
+
+pub const GERMAN: &[&str] = &[
+    "aber", "am", "an", "ans", "auf", "aus", "bei", "bis", "das", "dass", "dem",
+    "den", "der", "des", "die", "ein", "eine", "einem", "einen", "einer",
+    "eines", "für", "im", "in", "ins", "mit", "nach", "oder", "sondern", "um",
+    "und", "vom", "von", "zu", "zum", "zur", "über",
+];
+
+pub const GREEK: &[&str] = &[
+    "ένα", "ένας", "ή", "αλλά", "από", "για", "η", "και", "με", "μια", "ο",
+    "οι", "σε", "στα", "στη", "στην", "στις", "στο", "στον", "στους", "τα",
+    "την", "της", "το", "τον", "του", "τους", "των", "ως",
+];
+
+pub const HUNGARIAN: &[&str] = &[
+    "a", "az", "de", "egy", "hogy", "is", "meg", "mint", "nem", "pedig", "vagy",
+    "és",
+];
+
+pub const ITALIAN: &[&str] = &[
+    "a", "agli", "ai", "al", "alla", "alle", "allo", "con", "da", "dagli",
+    "dai", "dal", "dalla", "dalle", "degli", "dei", "del", "della", "delle",
+    "dello", "di", "e", "ed", "fra", "gli", "i", "il", "in", "la", "le", "lo",
+    "ma", "negli", "nei", "nel", "nella", "nelle", "o", "per", "su", "sui",
+    "sul", "sulla", "sulle", "tra", "un", "una", "uno",
+];
+
+pub const NORWEGIAN: &[&str] = &[
+    "at", "av", "de", "den", "det", "eller", "en", "et", "for", "fra", "i",
+    "med", "men", "og", "om", "på", "til", "ved",
+];
+
+pub const PORTUGUESE: &[&str] = &[
+    "a", "ao", "aos", "as", "com", "da", "das", "de", "do", "dos", "e", "em",
+    "mas", "na", "nas", "nem", "no", "nos", "num", "numa", "o", "os", "ou",
+    "para", "pela", "pelas", "pelo", "pelos", "por", "sem", "sob", "um", "uma",
+    "à", "às",
+];
+
+pub const ROMANIAN: &[&str] = &[
+    "a", "al", "ale", "cu", "dar", "de", "din", "după", "fără", "iar", "la",
+    "lui", "o", "pe", "pentru", "prin", "sau", "spre", "un", "una", "unei",
+    "unui", "în", "și",
+];
+
+pub const RUSSIAN: &[&str] = &[
+    "а", "без", "в", "во", "для", "до", "же", "за", "и", "из", "или", "к", "ко",
+    "на", "над", "не", "но", "о", "об", "от", "по", "под", "при", "про", "с",
+    "со", "у", "через",
+];
+
+pub const SPANISH: &[&str] = &[
+    "a", "al", "ante", "asi", "así", "aun", "aún", "bajo", "con", "contra",
+    "de", "del", "desde", "el", "en", "entre", "hacia", "hasta", "la", "las",
+    "los", "ni", "o", "para", "pero", "por", "que", "según", "si", "sin",
+    "sino", "sobre", "sí", "tan", "tras", "un", "una", "unas", "uno", "unos",
+    "vía", "y",
+];
+
+pub const SWEDISH: &[&str] = &[
+    "av", "de", "den", "det", "eller", "en", "ett", "från", "för", "i", "med",
+    "men", "och", "om", "på", "som", "till", "ur", "vid", "åt",
+];
+
+pub const TAMIL: &[&str] = &[
+    "அந்த", "அல்லது", "இந்த", "ஒரு", "மற்றும்",
+];
+
+pub const TURKISH: &[&str] = &[
+    "ama", "bir", "bu", "da", "de", "gibi", "hem", "ile", "için", "ki", "ve",
+    "veya", "ya",
+];
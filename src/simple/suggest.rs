@@ -0,0 +1,183 @@
+use crate::simple::{
+    internal::{phonetics::PHONETIC_SIMILARITY, string_keywords::SplitContext},
+    SearchIndex,
+};
+use kstring::KString;
+use std::{cmp::Ordering, collections::BTreeSet, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// The number of partial corrections that are kept while each keyword of the
+/// search string is corrected. This keeps the number of combinations tried
+/// from growing exponentially with the number of keywords.
+const BEAM_WIDTH: usize = 16;
+
+// -----------------------------------------------------------------------------
+//
+/// A correction of the search string's first keywords.
+struct Correction<'s, K> {
+    /// The corrected keywords.
+    keywords: Vec<KString>,
+    /// The sum of the similarities of the corrected keywords.
+    similarity: f64,
+    /// The keys of the records that contain all of the corrected keywords.
+    keys: BTreeSet<&'s K>,
+} // Correction
+
+// -----------------------------------------------------------------------------
+
+impl<K> Correction<'_, K> {
+    /// Corrections whose keywords co-occur in a record come first, then the
+    /// most similar corrections.
+    fn rank(&self, other: &Self) -> Ordering {
+        self.keys.is_empty().cmp(&other.keys.is_empty())
+            .then_with(|| other.similarity.total_cmp(&self.similarity))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+    /// Returns spelling suggestions for a search string, for a "did you mean"
+    /// prompt when a search returns nothing. Each keyword that isn't in the
+    /// search index is replaced with a keyword that sounds like it (if
+    /// `phonetic_matching` is set) or with one of its closest fuzzy matches
+    /// (if a string similarity feature is enabled). Keywords that are in the
+    /// search index are kept as they are.
+    ///
+    /// Each suggestion is returned with its similarity to the search string,
+    /// from `0.0` to `1.0`. Suggestions whose keywords all occur together in at
+    /// least one record come first, so that searching for them will return
+    /// results. Otherwise, suggestions are in order of descending similarity.
+    ///
+    /// A keyword that can't be corrected is kept as it is, but it lowers the
+    /// suggestion's similarity as if nothing matched it. Nothing is returned
+    /// if every keyword is in the search index, or if no corrections are
+    /// found. Up to `maximum_autocomplete_options` suggestions are returned.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{PhoneticAlgorithm, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .phonetic_matching(Some(PhoneticAlgorithm::Metaphone))
+    ///     .build_from([(0, "Catherine Smith"), (1, "Kathryn Jones")]);
+    ///
+    /// let suggestions = search_index.suggest("catherine smyth");
+    /// assert_eq!(suggestions[0].0, "catherine smith");
+    /// ```
+    #[tracing::instrument(level = "trace", name = "suggest", skip(self))]
+    pub fn suggest(&self, string: &str) -> Vec<(String, f64)> {
+        let keywords: Vec<KString> = self.string_keywords(string, &SplitContext::Searching);
+
+        if keywords.iter().all(|keyword| self.b_tree_map.contains_key(keyword)) {
+            return Vec::new();
+        } // if
+
+        let mut corrections: Vec<Correction<K>> = vec![Correction {
+            keywords: Vec::new(),
+            similarity: 0.0,
+            keys: BTreeSet::new(),
+        }]; // vec!
+
+        for keyword in &keywords {
+            let mut candidates = self.suggestion_candidates(keyword);
+
+            // A keyword that can't be corrected is kept, with no similarity:
+            if candidates.is_empty() {
+                candidates.push((keyword.as_str(), 0.0));
+            } // if
+
+            corrections = corrections
+                .iter()
+                .flat_map(|correction| candidates.iter().map(move |(candidate, similarity)| {
+                    let mut keywords = correction.keywords.clone();
+                    keywords.push(KString::from_ref(candidate));
+                    let keys = self.co_occurring_keys(correction, candidate);
+                    Correction { keywords, similarity: correction.similarity + similarity, keys }
+                })) // flat_map
+                .collect();
+
+            corrections.sort_by(Correction::rank);
+            corrections.truncate(BEAM_WIDTH);
+        } // for
+
+        #[allow(clippy::cast_precision_loss)]
+        let suggestions: Vec<(String, f64)> = corrections
+            .into_iter()
+            // If no keyword could be corrected, there's nothing to suggest:
+            .filter(|correction| correction.keywords != keywords)
+            .take(self.maximum_autocomplete_options)
+            .map(|correction| (correction.keywords.join(" "), correction.similarity / keywords.len() as f64))
+            .collect();
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} suggestions for \"{}\".", suggestions.len(), string);
+
+        suggestions
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of the records that contain every keyword of the
+    /// correction, and the candidate. The correction's keys are narrowed down
+    /// by the candidate's keys, so once no record contains all of the
+    /// keywords, no more keys are looked up.
+    fn co_occurring_keys<'s>(&'s self, correction: &Correction<'s, K>, candidate: &str) -> BTreeSet<&'s K> {
+        let Some(candidate_keys) = self.b_tree_map.get(candidate) else {
+            return BTreeSet::new();
+        }; // let
+
+        if correction.keywords.is_empty() {
+            candidate_keys.iter().collect()
+        } else {
+            correction.keys
+                .iter()
+                .filter(|key| candidate_keys.contains(**key))
+                .copied()
+                .collect()
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that may replace a keyword of the search string,
+    /// and their similarity to it. A keyword that's in the search index is
+    /// only replaced with itself. Keywords that sound like it have a
+    /// similarity of `PHONETIC_SIMILARITY`.
+    ///
+    /// This method expects the keyword to be normalized already.
+    fn suggestion_candidates(&self, keyword: &KString) -> Vec<(&str, f64)> {
+        if let Some((keyword, _keys)) = self.b_tree_map.get_key_value(keyword) {
+            return vec![(keyword.as_str(), 1.0)];
+        } // if
+
+        // Keywords that sound like the keyword are scored with
+        // `PHONETIC_SIMILARITY`, so they rank below the keyword itself:
+        #[allow(unused_mut, reason = "compiler complains when fuzzy matching is off")]
+        let mut candidates: Vec<(&str, f64)> = self
+            .phonetic_keywords(&[], &BTreeSet::new(), keyword)
+            .into_iter()
+            .map(|(candidate, _keys)| (candidate.as_str(), PHONETIC_SIMILARITY))
+            .collect();
+
+        // Then the closest fuzzy matches that don't sound like the keyword.
+        // Whole strings that are indexed as keywords (see `max_string_len`)
+        // would replace one keyword with several, so they're skipped:
+        #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+        for (candidate, _keys, similarity) in
+            self.fuzzy_similarities(self.search_strategy.as_ref(), &[], &BTreeSet::new(), keyword)
+        {
+            if !candidate.contains(char::is_whitespace)
+                && !candidates.iter().any(|(phonetic, _similarity)| *phonetic == candidate.as_str())
+            {
+                candidates.push((candidate.as_str(), similarity));
+            } // if
+        } // for
+
+        candidates
+    } // fn
+} // impl
@@ -0,0 +1,88 @@
+// -----------------------------------------------------------------------------
+//
+/// A list of synonyms for a search index. Synonyms are applied to search
+/// strings, so that a search for `tv` will also find records that say
+/// `television`. Records are indexed as-is.
+///
+/// Synonyms may be one-way or two-way, and may be made of several words (i.e.
+/// `nyc` and `new york city`.) Records that match the search string itself are
+/// returned before records that only match a synonym.
+///
+/// Synonyms are split into keywords and normalized with the search index's
+/// settings when the search index is built.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{SearchIndex, SearchIndexBuilder, Synonyms};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+///     .synonyms(Synonyms::new()
+///         .two_way(["tv", "television"])
+///         .one_way("nyc", ["new york city"]))
+///     .build();
+///
+/// search_index.insert(&0, &"Television repairs");
+/// search_index.insert(&1, &"Pizza in New York City");
+/// search_index.insert(&2, &"TV listings");
+///
+/// assert_eq!(search_index.search("tv"), vec![&2, &0]);
+/// assert_eq!(search_index.search("nyc pizza"), vec![&1]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Synonyms {
+    /// One-way `(phrase, synonym)` pairs. A two-way entry is stored as a pair
+    /// in each direction.
+    pub(crate) entries: Vec<(String, String)>,
+} // Synonyms
+
+// -----------------------------------------------------------------------------
+
+impl Synonyms {
+    /// Makes an empty list of synonyms.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { entries: Vec::new() }
+    } // fn
+
+    /// Adds a one-way entry. Searches for `phrase` will also return records
+    /// that contain any of the `synonyms`, but not the other way around.
+    #[must_use]
+    pub fn one_way<S: AsRef<str>>(
+        mut self,
+        phrase: impl AsRef<str>,
+        synonyms: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.entries.extend(
+            synonyms
+                .into_iter()
+                .map(|synonym| (phrase.as_ref().to_string(), synonym.as_ref().to_string()))
+        ); // extend
+        self
+    } // fn
+
+    /// Adds a two-way entry. Searches for any of the `phrases` will also
+    /// return records that contain the others.
+    #[must_use]
+    pub fn two_way<S: AsRef<str>>(mut self, phrases: impl IntoIterator<Item = S>) -> Self {
+        let phrases: Vec<S> = phrases.into_iter().collect();
+
+        for phrase in &phrases {
+            self = self.one_way(
+                phrase,
+                phrases
+                    .iter()
+                    .filter(|synonym| synonym.as_ref() != phrase.as_ref())
+            ); // one_way
+        } // for
+
+        self
+    } // fn
+
+    /// Returns `true` if there are no synonyms.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    } // fn
+} // impl
@@ -208,12 +208,14 @@ fn simple() {
     #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
     assert_eq!(similar_keyword, Some("william")); */
 
-    // Test internal global fuzzy autocompletion interface:
-    #[cfg(feature = "eddie")]
+    // Test internal global fuzzy autocompletion interface. If several string
+    // similarity crates are enabled, the first one that autocompletion would
+    // use is tested:
+    #[cfg(all(feature = "eddie", not(any(feature = "rapidfuzz", feature = "strsim"))))]
     let similar_autocompletions = search_index.eddie_global(&[], &"Normy".to_lowercase());
     #[cfg(feature = "rapidfuzz")]
     let similar_autocompletions = search_index.rapidfuzz_global(&[], &"Normy".to_lowercase());
-    #[cfg(feature = "strsim")]
+    #[cfg(all(feature = "strsim", not(feature = "rapidfuzz")))]
     let similar_autocompletions = search_index.strsim_global(&[], &"Normy".to_lowercase());
     #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
     let similar_autocompletions_vec: Vec<&KString> = similar_autocompletions
@@ -708,4 +710,2129 @@ fn unicode_normalization_case_sensitive() {
 
     let results = search_index.search_type(&SearchType::Keyword, "FILE");
     assert_eq!(results, vec![&0, &2]);
-}
\ No newline at end of file
+}
+#[test]
+fn fields() {
+    use crate::simple::{Indexable, SearchIndex, SearchType};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct MyStruct {
+        title: String,
+        body: String,
+    }
+
+    impl Indexable for MyStruct {
+        fn strings(&self) -> Vec<String> {
+            vec![self.title.clone(), self.body.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("title".to_string(), self.title.clone()),
+                ("body".to_string(), self.body.clone()),
+            ]
+        }
+    }
+
+    let my_vec = [
+        MyStruct {
+            title: "Silver Dawn".to_string(),
+            body: "A thread of light at 12:30.".to_string(),
+        },
+        MyStruct {
+            title: "Golden Thread".to_string(),
+            body: "Just before dawn.".to_string(),
+        },
+        MyStruct {
+            title: "Dawn Chorus".to_string(),
+            body: "Birds singing.".to_string(),
+        },
+    ];
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    my_vec
+        .iter()
+        .enumerate()
+        .for_each(|(index, element)| search_index.insert(&index, element));
+
+    // Unrestricted keywords match every field:
+    assert_eq!(search_index.search_type(&SearchType::And, "dawn"), vec![&0, &1, &2]);
+
+    // Field-restricted keywords only match their field:
+    assert_eq!(search_index.search_type(&SearchType::And, "title:dawn"), vec![&0, &2]);
+    assert_eq!(search_index.search_type(&SearchType::And, "body:dawn"), vec![&1]);
+    assert_eq!(search_index.search_type(&SearchType::Or, "title:thread"), vec![&1]);
+    assert_eq!(search_index.search_type(&SearchType::Keyword, "TITLE:Chorus"), vec![&2]);
+
+    // Field-restricted keywords can be combined with regular keywords:
+    assert_eq!(search_index.search_type(&SearchType::And, "title:dawn light"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "dawn title:sil"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "title:dawn bir"), vec![&2]);
+
+    // Unknown fields are not treated as fields:
+    assert_eq!(search_index.search_type(&SearchType::And, "12:30"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::And, "author:dawn"), Vec::<&usize>::new());
+
+    // Removing a record also removes it from the field postings:
+    search_index.remove(&2, &my_vec[2]);
+    assert_eq!(search_index.search_type(&SearchType::And, "title:dawn"), vec![&0]);
+
+    // Replacing a record updates the field postings:
+    search_index.replace(&0, &my_vec[0], &MyStruct {
+        title: "Silver Dusk".to_string(),
+        body: "Dawn is over.".to_string(),
+    });
+    assert_eq!(search_index.search_type(&SearchType::And, "title:dawn"), Vec::<&usize>::new());
+    assert_eq!(search_index.search_type(&SearchType::And, "body:dawn"), vec![&0, &1]);
+
+    // Records that only implement `strings` can share the same index, but
+    // can't be searched by field:
+    search_index.insert(&3, &"Dawn Patrol");
+    assert_eq!(search_index.search_type(&SearchType::And, "dawn"), vec![&0, &1, &3]);
+    assert_eq!(search_index.search_type(&SearchType::And, "title:patrol"), Vec::<&usize>::new());
+
+    // Once every named field is gone, the field maps are empty too:
+    search_index.remove(&0, &MyStruct {
+        title: "Silver Dusk".to_string(),
+        body: "Dawn is over.".to_string(),
+    });
+    search_index.remove(&1, &my_vec[1]);
+    assert!(search_index.field_b_tree_maps.is_empty());
+}
+
+#[test]
+fn field_weights() {
+    use crate::simple::{Indexable, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    struct Product {
+        title: String,
+        description: String,
+    }
+
+    impl Indexable for Product {
+        fn strings(&self) -> Vec<String> {
+            vec![self.title.clone(), self.description.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("title".to_string(), self.title.clone()),
+                ("description".to_string(), self.description.clone()),
+            ]
+        }
+    }
+
+    let products = [
+        Product {
+            title: "Garden Hose".to_string(),
+            description: "Pairs well with our lantern and torch.".to_string(),
+        },
+        Product {
+            title: "Camping Lantern".to_string(),
+            description: "A bright, rechargeable light.".to_string(),
+        },
+        Product {
+            title: "Storm Torch".to_string(),
+            description: "Waterproof lantern alternative.".to_string(),
+        },
+    ];
+
+    // Without field weights, `Or` ranks by hit count and `Live` returns keys
+    // in key order:
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    products
+        .iter()
+        .enumerate()
+        .for_each(|(index, product)| search_index.insert(&index, product));
+
+    assert_eq!(search_index.search_type(&SearchType::Or, "lantern torch"), vec![&0, &2, &1]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "lant"), vec![&0, &1, &2]);
+
+    // With field weights, title matches outrank description matches:
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .field_weights(Some(vec![("Title".to_string(), 3.0)]))
+        .build();
+
+    products
+        .iter()
+        .enumerate()
+        .for_each(|(index, product)| search_index.insert(&index, product));
+
+    assert_eq!(search_index.search_type(&SearchType::Or, "lantern"), vec![&1, &0, &2]);
+    assert_eq!(search_index.search_type(&SearchType::Or, "lantern torch"), vec![&2, &1, &0]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "lant"), vec![&1, &0, &2]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "waterproof lant"), vec![&2]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "tor"), vec![&2, &0]);
+
+    // The maximum number of search results is observed after ranking:
+    assert_eq!(search_index.search_with(&SearchType::Live, 1, "lant"), vec![&1]);
+
+    // Field names are normalized once, when the search index is built:
+    assert_eq!(search_index.field_weights.as_ref().and_then(|weights| weights.get("title")), Some(&3.0));
+    let search_index = SearchIndexBuilder::from(search_index).build();
+    assert_eq!(search_index.search_type(&SearchType::Or, "lantern"), vec![&1, &0, &2]);
+}
+
+#[test]
+fn scoring_type() {
+    use crate::simple::{ScoringType, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let documents = [
+        "rust rust rust programming language",
+        "rust programming language with a very long description about many other things",
+        "cooking recipes",
+        "garden tools and rust removal",
+    ];
+
+    for scoring_type in [ScoringType::Bm25, ScoringType::TfIdf] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .scoring_type(scoring_type.clone())
+            .max_string_len(None)
+            .build();
+
+        documents
+            .iter()
+            .enumerate()
+            .for_each(|(index, document)| search_index.insert(&index, document));
+
+        // Repeated keywords score higher:
+        let search_results = search_index.search_type(&SearchType::Or, "rust");
+        assert_eq!(search_results.len(), 3);
+        assert_eq!(search_results[0], &0, "{scoring_type:?}");
+
+        // Rare keywords score higher than common keywords:
+        assert_eq!(
+            search_index.search_type(&SearchType::Or, "rust removal"),
+            vec![&3, &0, &1],
+            "{scoring_type:?}",
+        );
+
+        // `And` and `Live` results are ranked too:
+        assert_eq!(
+            search_index.search_type(&SearchType::And, "rust programming"),
+            vec![&0, &1],
+            "{scoring_type:?}",
+        );
+
+        assert_eq!(search_index.search_type(&SearchType::Live, "ru")[0], &0, "{scoring_type:?}");
+
+        // Statistics are kept up to date when records are removed:
+        search_index.remove(&0, &documents[0]);
+        assert!(!search_index.scoring_statistics.lengths.contains_key(&0));
+        assert!(!search_index.scoring_statistics.frequencies.contains_key("rust"));
+        assert_eq!(search_index.scoring_statistics.document_frequencies["rust"], 2);
+
+        search_index.clear();
+        assert_eq!(search_index.scoring_statistics.total_length, 0);
+    }
+
+    // Keywords are still told apart by how common they are when their keys
+    // are capped by `max_keys_per_keyword`:
+    for scoring_type in [ScoringType::Bm25, ScoringType::TfIdf] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .scoring_type(scoring_type.clone())
+            .max_keys_per_keyword(2)
+            .build();
+
+        (0..10).for_each(|index| search_index.insert(&index, &"apple"));
+        (10..13).for_each(|index| search_index.insert(&index, &"pear"));
+        search_index.insert(&13, &"apple pear");
+
+        assert!(
+            search_index.keyword_score(None, "pear", &13) > search_index.keyword_score(None, "apple", &13),
+            "{scoring_type:?}",
+        );
+    }
+
+    // A key may be inserted more than once, with different values. Keywords
+    // that the record already has aren't counted twice, and its length is
+    // added to:
+    let builder = || SearchIndexBuilder::default().scoring_type(ScoringType::Bm25).max_string_len(None);
+    let mut search_index: SearchIndex<usize> = builder().build();
+    search_index.insert(&0, &"rust book");
+    search_index.insert(&0, &"rust guide");
+    let statistics = &search_index.scoring_statistics;
+    assert_eq!(statistics.document_frequencies["rust"], 1);
+    assert_eq!(statistics.frequencies["rust"][&0], 2);
+    assert_eq!(statistics.lengths[&0], 4);
+    assert_eq!(statistics.total_length, 4);
+
+    // `extend` gives the same statistics:
+    let extended: SearchIndex<usize> = builder().build_from([(0, "rust book"), (0, "rust guide")]);
+    assert_eq!(extended.scoring_statistics, search_index.scoring_statistics);
+
+    // Removing one value takes its keywords away:
+    search_index.remove(&0, &"rust book");
+    let statistics = &search_index.scoring_statistics;
+    assert!(!statistics.document_frequencies.contains_key("rust"));
+    assert_eq!(statistics.document_frequencies["guide"], 1);
+    assert_eq!(statistics.lengths[&0], 1);
+    assert_eq!(statistics.total_length, 1);
+
+    search_index.remove(&0, &"rust guide");
+    assert!(search_index.scoring_statistics.document_frequencies.is_empty());
+    assert!(search_index.scoring_statistics.lengths.is_empty());
+    assert_eq!(search_index.scoring_statistics.total_length, 0);
+
+    // BM25 penalizes long records, unless length normalization is disabled:
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .scoring_type(ScoringType::Bm25)
+        .build();
+
+    search_index.insert(&0, &"garden rake with a long handle made of hard wood");
+    search_index.insert(&1, &"garden hose");
+    search_index.insert(&2, &"kitchen sink");
+
+    assert_eq!(search_index.search_type(&SearchType::Or, "garden"), vec![&1, &0]);
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .scoring_type(ScoringType::Bm25)
+        .bm25_parameters(1.2, 0.0)
+        .build();
+
+    search_index.insert(&0, &"garden rake with a long handle made of hard wood");
+    search_index.insert(&1, &"garden hose");
+
+    assert_eq!(search_index.search_type(&SearchType::Or, "garden"), vec![&0, &1]);
+}
+
+#[test]
+fn search_scored() {
+    use crate::simple::{Indexable, MatchType, SearchIndex, SearchType};
+    use pretty_assertions::assert_eq;
+
+    struct Book {
+        title: String,
+        author: String,
+    }
+
+    impl Indexable for Book {
+        fn strings(&self) -> Vec<String> {
+            vec![self.title.clone(), self.author.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("title".to_string(), self.title.clone()),
+                ("author".to_string(), self.author.clone()),
+            ]
+        }
+    }
+
+    let books = [
+        Book { title: "Silver Dawn".to_string(), author: "Edgar Wells".to_string() },
+        Book { title: "Dawn Chorus".to_string(), author: "Silvia Hart".to_string() },
+        Book { title: "Harbour Lights".to_string(), author: "Edgar Dawn".to_string() },
+    ];
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    books
+        .iter()
+        .enumerate()
+        .for_each(|(index, book)| search_index.insert(&index, book));
+
+    // The scored results are the same as the regular search results:
+    for search_type in [SearchType::And, SearchType::Keyword, SearchType::Live, SearchType::Or] {
+        for string in ["dawn", "silver dawn", "dawn sil", "title:dawn edgar", "nothing"] {
+            let keys: Vec<&usize> = search_index
+                .search_scored_type(&search_type, string)
+                .into_iter()
+                .map(|(key, _score, _match_info)| key)
+                .collect();
+
+            assert_eq!(keys, search_index.search_type(&search_type, string));
+        }
+    }
+
+    // `Or` scores are the number of keyword hits:
+    let search_results = search_index.search_scored_type(&SearchType::Or, "silver dawn");
+    let scores: Vec<(&usize, f64)> = search_results
+        .iter()
+        .map(|(key, score, _match_info)| (*key, *score))
+        .collect();
+    assert_eq!(scores, vec![(&0, 2.0), (&1, 1.0), (&2, 1.0)]);
+
+    let (_key, _score, match_info) = &search_results[1];
+    assert_eq!(match_info.keywords.len(), 1);
+    assert_eq!(match_info.keywords[0].search_keyword, "dawn");
+    assert_eq!(match_info.keywords[0].match_type, MatchType::Exact);
+
+    // The last keyword of a `Live` search is matched by prefix:
+    let search_results = search_index.search_scored_type(&SearchType::Live, "dawn sil");
+    assert_eq!(search_results.len(), 2);
+
+    let (key, score, match_info) = &search_results[1];
+    assert_eq!(*key, &1);
+    assert!((score - 2.0).abs() < f64::EPSILON);
+    assert_eq!(match_info.keywords[1].search_keyword, "sil");
+    assert_eq!(match_info.keywords[1].index_keyword, "silvia");
+    assert_eq!(match_info.keywords[1].match_type, MatchType::Prefix);
+
+    // Field-restricted keywords report their field:
+    let search_results = search_index.search_scored_type(&SearchType::And, "title:dawn edgar");
+    let (key, _score, match_info) = &search_results[0];
+    assert_eq!(*key, &0);
+    assert_eq!(match_info.keywords[0].field, None);
+    assert_eq!(match_info.keywords[1].field.as_deref(), Some("title"));
+
+    // When the last keyword of a `Live` search isn't found, fuzzy matching
+    // substitutes it and reports the similarity score:
+    #[cfg(any(feature = "eddie", feature = "rapidfuzz", feature = "strsim"))]
+    {
+        let search_results = search_index.search_scored_type(&SearchType::Live, "harbuor");
+
+        let (_key, score, match_info) = search_results
+            .iter()
+            .find(|(key, _score, _match_info)| *key == &2)
+            .unwrap();
+        assert_eq!(match_info.keywords[0].index_keyword, "harbour");
+
+        let MatchType::Fuzzy(similarity) = match_info.keywords[0].match_type else {
+            panic!("expected a fuzzy match");
+        };
+
+        assert!(similarity > 0.0 && similarity < 1.0);
+        assert!((score - similarity).abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn query() {
+    use crate::simple::{Indexable, QueryNode, ScoringType, SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    struct Book {
+        title: String,
+        author: String,
+    }
+
+    impl Indexable for Book {
+        fn strings(&self) -> Vec<String> {
+            vec![self.title.clone(), self.author.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("title".to_string(), self.title.clone()),
+                ("author".to_string(), self.author.clone()),
+            ]
+        }
+    }
+
+    let books = [
+        Book { title: "Silver Dawn".to_string(), author: "Edgar Wells".to_string() },
+        Book { title: "Red Thread".to_string(), author: "Silvia Dawn".to_string() },
+        Book { title: "Blue Harbour".to_string(), author: "Edgar Silver".to_string() },
+        Book { title: "Red Dawn".to_string(), author: "Marian Blue".to_string() },
+    ];
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    books
+        .iter()
+        .enumerate()
+        .for_each(|(index, book)| search_index.insert(&index, book));
+
+    // Operators, phrases, prefixes and fields are parsed into a tree:
+    assert_eq!(
+        search_index.parse_query(r#"title:"Silver Dawn" OR -edgar silv* unknown:red"#),
+        Some(QueryNode::Or(vec![
+            QueryNode::Field(
+                "title".to_string(),
+                Box::new(QueryNode::Phrase(vec!["silver".to_string(), "dawn".to_string()])),
+            ),
+            QueryNode::And(vec![
+                QueryNode::Not(Box::new(QueryNode::Keyword("edgar".to_string()))),
+                QueryNode::Prefix("silv".to_string()),
+                // Unknown fields are treated as regular text:
+                QueryNode::Phrase(vec!["unknown".to_string(), "red".to_string()]),
+            ]),
+        ])),
+    );
+
+    // Empty queries and unbalanced input don't panic:
+    assert_eq!(search_index.parse_query(""), None);
+    assert_eq!(search_index.parse_query("( )"), None);
+    assert_eq!(
+        search_index.parse_query(r#"(dawn OR "red"#),
+        Some(QueryNode::Or(vec![
+            QueryNode::Keyword("dawn".to_string()),
+            QueryNode::Keyword("red".to_string()),
+        ])),
+    );
+
+    // Keywords next to each other must all match:
+    assert_eq!(search_index.search_query("dawn red"), vec![&1, &3]);
+    assert_eq!(search_index.search_query("dawn AND red"), vec![&1, &3]);
+
+    // `OR` binds more loosely than `AND`:
+    assert_eq!(search_index.search_query("harbour OR dawn red"), vec![&1, &2, &3]);
+    assert_eq!(search_index.search_query("(harbour OR dawn) edgar"), vec![&0, &2]);
+
+    // Exclusions:
+    assert_eq!(search_index.search_query("dawn -red"), vec![&0]);
+    assert_eq!(search_index.search_query("dawn NOT red"), vec![&0]);
+    assert_eq!(search_index.search_query("-dawn"), vec![&2]);
+    assert_eq!(search_index.search_query("-(dawn OR blue)"), Vec::<&usize>::new());
+
+    // Deeply nested groups and exclusions are flattened instead of
+    // overflowing the stack:
+    let nested = format!("{}dawn{}", "(".repeat(10_000), ")".repeat(10_000));
+    assert_eq!(search_index.search_query(&nested), vec![&0, &1, &3]);
+    assert_eq!(search_index.search_query(&"(".repeat(10_000)), Vec::<&usize>::new());
+    assert_eq!(search_index.search_query(&format!("{}dawn", "-".repeat(10_000))), vec![&0, &1, &3]);
+    let mut node = search_index.parse_query(&format!("{}dawn", "NOT ".repeat(10_001)));
+    let mut depth = 0;
+    while let Some(QueryNode::Not(inner)) = node {
+        node = Some(*inner);
+        depth += 1;
+    }
+    assert_eq!(depth, 64);
+    assert_eq!(node, Some(QueryNode::Keyword("dawn".to_string())));
+
+    // Prefixes:
+    assert_eq!(search_index.search_query("silv* -edgar"), vec![&1]);
+
+    // Fields:
+    assert_eq!(search_index.search_query("author:dawn"), vec![&1]);
+    assert_eq!(search_index.search_query("title:(red OR blue) -thread"), vec![&2, &3]);
+    assert_eq!(search_index.search_query("title:silv*"), vec![&0]);
+
+    // With relevance scoring, results are ranked by the keywords that they
+    // matched:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .scoring_type(ScoringType::Bm25)
+        .build();
+
+    books
+        .iter()
+        .enumerate()
+        .for_each(|(index, book)| search_index.insert(&index, book));
+
+    let search_results = search_index.search_query("edgar OR harbour");
+    assert_eq!(search_results, vec![&2, &0]);
+
+    // Without a dump keyword, exclusion-only queries are subtracted from every
+    // record, with or without relevance scoring:
+    for scoring_type in [ScoringType::HitCount, ScoringType::Bm25] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .dump_keyword(None)
+            .scoring_type(scoring_type)
+            .build();
+
+        books
+            .iter()
+            .enumerate()
+            .for_each(|(index, book)| search_index.insert(&index, book));
+
+        assert_eq!(search_index.search_query("-dawn"), vec![&2]);
+        assert_eq!(search_index.search_query("-thread"), vec![&0, &2, &3]);
+        search_index.remove(&2, &books[2]);
+        assert_eq!(search_index.search_query("-dawn"), Vec::<&usize>::new());
+        assert_eq!(search_index.search_query("-thread"), vec![&0, &3]);
+    }
+}
+
+#[test]
+fn positions() {
+    use crate::simple::{Indexable, QueryNode, SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    struct Place {
+        name: String,
+        notes: String,
+    }
+
+    impl Indexable for Place {
+        fn strings(&self) -> Vec<String> {
+            vec![self.name.clone(), self.notes.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("name".to_string(), self.name.clone()),
+                ("notes".to_string(), self.notes.clone()),
+            ]
+        }
+    }
+
+    let places = [
+        Place { name: "New York".to_string(), notes: "Large harbour city".to_string() },
+        Place { name: "York".to_string(), notes: "Walled city, newer quarter".to_string() },
+        Place { name: "Old York".to_string(), notes: "Nothing new here".to_string() },
+        Place { name: "Harbour Town".to_string(), notes: "Quiet market city near busy harbour".to_string() },
+    ];
+
+    // Without positions, a phrase matches its keywords anywhere:
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    places
+        .iter()
+        .enumerate()
+        .for_each(|(index, place)| search_index.insert(&index, place));
+
+    assert_eq!(search_index.search_query(r#""new york""#), vec![&0, &2]);
+    assert_eq!(search_index.search_query("harbour NEAR/1 city"), vec![&0, &3]);
+
+    // With positions, keyword order and distance are checked:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .index_positions(true)
+        .build();
+
+    places
+        .iter()
+        .enumerate()
+        .for_each(|(index, place)| search_index.insert(&index, place));
+
+    assert_eq!(search_index.search_query(r#""new york""#), vec![&0]);
+    assert_eq!(search_index.search_query(r#""york new""#), Vec::<&usize>::new());
+
+    // `NEAR/n` allows up to _n_ keywords between its operands, in either
+    // order. Excluded keywords aren't counted:
+    assert_eq!(search_index.search_query("harbour NEAR/0 city"), vec![&0]);
+    assert_eq!(search_index.search_query("city NEAR/0 harbour"), vec![&0]);
+    assert_eq!(search_index.search_query("harbour NEAR/1 city"), vec![&0]);
+    assert_eq!(search_index.search_query("city NEAR/2 harbour"), vec![&0, &3]);
+    assert_eq!(search_index.search_query("market NEAR/3 (town OR harbour)"), vec![&3]);
+    assert_eq!(search_index.search_query("-(city NEAR/2 harbour)"), vec![&1, &2]);
+
+    // A group spans from its first to its last operand. An operand that only
+    // contains exclusions has no positions, so it's combined using `AND`:
+    assert_eq!(search_index.search_query("(large harbour) NEAR/0 city"), vec![&0]);
+    assert_eq!(search_index.search_query("(quiet market) NEAR/0 city"), vec![&3]);
+    assert_eq!(search_index.search_query("(market city) NEAR/1 busy"), vec![&3]);
+    assert_eq!(search_index.search_query("(quiet busy) NEAR/5 market"), Vec::<&usize>::new());
+    assert_eq!(search_index.search_query("(city -walled) NEAR/0 harbour"), vec![&0]);
+    assert_eq!(
+        search_index.parse_query("(-walled) NEAR/1 city"),
+        Some(QueryNode::And(vec![
+            QueryNode::Not(Box::new(QueryNode::Keyword("walled".to_string()))),
+            QueryNode::Keyword("city".to_string()),
+        ])),
+    );
+    assert_eq!(search_index.search_query("(-walled) NEAR/1 city"), vec![&0, &3]);
+
+    // Phrases don't match across the strings of a record:
+    assert_eq!(search_index.search_query(r#""york nothing""#), Vec::<&usize>::new());
+    assert_eq!(search_index.search_query("york NEAR/5 nothing"), Vec::<&usize>::new());
+
+    // Phrases restricted to a field:
+    assert_eq!(search_index.search_query(r#"name:"harbour town""#), vec![&3]);
+    assert_eq!(search_index.search_query(r#"notes:"harbour town""#), Vec::<&usize>::new());
+    assert_eq!(search_index.search_query("name:harbour NEAR/2 city"), Vec::<&usize>::new());
+    assert_eq!(search_index.search_query("notes:harbour NEAR/2 city"), vec![&0, &3]);
+    assert_eq!(search_index.search_query("notes:(market NEAR/2 city)"), vec![&3]);
+
+    // Positions are kept consistent by `remove` and `replace`:
+    search_index.replace(
+        &0,
+        &places[0],
+        &Place { name: "York New".to_string(), notes: "Renamed".to_string() },
+    );
+
+    assert_eq!(search_index.search_query(r#""new york""#), Vec::<&usize>::new());
+    assert_eq!(search_index.search_query(r#""york new""#), vec![&0]);
+
+    search_index.remove(
+        &0,
+        &Place { name: "York New".to_string(), notes: "Renamed".to_string() },
+    );
+
+    assert_eq!(search_index.search_query(r#""york new""#), Vec::<&usize>::new());
+    assert!(search_index.positional_index.keywords.get("york").is_some_and(|keys| !keys.contains_key(&0)));
+    assert!(!search_index.positional_index.keywords.contains_key("renamed"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use crate::simple::{CharTokenizer, Indexable, LowercaseFilter, ScoringType, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    struct Book {
+        title: String,
+        author: String,
+    }
+
+    impl Indexable for Book {
+        fn strings(&self) -> Vec<String> {
+            vec![self.title.clone(), self.author.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("title".to_string(), self.title.clone()),
+                ("author".to_string(), self.author.clone()),
+            ]
+        }
+    }
+
+    let books = [
+        Book { title: "Silver Dawn".to_string(), author: "Edgar Wells".to_string() },
+        Book { title: "Red Thread".to_string(), author: "Silvia Dawn".to_string() },
+        Book { title: "Blue Harbour".to_string(), author: "Edgar Silver".to_string() },
+    ];
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::Or)
+        .scoring_type(ScoringType::Bm25)
+        .index_positions(true)
+        .field_weights(Some(vec![("title".to_string(), 2.0)]))
+        .build();
+
+    books
+        .iter()
+        .enumerate()
+        .for_each(|(index, book)| search_index.insert(&index, book));
+
+    let json = serde_json::to_string(&search_index).unwrap();
+    let deserialized: SearchIndex<usize> = serde_json::from_str(&json).unwrap();
+
+    // The postings and every setting survive the round trip:
+    assert_eq!(deserialized, search_index);
+    assert_eq!(deserialized.search("silver edgar"), search_index.search("silver edgar"));
+    assert_eq!(deserialized.search_query(r#""silver dawn""#), vec![&0]);
+    assert_eq!(
+        deserialized.autocomplete("silver da"),
+        search_index.autocomplete("silver da"),
+    );
+
+    // A custom tokenizer can't be serialized. Only its presence is, and the
+    // search index is refused rather than deserialized without it:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .tokenizer(CharTokenizer::new(vec!['/']))
+        .build_from([(0, "usr/local/bin")]);
+    let json = serde_json::to_string(&search_index).unwrap();
+    assert!(json.contains(r#""tokenizer":true"#));
+    let error = serde_json::from_str::<SearchIndex<usize>>(&json).unwrap_err();
+    assert!(error.to_string().contains("custom tokenizer"));
+
+    // The same goes for token filters:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .token_filter(LowercaseFilter)
+        .build_from([(0, "Harold Godwinson")]);
+    let json = serde_json::to_string(&search_index).unwrap();
+    assert!(json.contains(r#""token_filters":true"#));
+    let error = serde_json::from_str::<SearchIndex<usize>>(&json).unwrap_err();
+    assert!(error.to_string().contains("token filters"));
+}
+
+#[test]
+fn snapshot() {
+    use crate::simple::{
+        CharTokenizer,
+        Indexable,
+        LowercaseFilter,
+        ScoringType,
+        SearchIndex,
+        SearchIndexBuilder,
+        SnapshotError,
+        TokenFilter,
+        Tokenizer,
+    };
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    struct Book {
+        title: String,
+        author: String,
+    }
+
+    impl Indexable for Book {
+        fn strings(&self) -> Vec<String> {
+            vec![self.title.clone(), self.author.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("title".to_string(), self.title.clone()),
+                ("author".to_string(), self.author.clone()),
+            ]
+        }
+    }
+
+    let books = [
+        Book { title: "Silver Dawn".to_string(), author: "Edgar Wells".to_string() },
+        Book { title: "Red Thread".to_string(), author: "Silvia Dawn".to_string() },
+        Book { title: "Blue Harbour Dawn Dawn".to_string(), author: "Edgar Silver".to_string() },
+    ];
+
+    let mut search_index: SearchIndex<u64> = SearchIndexBuilder::default()
+        .scoring_type(ScoringType::Bm25)
+        .index_positions(true)
+        .field_weights(Some(vec![("title".to_string(), 2.0)]))
+        .dump_keyword(Some("everything".to_string()))
+        .build();
+
+    books
+        .iter()
+        .zip([10, 2000, 300_000])
+        .for_each(|(book, key)| search_index.insert(&key, book));
+
+    let mut snapshot: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut snapshot).unwrap();
+
+    // The postings, statistics, positions, and every setting survive the
+    // round trip:
+    let loaded: SearchIndex<u64> = SearchIndex::read_snapshot(snapshot.as_slice()).unwrap();
+    assert_eq!(loaded, search_index);
+    assert_eq!(loaded.search("dawn"), search_index.search("dawn"));
+    assert_eq!(loaded.search_query(r#""harbour dawn""#), vec![&300_000]);
+    assert_eq!(loaded.autocomplete("silver da"), search_index.autocomplete("silver da"));
+
+    // Writing is deterministic:
+    let mut again: Vec<u8> = Vec::new();
+    loaded.write_snapshot(&mut again).unwrap();
+    assert_eq!(again, snapshot);
+
+    // Truncated snapshots return an error instead of panicking:
+    for length in 0..snapshot.len() {
+        assert!(SearchIndex::<u64>::from_snapshot_bytes(&snapshot[..length]).is_err());
+    }
+
+    // The snapshot must be read with the same key type:
+    assert!(matches!(
+        SearchIndex::<u32>::from_snapshot_bytes(&snapshot),
+        Err(SnapshotError::Corrupt(_)),
+    ));
+
+    // The format version is validated:
+    let mut future = snapshot.clone();
+    future[8] = 99;
+    assert!(matches!(
+        SearchIndex::<u64>::from_snapshot_bytes(&future),
+        Err(SnapshotError::UnsupportedVersion { found: 99, supported: 2 }),
+    ));
+
+    // Build features are validated. Byte 10 has a bit for each fuzzy matching
+    // crate that the snapshot uses, and byte 11 identifies the Unicode
+    // normalization crate:
+    let mut other_build = snapshot.clone();
+    other_build[11] = (other_build[11] + 1) % 3;
+    assert!(matches!(
+        SearchIndex::<u64>::from_snapshot_bytes(&other_build),
+        Err(SnapshotError::FeatureMismatch { setting: "unicode normalization", .. }),
+    ));
+
+    // A snapshot that uses a fuzzy matching crate that isn't in this build
+    // can't be read:
+    #[cfg(not(all(feature = "eddie", feature = "rapidfuzz", feature = "strsim")))]
+    {
+        let mut other_build = snapshot.clone();
+        other_build[10] = 0b111;
+        assert!(matches!(
+            SearchIndex::<u64>::from_snapshot_bytes(&other_build),
+            Err(SnapshotError::FeatureMismatch { setting: "fuzzy matching", .. }),
+        ));
+    }
+
+    // A snapshot with fuzzy matching turned off loads in any build:
+    let mut search_index: SearchIndex<String> = SearchIndex {
+        eddie_metric: None,
+        rapidfuzz_metric: None,
+        strsim_metric: None,
+        ..SearchIndex::default()
+    };
+    search_index.insert(&"harold".to_string(), &"Harold Godwinson");
+
+    let mut snapshot: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut snapshot).unwrap();
+    assert_eq!(snapshot[10], 0);
+    assert_eq!(SearchIndex::<String>::read_snapshot(snapshot.as_slice()).unwrap(), search_index);
+
+    // Snapshot files can be memory-mapped:
+    #[cfg(feature = "memmap2")]
+    {
+        let path = std::env::temp_dir().join(format!("indicium-{}.snapshot", std::process::id()));
+        std::fs::write(&path, &snapshot).unwrap();
+        let loaded = SearchIndex::<String>::read_snapshot_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), search_index);
+    }
+
+    // Settings that can't be saved are recorded in byte 9, and the snapshot
+    // is refused rather than loaded without them:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .tokenizer(CharTokenizer::new(vec![' ']))
+        .build_from([(0, "Harold Godwinson")]);
+    let mut snapshot: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut snapshot).unwrap();
+    assert!(matches!(
+        SearchIndex::<usize>::from_snapshot_bytes(&snapshot),
+        Err(SnapshotError::MissingTokenizer),
+    ));
+
+    // The tokenizer can be given again when the snapshot is loaded:
+    let tokenizer: Arc<dyn Tokenizer> = Arc::new(CharTokenizer::new(vec![' ']));
+    let loaded = SearchIndex::<usize>::from_snapshot_bytes_with(&snapshot, Some(tokenizer.clone()), Vec::new()).unwrap();
+    assert_eq!(loaded, search_index);
+    assert_eq!(loaded.search("godwinson"), vec![&0]);
+
+    // But not to a snapshot that didn't use one:
+    let mut snapshot: Vec<u8> = Vec::new();
+    SearchIndex::<usize>::default().write_snapshot(&mut snapshot).unwrap();
+    assert!(matches!(
+        SearchIndex::<usize>::from_snapshot_bytes_with(&snapshot, Some(tokenizer), Vec::new()),
+        Err(SnapshotError::UnexpectedTokenizer),
+    ));
+
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .token_filter(LowercaseFilter)
+        .build_from([(0, "Harold Godwinson")]);
+    let mut snapshot: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut snapshot).unwrap();
+    assert!(matches!(
+        SearchIndex::<usize>::from_snapshot_bytes(&snapshot),
+        Err(SnapshotError::MissingTokenFilters),
+    ));
+
+    // The token filters can be given again when the snapshot is loaded:
+    let token_filters: Vec<Arc<dyn TokenFilter>> = vec![Arc::new(LowercaseFilter)];
+    let loaded = SearchIndex::<usize>::from_snapshot_bytes_with(&snapshot, None, token_filters.clone()).unwrap();
+    assert_eq!(loaded, search_index);
+    assert_eq!(loaded.search("HAROLD"), vec![&0]);
+
+    // But not to a snapshot that didn't use any:
+    let mut snapshot: Vec<u8> = Vec::new();
+    SearchIndex::<usize>::default().write_snapshot(&mut snapshot).unwrap();
+    assert!(matches!(
+        SearchIndex::<usize>::from_snapshot_bytes_with(&snapshot, None, token_filters),
+        Err(SnapshotError::UnexpectedTokenFilters),
+    ));
+}
+
+#[test]
+fn concurrent() {
+    use crate::simple::{ConcurrentSearchIndex, SearchIndex};
+    use pretty_assertions::assert_eq;
+
+    let search_index: ConcurrentSearchIndex<usize> = SearchIndex::default().into();
+    search_index.insert(&0, &"Harold Godwinson");
+
+    // A version of the search index that's been read isn't changed by later
+    // writes:
+    let before = search_index.read();
+    search_index.insert(&1, &"Harold Harefoot");
+    assert_eq!(before.search("harold"), vec![&0]);
+    assert_eq!(search_index.search("harold"), vec![0, 1]);
+
+    // Writes are caught up on both copies, including the copy that was held
+    // by a reader during the last write:
+    search_index.replace(&0, &"Harold Godwinson", &"Edgar Ætheling");
+    drop(before);
+    search_index.remove(&1, &"Harold Harefoot");
+    assert_eq!(search_index.search("harold"), Vec::<usize>::new());
+    assert_eq!(search_index.search("edgar"), vec![0]);
+    search_index.insert(&2, &"William Rufus");
+    assert_eq!(search_index.search("edgar"), vec![0]);
+    assert_eq!(search_index.search("william"), vec![2]);
+
+    // Readers on other threads always see a whole record, never a partial
+    // replacement:
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            for generation in 0..100 {
+                search_index.replace(
+                    &0,
+                    &format!("Edgar Ætheling {generation}"),
+                    &format!("Edgar Ætheling {}", generation + 1),
+                );
+            }
+        });
+
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    assert_eq!(search_index.search("edgar"), vec![0]);
+                    assert_eq!(search_index.read().search("william"), vec![&2]);
+                }
+            });
+        }
+    });
+
+    search_index.clear();
+    assert_eq!(search_index.search("william"), Vec::<usize>::new());
+    assert_eq!(search_index.autocomplete("wil"), Vec::<String>::new());
+}
+
+#[test]
+fn extend() {
+    use crate::simple::{Indexable, ScoringType, SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Clone)]
+    struct Book {
+        title: String,
+        notes: String,
+    }
+
+    impl Indexable for Book {
+        fn strings(&self) -> Vec<String> {
+            vec![self.title.clone(), self.notes.clone()]
+        }
+
+        fn fields(&self) -> Vec<(String, String)> {
+            vec![
+                ("title".to_string(), self.title.clone()),
+                ("notes".to_string(), self.notes.clone()),
+            ]
+        }
+    }
+
+    let books: Vec<(usize, Book)> = (0..50)
+        .map(|index| (index, Book {
+            title: format!("Volume {index} Chronicles"),
+            notes: if index % 2 == 0 { "Illustrated edition".to_string() } else { "Pocket edition".to_string() },
+        }))
+        .collect();
+
+    let builder = || SearchIndexBuilder::default()
+        .scoring_type(ScoringType::Bm25)
+        .index_positions(true)
+        .dump_keyword(Some("\0".to_string()))
+        .max_keys_per_keyword(10);
+
+    // Loading in bulk gives the same search index as inserting each record,
+    // including which keys are kept once a keyword's limit is reached:
+    let mut inserted: SearchIndex<usize> = builder().build();
+    for (key, book) in &books {
+        inserted.insert(key, book);
+    }
+
+    let bulk_loaded: SearchIndex<usize> = builder().build_from(books.clone());
+    assert_eq!(bulk_loaded, inserted);
+    assert_eq!(bulk_loaded.search("illustrated"), vec![&0, &2, &4, &6, &8, &10, &12, &14, &16, &18]);
+
+    // Extending an existing search index also gives the same result:
+    let atlas = Book { title: "Pocket Atlas".to_string(), notes: "Maps".to_string() };
+    let mut inserted: SearchIndex<usize> = builder().build();
+    inserted.insert(&99, &atlas);
+    for (key, book) in &books {
+        inserted.insert(key, book);
+    }
+    let mut extended: SearchIndex<usize> = builder().build();
+    extended.insert(&99, &atlas);
+    extended.extend(books.clone());
+    assert_eq!(extended, inserted);
+    assert!(extended.search("pocket").contains(&&99));
+
+    // Splitting the records in parallel gives the same result:
+    #[cfg(feature = "rayon")]
+    {
+        let mut par_extended: SearchIndex<usize> = builder().build();
+        par_extended.insert(&99, &atlas);
+        par_extended.par_extend(books.clone());
+        assert_eq!(par_extended, inserted);
+        assert_eq!(builder().build_from_par(books), bulk_loaded);
+    }
+
+    // Records can be collected into a search index with the default settings:
+    let collected: SearchIndex<usize> = ["Harold Godwinson", "Edgar Ætheling"]
+        .into_iter()
+        .enumerate()
+        .collect();
+    assert_eq!(collected.search("edgar"), vec![&1]);
+}
+
+#[test]
+fn tokenizer() {
+    use crate::simple::{CharTokenizer, IdentifierTokenizer, NgramTokenizer, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    // Identifiers can be found whole, or by their parts:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .tokenizer(IdentifierTokenizer)
+        .search_type(SearchType::And)
+        .build();
+
+    search_index.insert(&0, &"fn getHttpResponse(request)");
+    search_index.insert(&1, &"contact: jean-luc@example.com");
+    search_index.insert(&2, &"HttpClient");
+
+    assert_eq!(search_index.search("gethttpresponse"), vec![&0]);
+    assert_eq!(search_index.search("http"), vec![&0, &2]);
+    assert_eq!(search_index.search("response"), vec![&0]);
+    assert_eq!(search_index.search("jean-luc@example.com"), vec![&1]);
+    assert_eq!(search_index.search("luc"), vec![&1]);
+
+    // Search strings are split by the same tokenizer. Here, `HttpClient` is
+    // split into `httpclient`, `http` and `client`:
+    assert_eq!(search_index.search("HttpClient"), vec![&2]);
+
+    // A search index with a tokenizer is equal to its clone:
+    assert_eq!(search_index.clone(), search_index);
+
+    // N-grams match text that isn't separated by spaces:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .tokenizer(NgramTokenizer::new(2, 2))
+        .search_type(SearchType::And)
+        .build();
+
+    search_index.insert(&0, &"東京都庁");
+    search_index.insert(&1, &"京都府");
+
+    assert_eq!(search_index.search("京都"), vec![&0, &1]);
+    assert_eq!(search_index.search("東京都"), vec![&0]);
+    assert_eq!(search_index.search("京都府"), vec![&1]);
+
+    // The character tokenizer splits like a split pattern does, but before
+    // the string is normalized:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .tokenizer(CharTokenizer::new(vec!['|']))
+        .search_type(SearchType::And)
+        .build();
+
+    search_index.insert(&0, &"Harold Godwinson|Edgar Ætheling");
+
+    assert_eq!(search_index.search("edgar ætheling"), vec![&0]);
+    assert_eq!(search_index.search("godwinson"), Vec::<&usize>::new());
+
+    // Unicode word boundaries:
+    #[cfg(feature = "unicode-segmentation")]
+    {
+        use crate::simple::WordTokenizer;
+
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .tokenizer(WordTokenizer)
+            .search_type(SearchType::And)
+            .build();
+
+        search_index.insert(&0, &"Don't panic, it's version 3.14!");
+
+        assert_eq!(search_index.search("don't"), vec![&0]);
+        assert_eq!(search_index.search("3.14"), vec![&0]);
+        assert_eq!(search_index.search("panic"), vec![&0]);
+    }
+}
+
+#[test]
+fn token_filter() {
+    use crate::simple::{
+        internal::string_keywords::SplitContext,
+        AsciiFoldingFilter,
+        LowercaseFilter,
+        SearchIndex,
+        SearchIndexBuilder,
+        SearchType,
+        StopWordFilter,
+        TokenFilter,
+    };
+    use kstring::KString;
+    use pretty_assertions::assert_eq;
+    use std::borrow::Cow;
+
+    /// Reverses each keyword.
+    #[derive(Debug)]
+    struct Reverse;
+
+    impl TokenFilter for Reverse {
+        fn filter<'t>(&self, token: Cow<'t, str>) -> Option<Cow<'t, str>> {
+            Some(Cow::Owned(token.chars().rev().collect()))
+        }
+    }
+
+    // Filters apply to records and to search strings:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::And)
+        .token_filter(AsciiFoldingFilter)
+        .token_filter(StopWordFilter::new(["der", "die", "das"]))
+        .build();
+
+    search_index.insert(&0, &"Das Résumé von Straße");
+    search_index.insert(&1, &"Die Kathedrale von Łódź");
+
+    assert_eq!(search_index.search("resume"), vec![&0]);
+    assert_eq!(search_index.search("résumé"), vec![&0]);
+    assert_eq!(search_index.search("strasse"), vec![&0]);
+    assert_eq!(search_index.search("lodz"), vec![&1]);
+    assert_eq!(search_index.search("von"), vec![&0, &1]);
+
+    // Stop words aren't indexed, and are ignored in search strings:
+    assert_eq!(search_index.search("das"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("die kathedrale"), vec![&1]);
+
+    // Autocompletion and prefixes use the filtered keywords:
+    assert_eq!(search_index.autocomplete("das résumé v"), vec!["resume von".to_string()]);
+    assert_eq!(search_index.search_query("resu*"), vec![&0]);
+
+    // Filters run in order, after normalization. In a case sensitive search
+    // index, folding `Æ` gives `AE` which is then lowercased:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::And)
+        .case_sensitive(true)
+        .token_filter(AsciiFoldingFilter)
+        .token_filter(LowercaseFilter)
+        .build();
+
+    search_index.insert(&0, &"Edgar Ætheling");
+    assert_eq!(search_index.search("aetheling"), vec![&0]);
+    assert_eq!(search_index.clone(), search_index);
+
+    // Snowball stemming:
+    #[cfg(feature = "rust-stemmers")]
+    {
+        use crate::simple::{Language, StemmerFilter};
+
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .search_type(SearchType::And)
+            .token_filter(StemmerFilter::new(Language::English))
+            .build();
+
+        search_index.insert(&0, &"The king was running");
+        search_index.insert(&1, &"Two kings ran");
+
+        assert_eq!(search_index.search("kings"), vec![&0, &1]);
+        assert_eq!(search_index.search("runs"), vec![&0]);
+    }
+
+    // Filters run on each keyword, but not on the whole string when it's also
+    // used as a keyword:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .max_string_len(Some(40))
+        .dump_keyword(None)
+        .token_filter(Reverse)
+        .build();
+
+    search_index.insert(&0, &"silver dawn");
+    assert_eq!(
+        search_index.b_tree_map.keys().map(KString::as_str).collect::<Vec<&str>>(),
+        vec!["nwad", "revlis", "silver dawn"],
+    );
+
+    // The same is true for search strings that aren't split into keywords:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .split_pattern(None)
+        .token_filter(Reverse)
+        .build();
+    assert_eq!(
+        search_index.string_keywords("silver dawn", &SplitContext::Searching),
+        vec![KString::from_ref("silver dawn")],
+    );
+}
+
+#[test]
+fn synonyms() {
+    use crate::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder, SearchType, Synonyms};
+    use pretty_assertions::assert_eq;
+
+    let build = |search_type: SearchType| {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .search_type(search_type)
+            .synonyms(Synonyms::new()
+                .two_way(["tv", "television"])
+                .one_way("nyc", ["new york city"])
+                .one_way("ny", ["new york"]))
+            .build();
+
+        search_index.insert(&0, &"Television repairs");
+        search_index.insert(&1, &"Pizza in New York City");
+        search_index.insert(&2, &"TV listings");
+        search_index.insert(&3, &"New York bagels");
+        search_index.insert(&4, &"NYC television guide");
+        search_index
+    }; // build
+
+    // Exact hits are ranked above synonym hits, for every search type:
+    for search_type in [SearchType::And, SearchType::Keyword, SearchType::Live, SearchType::Or] {
+        let search_index = build(search_type.clone());
+        assert_eq!(search_index.search("tv"), vec![&2, &0, &4], "{search_type:?}");
+        assert_eq!(search_index.search("television"), vec![&0, &4, &2], "{search_type:?}");
+    } // for
+
+    // Multi-word synonyms:
+    let search_index = build(SearchType::And);
+    assert_eq!(search_index.search("nyc pizza"), vec![&1]);
+    assert_eq!(search_index.search("nyc"), vec![&4, &1]);
+    assert_eq!(search_index.search("nyc tv"), vec![&4]);
+
+    // One-way synonyms don't apply in reverse:
+    assert_eq!(search_index.search("new york city"), vec![&1]);
+
+    // Longest phrase wins, so `ny` isn't substituted inside `nyc`:
+    assert_eq!(search_index.search("ny bagels"), vec![&3]);
+
+    // Autocompletion:
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Context, "nyc p"),
+        vec!["new york city pizza".to_string(), "new york city pizza in new york city".to_string()],
+    );
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Global, "tv r"),
+        vec!["tv repairs".to_string(), "television repairs".to_string()],
+    );
+
+    // Synonyms are kept by `clone` and in snapshots:
+    assert_eq!(search_index.clone(), search_index);
+
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    let loaded: SearchIndex<usize> = SearchIndex::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(loaded, search_index);
+    assert_eq!(loaded.search("tv"), vec![&2, &0, &4]);
+
+    // The last keyword of a `Live` search may be partial, whether or not it
+    // has synonyms:
+    let search_index = build(SearchType::Live);
+    assert_eq!(search_index.search("television l"), vec![&2]);
+    assert_eq!(search_index.search("guide tv"), vec![&4]);
+    assert_eq!(search_index.search("nyc pi"), vec![&1]);
+
+    // For `Or` searches, records that only match through synonyms come last:
+    let search_index = build(SearchType::Or);
+    assert_eq!(search_index.search("nyc bagels"), vec![&3, &4, &1]);
+
+    // Every combination of synonyms is searched for at once, no matter how
+    // many phrases have synonyms:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::And)
+        .synonyms(Synonyms::new()
+            .one_way("red", ["rouge"])
+            .one_way("blue", ["bleu"])
+            .one_way("green", ["vert"])
+            .one_way("black", ["noir"])
+            .one_way("white", ["blanc"]))
+        .build();
+
+    search_index.insert(&0, &"rouge bleu vert noir blanc");
+    search_index.insert(&1, &"red blue green black white");
+    assert_eq!(search_index.search("red blue green black white"), vec![&1, &0]);
+}
+
+#[test]
+fn stop_words() {
+    use crate::simple::{Language, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let build = |builder: SearchIndexBuilder<usize>| {
+        let mut search_index: SearchIndex<usize> = builder.search_type(SearchType::And).build();
+        search_index.insert(&0, &"The dog and the cat");
+        search_index.insert(&1, &"Le chien et le chat");
+        search_index.insert(&2, &"Der Hund und die Katze");
+        search_index
+    }; // build
+
+    // The default is English, French, and Spanish stop words:
+    let search_index = build(SearchIndexBuilder::default());
+    assert_eq!(search_index.search("the"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("le"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("der"), vec![&2]);
+
+    // The default is a short list, rather than the union of the English,
+    // French, and Spanish sets:
+    let default_stop_words = SearchIndex::<usize>::default().exclude_keywords.unwrap();
+    assert_eq!(default_stop_words.len(), 55);
+    assert!(default_stop_words.contains("the"));
+    assert!(!default_stop_words.contains("with"));
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"Tea with milk");
+    assert_eq!(search_index.search("with"), vec![&0]);
+
+    // Each language's set has its most common articles and prepositions:
+    for (language, stop_words) in [
+        (Language::English, ["a", "the", "of", "with"].as_slice()),
+        (Language::French, ["la", "des", "du", "au", "avec"].as_slice()),
+        (Language::Spanish, ["de", "la", "en", "un", "a", "con"].as_slice()),
+    ] {
+        for stop_word in stop_words {
+            assert!(language.stop_words().contains(stop_word), "{stop_word}");
+        } // for
+    } // for
+
+    // Stop words are selected by language code:
+    let search_index = build(SearchIndexBuilder::default().stop_words(["en"]));
+    assert_eq!(search_index.search("the"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("le"), vec![&1]);
+
+    let search_index = build(SearchIndexBuilder::default().stop_words(["DE", "xx"]));
+    assert_eq!(search_index.search("der"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("und"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("the dog"), vec![&0]);
+
+    // If none of the language codes are supported, nothing is excluded:
+    let search_index = build(SearchIndexBuilder::default().stop_words(["xx"]));
+    assert_eq!(search_index.exclude_keywords, None);
+    assert_eq!(search_index.search("the"), vec![&0]);
+
+    // Every language has a code, and a set of stop words:
+    for language in [
+        Language::Arabic, Language::Danish, Language::Dutch, Language::English,
+        Language::Finnish, Language::French, Language::German, Language::Greek,
+        Language::Hungarian, Language::Italian, Language::Norwegian,
+        Language::Portuguese, Language::Romanian, Language::Russian,
+        Language::Spanish, Language::Swedish, Language::Tamil, Language::Turkish,
+    ] {
+        assert_eq!(Language::from_code(language.code()), Some(language));
+        assert!(!language.stop_words().is_empty());
+    } // for
+}
+
+#[test]
+fn normalization() {
+    use crate::simple::{CaseFolding, NormalizationForm, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let build = |builder: SearchIndexBuilder<usize>| {
+        let mut search_index: SearchIndex<usize> = builder
+            .search_type(SearchType::And)
+            .build();
+
+        search_index.insert(&0, &"Straße");
+        search_index.insert(&1, &"İstanbul DIŞ");
+        search_index.insert(&2, &"Café crème");
+        search_index
+    }; // build
+
+    // Lower case conversion is the default:
+    let search_index = build(SearchIndexBuilder::default());
+    assert_eq!(search_index.search("STRASSE"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("straße"), vec![&0]);
+
+    // Full case folding:
+    let search_index = build(SearchIndexBuilder::default().case_folding(CaseFolding::Full));
+    assert_eq!(search_index.search("STRASSE"), vec![&0]);
+    assert_eq!(search_index.search("strasse"), vec![&0]);
+
+    // Turkish and Azerbaijani case folding:
+    let search_index = build(SearchIndexBuilder::default().case_folding(CaseFolding::Turkic));
+    assert_eq!(search_index.search("istanbul"), vec![&1]);
+    assert_eq!(search_index.search("dış"), vec![&1]);
+    assert_eq!(search_index.search("dis"), Vec::<&usize>::new());
+
+    // Case folding doesn't apply to case sensitive search indexes:
+    let search_index = build(SearchIndexBuilder::default()
+        .case_folding(CaseFolding::Full)
+        .case_sensitive(true));
+    assert_eq!(search_index.search("STRASSE"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("Straße"), vec![&0]);
+
+    // Combining marks and normalization forms require a Unicode normalization
+    // feature:
+    #[cfg(any(feature = "icu_normalizer", feature = "unicode-normalization"))]
+    {
+        let search_index = build(SearchIndexBuilder::default().remove_combining_marks(true));
+        assert_eq!(search_index.search("cafe creme"), vec![&2]);
+        assert_eq!(search_index.search("café"), vec![&2]);
+
+        // Precomposed and decomposed characters match in every form:
+        for normalization_form in [
+            NormalizationForm::Nfc, NormalizationForm::Nfd,
+            NormalizationForm::Nfkc, NormalizationForm::Nfkd,
+        ] {
+            let search_index = build(SearchIndexBuilder::default().normalization_form(normalization_form));
+            assert_eq!(search_index.search("cafe\u{0301}"), vec![&2]);
+            assert_eq!(search_index.search("cafe"), Vec::<&usize>::new());
+        } // for
+
+        // Excluded keywords are normalized too, so accented stop words are
+        // still excluded:
+        let build_german = |builder: SearchIndexBuilder<usize>| {
+            let mut search_index: SearchIndex<usize> = builder
+                .search_type(SearchType::And)
+                .stop_words(["de"])
+                .build();
+
+            search_index.insert(&0, &"Tee für Zwei");
+            search_index
+        }; // build_german
+
+        let search_index = build_german(SearchIndexBuilder::default().normalization_form(NormalizationForm::Nfd));
+        assert_eq!(search_index.search("für"), Vec::<&usize>::new());
+        assert_eq!(search_index.search("fu\u{0308}r"), Vec::<&usize>::new());
+        assert_eq!(search_index.search("tee"), vec![&0]);
+
+        let search_index = build_german(SearchIndexBuilder::default().remove_combining_marks(true));
+        assert_eq!(search_index.search("für"), Vec::<&usize>::new());
+        assert_eq!(search_index.search("fur"), Vec::<&usize>::new());
+        assert_eq!(search_index.search("tee"), vec![&0]);
+
+        let search_index = build_german(SearchIndexBuilder::default()
+            .normalization_form(NormalizationForm::Nfkd)
+            .remove_combining_marks(true));
+        assert_eq!(search_index.search("für"), Vec::<&usize>::new());
+        assert_eq!(search_index.search("zwei"), vec![&0]);
+    }
+
+    // Settings are kept by `clone` and in snapshots:
+    let search_index = build(SearchIndexBuilder::default()
+        .normalization_form(NormalizationForm::Nfd)
+        .case_folding(CaseFolding::Turkic)
+        .remove_combining_marks(true));
+    assert_eq!(search_index.clone(), search_index);
+
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    let loaded: SearchIndex<usize> = SearchIndex::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(loaded, search_index);
+    assert_eq!(loaded.search("ISTANBUL"), Vec::<&usize>::new());
+    assert_eq!(loaded.search("İSTANBUL"), vec![&1]);
+}
+
+#[test]
+fn cjk_segmentation() {
+    use crate::simple::{AutocompleteType, CjkTokenizer, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .tokenizer(CjkTokenizer)
+        .build();
+
+    search_index.insert(&0, &"東京都の天気予報");
+    search_index.insert(&1, &"京都の観光ガイド");
+    search_index.insert(&2, &"北京大学 Peking University");
+    search_index.insert(&3, &"ร้านอาหารไทย");
+
+    // Text without spaces is searchable from the middle, and mixed with other
+    // scripts:
+    assert_eq!(search_index.search_type(&SearchType::Live, "東京"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "京都"), vec![&0, &1]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "京都の観"), vec![&1]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "北京 univ"), vec![&2]);
+    assert_eq!(search_index.search_type(&SearchType::And, "天気"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::And, "アイスクリーム"), Vec::<&usize>::new());
+    assert_eq!(search_index.search_type(&SearchType::Live, "อาหาร"), vec![&3]);
+
+    // Autocompleted bigrams are merged back together:
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Context, "東"),
+        vec!["東京".to_string(), "東京都の天気予報".to_string()],
+    );
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Context, "京都の観"),
+        vec!["京都の観".to_string()],
+    );
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Global, "天気 北"),
+        vec!["天気 北京".to_string(), "天気 北京大学 peking university".to_string()],
+    );
+
+    // Dictionary segmentation indexes real words:
+    #[cfg(feature = "icu_segmenter")]
+    {
+        use crate::simple::DictionaryTokenizer;
+
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .tokenizer(DictionaryTokenizer)
+            .build();
+
+        search_index.insert(&0, &"我们在北京大学学习");
+        search_index.insert(&1, &"北京烤鸭");
+
+        assert_eq!(search_index.search_type(&SearchType::And, "北京"), vec![&0, &1]);
+        assert_eq!(search_index.search_type(&SearchType::Live, "学习"), vec![&0]);
+        assert_eq!(
+            search_index.autocomplete_type(&AutocompleteType::Context, "我们在北"),
+            vec!["我们在北京".to_string()],
+        );
+    }
+}
+
+#[test]
+fn infix_matching() {
+    use crate::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::Live)
+        .infix_matching(true)
+        .build();
+
+    search_index.insert(&0, &"Smartphone case");
+    search_index.insert(&1, &"Wireless headphones");
+    search_index.insert(&2, &"Phone charger");
+    search_index.insert(&3, &"Part AB4471X");
+
+    // Prefix matches, infix matches, and suffix matches:
+    assert_eq!(search_index.search("phone"), vec![&0, &1, &2]);
+    assert_eq!(search_index.search("4471"), vec![&3]);
+    assert_eq!(search_index.search("71x"), vec![&3]);
+    assert_eq!(search_index.search("wireless phone"), vec![&1]);
+    assert_eq!(search_index.search("less"), vec![&1]);
+
+    // Fragments shorter than a trigram would match most keywords, so they're
+    // only matched by prefix:
+    assert_eq!(search_index.search("ph"), vec![&2]);
+    assert_eq!(search_index.search("ne"), Vec::<&usize>::new());
+    assert!(search_index.trigram_index.keywords("ho").is_empty());
+    assert!(search_index.trigram_index.grams.keys().all(|gram| gram.chars().count() == 3));
+    assert_eq!(search_index.search("hon"), vec![&0, &1, &2]);
+
+    // Autocompletions that start with the keyword come first:
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Keyword, "phone"),
+        vec![
+            "phone".to_string(),
+            "phone charger".to_string(),
+            "headphones".to_string(),
+            "smartphone".to_string(),
+        ],
+    );
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Context, "wireless phone"),
+        vec!["wireless headphones".to_string()],
+    );
+    assert_eq!(
+        search_index.autocomplete_type(&AutocompleteType::Global, "part 447"),
+        vec!["part ab4471x".to_string()],
+    );
+
+    // Removed keywords are no longer matched:
+    search_index.remove(&3, &"Part AB4471X");
+    assert_eq!(search_index.search("4471"), Vec::<&usize>::new());
+
+    // The trigram index is kept by `extend`, `clone` and in snapshots:
+    search_index.extend([(4, "Cordless telephone")]);
+    assert_eq!(search_index.search("phone"), vec![&0, &1, &2, &4]);
+    assert_eq!(search_index.clone(), search_index);
+
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    let loaded: SearchIndex<usize> = SearchIndex::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(loaded, search_index);
+
+    // Infix matching is off by default, and can be turned on for a search
+    // index that's already populated:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::Live)
+        .build();
+    search_index.insert(&0, &"Smartphone case");
+    assert_eq!(search_index.search("phone"), Vec::<&usize>::new());
+
+    let search_index = SearchIndexBuilder::from(search_index).infix_matching(true).build();
+    assert_eq!(search_index.search("phone"), vec![&0]);
+}
+
+#[test]
+fn keyword_patterns() {
+    use crate::simple::{QueryNode, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::And)
+        .pattern_matching(true)
+        .build();
+
+    search_index.insert(&0, &"Red color");
+    search_index.insert(&1, &"Blue colour");
+    search_index.insert(&2, &"Red collar");
+    search_index.insert(&3, &"INV-2024-001");
+    search_index.insert(&4, &"INV-2024-002");
+    search_index.insert(&5, &"INV-2025-001");
+
+    // `?` matches one character, and `*` matches any number:
+    assert_eq!(search_index.search("colo?r"), vec![&1]);
+    assert_eq!(search_index.search("colo*r"), vec![&0, &1]);
+    assert_eq!(search_index.search("c*l*r"), vec![&0, &1, &2]);
+    assert_eq!(search_index.search("inv-2024-*"), vec![&3, &4]);
+    assert_eq!(search_index.search("INV-202?-001"), vec![&3, &5]);
+
+    // Patterns are intersected with keywords in `And` searches, and add to
+    // the hits in `Or` searches:
+    assert_eq!(search_index.search("red col*"), vec![&0, &2]);
+    assert_eq!(search_index.search("red colo?r"), Vec::<&usize>::new());
+    assert_eq!(
+        search_index.search_type(&SearchType::Or, "red colo?r"),
+        vec![&0, &1, &2],
+    );
+
+    // Patterns are ignored by `Live` searches, and when pattern matching is
+    // turned off:
+    assert_eq!(
+        search_index.search_type(&SearchType::Live, "colo?r"),
+        Vec::<&usize>::new(),
+    );
+    let search_index = SearchIndexBuilder::from(search_index)
+        .pattern_matching(false)
+        .build();
+    assert_eq!(search_index.search("colo?r"), Vec::<&usize>::new());
+
+    // A pattern may only expand to so many keywords:
+    let search_index = SearchIndexBuilder::from(search_index)
+        .pattern_matching(true)
+        .max_pattern_expansions(1)
+        .build();
+    assert_eq!(search_index.search("colo*r"), vec![&0]);
+
+    // The query language always recognizes patterns:
+    assert_eq!(
+        search_index.parse_query("Colo?r -red"),
+        Some(QueryNode::And(vec![
+            QueryNode::Wildcard("colo?r".to_string()),
+            QueryNode::Not(Box::new(QueryNode::Keyword("red".to_string()))),
+        ])),
+    );
+    assert_eq!(search_index.search_query("c*l*r -red"), Vec::<&usize>::new());
+
+    let search_index = SearchIndexBuilder::from(search_index)
+        .max_pattern_expansions(100)
+        .build();
+    assert_eq!(search_index.search_query("c*l*r -red"), vec![&1]);
+
+    #[cfg(feature = "regex")]
+    {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .pattern_matching(true)
+            .build();
+
+        search_index.insert(&0, &"AB123");
+        search_index.insert(&1, &"AB1234");
+        search_index.insert(&2, &"XAB123");
+
+        assert_eq!(search_index.search_type(&SearchType::And, r"/^ab\d{3}$/"), vec![&0]);
+        assert_eq!(search_index.search_type(&SearchType::Or, "/b12/"), vec![&0, &1, &2]);
+        assert_eq!(search_index.search_query(r"/^(ab|xab)\d+$/ -ab1234"), vec![&0, &2]);
+    }
+}
+
+// `eddie` 0.4 trips a debug assertion on its internal buffers, so this test
+// only runs with the other string similarity crates:
+#[cfg(any(feature = "rapidfuzz", feature = "strsim"))]
+#[test]
+fn deletion_candidates() {
+    use crate::simple::{
+        AutocompleteType, FuzzyCandidates, SearchIndex, SearchIndexBuilder, SearchType,
+    };
+    use pretty_assertions::assert_eq;
+
+    let records = [
+        (0, "Phone charger"),
+        (1, "Laptop charger"),
+        (2, "Silver thread"),
+        (3, "Wireless headphones"),
+    ];
+
+    // By default, a typo in the first characters is never found:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .search_type(SearchType::Live)
+        .autocomplete_type(AutocompleteType::Keyword)
+        .build_from(records);
+    assert_eq!(search_index.search("fhone"), Vec::<&usize>::new());
+
+    // With deletion candidates, typos anywhere in the keyword are found:
+    let mut search_index = SearchIndexBuilder::from(search_index)
+        .fuzzy_candidates(FuzzyCandidates::Deletions)
+        .build();
+    assert_eq!(search_index.search("fhone"), vec![&0]);
+    assert_eq!(search_index.search("charger xilver"), Vec::<&usize>::new());
+    assert_eq!(search_index.search("charger lpatop"), vec![&1]);
+    assert_eq!(search_index.search("slvr"), vec![&2]);
+    assert_eq!(search_index.autocomplete("tjread"), vec!["thread".to_string()]);
+
+    // Keywords further than the fuzzy distance aren't found:
+    assert_eq!(search_index.search("sxlxvxr"), Vec::<&usize>::new());
+    let search_index_1 = SearchIndexBuilder::from(search_index.clone())
+        .fuzzy_distance(1)
+        .build();
+    assert_eq!(search_index_1.search("slvr"), Vec::<&usize>::new());
+    assert_eq!(search_index_1.search("silvr"), vec![&2]);
+
+    // The deletion index is kept as records are inserted and removed, and in
+    // snapshots:
+    search_index.insert(&4, &"Bronze");
+    assert_eq!(search_index.search("brnoze"), vec![&4]);
+    search_index.remove(&4, &"Bronze");
+    assert_eq!(search_index.search("brnoze"), Vec::<&usize>::new());
+
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    let loaded: SearchIndex<usize> = SearchIndex::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(loaded, search_index);
+    assert_eq!(loaded.search("fhone"), vec![&0]);
+}
+
+#[cfg(any(feature = "rapidfuzz", feature = "strsim"))]
+#[test]
+fn fuzzy_fallback() {
+    use crate::simple::{ScoringType, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let records = [
+        (0, "Silver thread dawn"),
+        (1, "Silver thread dusk"),
+        (2, "Golden thread dawn"),
+    ];
+
+    // Without the fallback, a typo in any keyword empties `And` results:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default().build_from(records);
+    assert_eq!(search_index.search_type(&SearchType::And, "slver thread dawn"), Vec::<&usize>::new());
+
+    let search_index = SearchIndexBuilder::from(search_index)
+        .fuzzy_fallback(true)
+        .build();
+
+    // Every keyword may fall back to its fuzzy matches:
+    assert_eq!(search_index.search_type(&SearchType::And, "slver thread dawn"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::And, "slver thraed dwan"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "slver thread da"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "slver thraed du"), vec![&1]);
+    assert_eq!(search_index.search_type(&SearchType::Or, "goldn dawn"), vec![&2, &0]);
+
+    // Keywords with no fuzzy match still empty `And` results:
+    assert_eq!(search_index.search_type(&SearchType::And, "slver xyzzy"), Vec::<&usize>::new());
+
+    // The number of fuzzy matches used in one search is limited:
+    let search_index = SearchIndexBuilder::from(search_index)
+        .max_fuzzy_expansions(1)
+        .build();
+    assert_eq!(search_index.search_type(&SearchType::And, "slver thread dawn"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::And, "slver thraed dawn"), Vec::<&usize>::new());
+
+    // With relevance scoring, fuzzy matches are scored by their similarity, so
+    // closer matches rank first:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .scoring_type(ScoringType::Bm25)
+        .fuzzy_fallback(true)
+        .build_from([(0, "thread dawns"), (1, "thread dawn")]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "thread dawq"), vec![&1, &0]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "dawq thread"), vec![&1, &0]);
+    assert_eq!(search_index.search_type(&SearchType::And, "dawq thread"), vec![&1, &0]);
+    assert_eq!(search_index.search_type(&SearchType::Or, "dawq"), vec![&1, &0]);
+}
+
+#[cfg(feature = "rapidfuzz")]
+#[test]
+fn fuzzy_strategies() {
+    use crate::simple::{
+        AutocompleteType,
+        FuzzyStrategy,
+        RapidfuzzMetric,
+        SearchIndex,
+        SearchIndexBuilder,
+        SearchType,
+        Similarity,
+    };
+    use pretty_assertions::assert_eq;
+
+    let records = [
+        (0, "William Rufus"),
+        (1, "Harold Godwinson"),
+        (2, "Henry Beauclerc"),
+        (3, "Wessex"),
+    ];
+
+    // Without a strategy, the index's `rapidfuzz_metric` is used:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default().build_from(records);
+    assert_eq!(search_index.autocomplete_type(&AutocompleteType::Keyword, "hnery"), vec!["henry"]);
+
+    // A strategy may be given for a single call:
+    let jaro_winkler = FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler);
+    assert_eq!(
+        search_index.autocomplete_with_strategy(&AutocompleteType::Keyword, &jaro_winkler, "hnery"),
+        vec!["henry", "henry beauclerc", "harold", "harold godwinson"],
+    );
+
+    // Or for each operation:
+    let search_index = SearchIndexBuilder::from(search_index)
+        .autocomplete_strategy(Some(FuzzyStrategy::new(RapidfuzzMetric::Prefix)))
+        .search_strategy(Some(FuzzyStrategy::new(RapidfuzzMetric::Prefix)))
+        .build();
+    assert_eq!(search_index.autocomplete_type(&AutocompleteType::Keyword, "hnery"), Vec::<String>::new());
+    assert_eq!(search_index.search_type(&SearchType::Live, "wesz"), vec![&3]);
+
+    // The per-call strategy overrides the operation's strategy:
+    assert_eq!(
+        search_index.autocomplete_with_strategy(&AutocompleteType::Keyword, &jaro_winkler, "hnery"),
+        vec!["henry", "henry beauclerc", "harold", "harold godwinson"],
+    );
+
+    // Combined strategies use the weighted average of their metrics:
+    let combined = FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler)
+        .with(RapidfuzzMetric::Prefix, 1.0);
+    let expected = (RapidfuzzMetric::JaroWinkler.similarity("wiliam", "william")
+        + RapidfuzzMetric::Prefix.similarity("wiliam", "william")) / 2.0;
+    assert!((combined.similarity("wiliam", "william") - expected).abs() < f64::EPSILON);
+    let ignored = FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler)
+        .with(RapidfuzzMetric::Prefix, 0.0);
+    assert!((ignored.similarity("wiliam", "william") - jaro_winkler.similarity("wiliam", "william")).abs() < f64::EPSILON);
+
+    // The strategies are saved in snapshots:
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    let loaded: SearchIndex<usize> = SearchIndex::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(loaded, search_index);
+}
+
+#[cfg(feature = "rapidfuzz")]
+#[test]
+fn custom_similarity() {
+    use crate::simple::{
+        AutocompleteType,
+        FuzzyStrategy,
+        RapidfuzzMetric,
+        SearchIndex,
+        SearchIndexBuilder,
+        SearchType,
+        SnapshotError,
+        Similarity,
+    };
+    use pretty_assertions::assert_eq;
+
+    /// Keywords are the same if their consonants are the same.
+    #[derive(Debug)]
+    struct Consonants;
+
+    impl Similarity for Consonants {
+        fn similarity(&self, a: &str, b: &str) -> f64 {
+            let consonants = |string: &str| string
+                .chars()
+                .filter(|char| !"aeiou".contains(*char))
+                .collect::<String>();
+            if consonants(a) == consonants(b) { 1.0 } else { 0.0 }
+        }
+    }
+
+    let records = [
+        (0, "Aspirin"),
+        (1, "Atenolol"),
+        (2, "Amoxicillin"),
+    ];
+
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default().build_from(records);
+    assert_eq!(search_index.autocomplete_type(&AutocompleteType::Keyword, "aspirim"), vec!["aspirin"]);
+
+    // The custom similarity is used instead of the built-in metrics:
+    let consonants = FuzzyStrategy::custom(Consonants);
+    assert_eq!(
+        search_index.autocomplete_with_strategy(&AutocompleteType::Keyword, &consonants, "aspirim"),
+        Vec::<String>::new(),
+    );
+    assert_eq!(
+        search_index.autocomplete_with_strategy(&AutocompleteType::Keyword, &consonants, "aspurun"),
+        vec!["aspirin"],
+    );
+    assert_eq!(search_index.search_with_strategy(&SearchType::Live, &consonants, "atnll"), vec![&1]);
+
+    // And for substituting search keywords:
+    let search_index = SearchIndexBuilder::from(search_index)
+        .fuzzy_fallback(true)
+        .search_strategy(Some(FuzzyStrategy::custom(Consonants)))
+        .build();
+    assert_eq!(search_index.search_type(&SearchType::And, "aspurun"), vec![&0]);
+    assert_eq!(search_index.search_type(&SearchType::And, "aspirim"), Vec::<&usize>::new());
+
+    // Custom similarities may be combined with the built-in metrics:
+    let combined = FuzzyStrategy::new(RapidfuzzMetric::JaroWinkler)
+        .with_custom(Consonants, 3.0);
+    let expected = (RapidfuzzMetric::JaroWinkler.similarity("aspurun", "aspirin") + 3.0) / 4.0;
+    assert!((combined.similarity("aspurun", "aspirin") - expected).abs() < f64::EPSILON);
+
+    // Custom similarities can't be saved in snapshots, so the snapshot is
+    // refused:
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    assert!(matches!(
+        SearchIndex::<usize>::from_snapshot_bytes(&bytes),
+        Err(SnapshotError::CustomSimilarity),
+    ));
+
+    // Nor can they be serialized. A strategy that had them is refused, rather
+    // than deserialized as an empty strategy:
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&consonants).unwrap();
+        assert_eq!(json, r#"{"metrics":[],"custom":true}"#);
+        let error = serde_json::from_str::<FuzzyStrategy>(&json).unwrap_err();
+        assert!(error.to_string().contains("custom similarity metrics"));
+        assert!(serde_json::from_str::<SearchIndex<usize>>(&serde_json::to_string(&search_index).unwrap()).is_err());
+
+        let json = serde_json::to_string(&FuzzyStrategy::new(RapidfuzzMetric::Osa)).unwrap();
+        assert!(serde_json::from_str::<FuzzyStrategy>(&json).is_ok());
+    }
+}
+
+#[test]
+fn phonetic_matching() {
+    use crate::simple::{
+        internal::phonetics::codes,
+        AutocompleteType,
+        MatchType,
+        PhoneticAlgorithm,
+        SearchIndex,
+        SearchIndexBuilder,
+        SearchType,
+    };
+    use pretty_assertions::assert_eq;
+
+    // The algorithms' codes for some well-known names:
+    assert_eq!(codes("smith", &PhoneticAlgorithm::Soundex), vec!["S530"]);
+    assert_eq!(codes("smyth", &PhoneticAlgorithm::Soundex), vec!["S530"]);
+    assert_eq!(codes("pfister", &PhoneticAlgorithm::Soundex), vec!["P236"]);
+    assert_eq!(codes("ashcraft", &PhoneticAlgorithm::Soundex), vec!["A261"]);
+    assert_eq!(codes("catherine", &PhoneticAlgorithm::Metaphone), vec!["K0RN"]);
+    assert_eq!(codes("kathryn", &PhoneticAlgorithm::Metaphone), vec!["K0RN"]);
+    assert_eq!(codes("smith", &PhoneticAlgorithm::DoubleMetaphone), vec!["SM0", "XMT"]);
+    assert_eq!(codes("schmidt", &PhoneticAlgorithm::DoubleMetaphone), vec!["XMT", "SMT"]);
+    assert_eq!(codes("x100", &PhoneticAlgorithm::Metaphone), Vec::<String>::new());
+
+    let records = [
+        (0, "Catherine Smith"),
+        (1, "Margaret Schmidt"),
+        (2, "Henry Jones"),
+    ];
+
+    // Without phonetic matching, sound-alike keywords aren't found:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default().build_from(records);
+    assert_eq!(search_index.search_type(&SearchType::And, "kathryn"), Vec::<&usize>::new());
+
+    for algorithm in [PhoneticAlgorithm::Metaphone, PhoneticAlgorithm::DoubleMetaphone] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .phonetic_matching(Some(algorithm))
+            .build_from(records);
+
+        assert_eq!(search_index.search_type(&SearchType::And, "kathryn smyth"), vec![&0]);
+        // Sound-alike matches rank below exact matches:
+        assert_eq!(search_index.search_type(&SearchType::Or, "kathryn jones"), vec![&2, &0]);
+        assert_eq!(search_index.search_type(&SearchType::Live, "henry smyth"), Vec::<&usize>::new());
+        assert_eq!(
+            search_index.autocomplete_type(&AutocompleteType::Keyword, "kathryn"),
+            vec!["catherine"],
+        );
+        assert_eq!(
+            search_index.autocomplete_type(&AutocompleteType::Context, "henry smyth"),
+            Vec::<String>::new(),
+        );
+
+        // Scored searches report phonetic substitutes:
+        let scored = search_index.search_scored_with(&SearchType::Live, 10, "smyth");
+        assert_eq!(scored[0].0, &0);
+        assert_eq!(scored[0].2.keywords[0].index_keyword, "smith");
+        assert_eq!(scored[0].2.keywords[0].match_type, MatchType::Phonetic);
+
+        // The phonetic index follows inserted and removed records:
+        search_index.insert(&3, &"Kathryn Smythe");
+        assert_eq!(search_index.search_type(&SearchType::And, "catherine"), vec![&0]);
+        search_index.remove(&0, &"Catherine Smith");
+        assert_eq!(search_index.search_type(&SearchType::And, "catherine"), vec![&3]);
+    } // for
+
+    // Double Metaphone's alternate codes match more pronunciations. `Smith`
+    // may sound like `XMT`, as `Schmidt` does:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .phonetic_matching(Some(PhoneticAlgorithm::DoubleMetaphone))
+        .build_from(records);
+    assert_eq!(search_index.search_type(&SearchType::And, "schmitt"), vec![&0, &1]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "margaret smit"), vec![&1]);
+
+    // The setting is saved in snapshots, and the phonetic index is rebuilt:
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    let loaded: SearchIndex<usize> = SearchIndex::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(loaded, search_index);
+    assert_eq!(loaded.search_type(&SearchType::And, "schmitt"), vec![&0, &1]);
+}
+
+#[cfg(feature = "rapidfuzz")]
+#[test]
+fn keyboard_metric() {
+    use crate::simple::{
+        AutocompleteType,
+        RapidfuzzMetric,
+        SearchIndex,
+        SearchIndexBuilder,
+        SearchType,
+        Similarity,
+    };
+    use pretty_assertions::assert_eq;
+
+    // Neighbouring keys and swapped chars are closer than other typos:
+    let english = RapidfuzzMetric::KeyboardQwerty;
+    assert!((english.similarity("thread", "thread") - 1.0).abs() < f64::EPSILON);
+    assert!(english.similarity("thrwad", "thread") > english.similarity("thrxad", "thread"));
+    assert!(english.similarity("thraed", "thread") > english.similarity("thrzad", "thread"));
+
+    // The neighbouring keys depend on the layout:
+    let french = RapidfuzzMetric::KeyboardAzerty;
+    let german = RapidfuzzMetric::KeyboardQwertz;
+    assert!(french.similarity("lelon", "melon") > english.similarity("lelon", "melon"));
+    assert!(german.similarity("tebra", "zebra") > english.similarity("tebra", "zebra"));
+    assert!(english.similarity("yyre", "tyre") > german.similarity("yyre", "tyre"));
+
+    let records = [
+        (0, "Silver Cat"),
+        (1, "Golden Cut"),
+    ];
+
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .rapidfuzz_metric(Some(RapidfuzzMetric::KeyboardQwerty))
+        .build_from(records);
+    assert_eq!(search_index.autocomplete_type(&AutocompleteType::Keyword, "cst"), vec!["cat", "cut"]);
+    assert_eq!(search_index.autocomplete_type(&AutocompleteType::Keyword, "cit"), vec!["cut", "cat"]);
+    assert_eq!(search_index.search_type(&SearchType::Live, "silvrr"), vec![&0]);
+
+    // The metric is saved in snapshots:
+    let mut bytes: Vec<u8> = Vec::new();
+    search_index.write_snapshot(&mut bytes).unwrap();
+    let loaded: SearchIndex<usize> = SearchIndex::from_snapshot_bytes(&bytes).unwrap();
+    assert_eq!(loaded, search_index);
+}
+
+#[cfg(feature = "rapidfuzz")]
+#[test]
+fn suggest() {
+    use crate::simple::{
+        internal::phonetics::PHONETIC_SIMILARITY, PhoneticAlgorithm, SearchIndex, SearchIndexBuilder,
+        SearchType,
+    };
+    use pretty_assertions::assert_eq;
+
+    let records = [
+        (0, "Silver Thread at Dawn"),
+        (1, "Silver Bread"),
+        (2, "Golden Thread"),
+        (3, "Kathryn Dusk"),
+        (4, "Golden Brass"),
+    ];
+
+    // Every keyword is corrected:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default().build_from(records);
+    assert_eq!(search_index.search_type(&SearchType::And, "silvr thred dawm"), Vec::<&usize>::new());
+    let suggestions = search_index.suggest("silvr thred dawm");
+    assert_eq!(suggestions[0].0, "silver thread dawn");
+    assert!(suggestions[0].1 > 0.5 && suggestions[0].1 < 1.0);
+    assert_eq!(search_index.search_type(&SearchType::And, &suggestions[0].0), vec![&0]);
+
+    // Keywords that are in the search index are kept:
+    let suggestions = search_index.suggest("silver bred");
+    assert_eq!(suggestions[0].0, "silver bread");
+    assert_eq!(search_index.suggest("silver thread"), Vec::<(String, f64)>::new());
+
+    // Corrections that occur together in a record come first:
+    let suggestions = search_index.suggest("golden bred");
+    assert_eq!(suggestions[0].0, "golden brass");
+    assert_eq!(suggestions[1].0, "golden bread");
+    assert!(suggestions[0].1 < suggestions[1].1);
+
+    // A keyword with no corrections is kept, and lowers the similarity:
+    let suggestions = search_index.suggest("silver threadd qqqqqqqzzzz");
+    assert_eq!(suggestions[0].0, "silver thread qqqqqqqzzzz");
+    assert!(suggestions[0].1 < search_index.suggest("silver threadd")[0].1);
+    assert_eq!(search_index.suggest("qqqqqqqzzzz"), Vec::<(String, f64)>::new());
+
+    // Keywords only co-occur if one record contains all of them, not if the
+    // keywords are split across records:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default().build_from([
+        (0, "Silver"),
+        (1, "Thread dawn"),
+        (2, "Silvery thread dawn"),
+    ]);
+    let suggestions = search_index.suggest("silvr thread dawn");
+    assert_eq!(suggestions[0].0, "silvery thread dawn");
+    assert_eq!(suggestions[1].0, "silver thread dawn");
+
+    // Keywords that sound alike are corrected if `phonetic_matching` is set:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .phonetic_matching(Some(PhoneticAlgorithm::Metaphone))
+        .build_from(records);
+    let suggestions = search_index.suggest("catherine dusk");
+    assert_eq!(suggestions[0].0, "kathryn dusk");
+    assert!((suggestions[0].1 - (PHONETIC_SIMILARITY + 1.0) / 2.0).abs() < f64::EPSILON);
+}
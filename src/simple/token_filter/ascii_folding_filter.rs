@@ -0,0 +1,97 @@
+use crate::simple::TokenFilter;
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// The ASCII replacements for the Latin-1 Supplement and Latin Extended-A
+/// letters, `U+00C0` to `U+017F`. An empty string means the character isn't
+/// a letter, and is kept as-is.
+const LATIN: [&str; 192] = [
+    "A", "A", "A", "A", "A", "A", "AE", "C", // ÀÁÂÃÄÅÆÇ
+    "E", "E", "E", "E", "I", "I", "I", "I", // ÈÉÊËÌÍÎÏ
+    "D", "N", "O", "O", "O", "O", "O", "", // ÐÑÒÓÔÕÖ×
+    "O", "U", "U", "U", "U", "Y", "TH", "ss", // ØÙÚÛÜÝÞß
+    "a", "a", "a", "a", "a", "a", "ae", "c", // àáâãäåæç
+    "e", "e", "e", "e", "i", "i", "i", "i", // èéêëìíîï
+    "d", "n", "o", "o", "o", "o", "o", "", // ðñòóôõö÷
+    "o", "u", "u", "u", "u", "y", "th", "y", // øùúûüýþÿ
+    "A", "a", "A", "a", "A", "a", "C", "c", // ĀāĂăĄąĆć
+    "C", "c", "C", "c", "C", "c", "D", "d", // ĈĉĊċČčĎď
+    "D", "d", "E", "e", "E", "e", "E", "e", // ĐđĒēĔĕĖė
+    "E", "e", "E", "e", "G", "g", "G", "g", // ĘęĚěĜĝĞğ
+    "G", "g", "G", "g", "H", "h", "H", "h", // ĠġĢģĤĥĦħ
+    "I", "i", "I", "i", "I", "i", "I", "i", // ĨĩĪīĬĭĮį
+    "I", "i", "IJ", "ij", "J", "j", "K", "k", // İıĲĳĴĵĶķ
+    "k", "L", "l", "L", "l", "L", "l", "L", // ĸĹĺĻļĽľĿ
+    "l", "L", "l", "N", "n", "N", "n", "N", // ŀŁłŃńŅņŇ
+    "n", "n", "N", "n", "O", "o", "O", "o", // ňŉŊŋŌōŎŏ
+    "O", "o", "OE", "oe", "R", "r", "R", "r", // ŐőŒœŔŕŖŗ
+    "R", "r", "S", "s", "S", "s", "S", "s", // ŘřŚśŜŝŞş
+    "S", "s", "T", "t", "T", "t", "T", "t", // ŠšŢţŤťŦŧ
+    "U", "u", "U", "u", "U", "u", "U", "u", // ŨũŪūŬŭŮů
+    "U", "u", "U", "u", "W", "w", "Y", "y", // ŰűŲųŴŵŶŷ
+    "Y", "Z", "z", "Z", "z", "Z", "z", "s", // ŸŹźŻżŽžſ
+]; // LATIN
+
+// -----------------------------------------------------------------------------
+//
+/// Removes diacritics from Latin letters, and replaces ligatures and other
+/// special Latin letters with their closest ASCII equivalents. This allows
+/// `resume` to match `résumé`, and `strasse` to match `straße`.
+///
+/// The Latin-1 Supplement and Latin Extended-A letters are folded, as are
+/// combining diacritical marks (which are removed.) All other characters are
+/// kept as-is.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{AsciiFoldingFilter, TokenFilter};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// assert_eq!(AsciiFoldingFilter.filter("résumé".into()), Some("resume".into()));
+/// assert_eq!(AsciiFoldingFilter.filter("Ætheling".into()), Some("AEtheling".into()));
+/// assert_eq!(AsciiFoldingFilter.filter("Łódź".into()), Some("Lodz".into()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AsciiFoldingFilter;
+
+// -----------------------------------------------------------------------------
+
+impl TokenFilter for AsciiFoldingFilter {
+    fn filter<'t>(&self, token: Cow<'t, str>) -> Option<Cow<'t, str>> {
+        // Most tokens have nothing to fold. Avoid allocating for these:
+        if token.is_ascii() || !token.chars().any(|char| fold(char).is_some()) {
+            return Some(token);
+        } // if
+
+        let mut folded = String::with_capacity(token.len());
+
+        for char in token.chars() {
+            match fold(char) {
+                Some(replacement) => folded.push_str(replacement),
+                None => folded.push(char),
+            } // match
+        } // for
+
+        Some(Cow::Owned(folded))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the replacement for a character, if it should be folded.
+fn fold(char: char) -> Option<&'static str> {
+    match char {
+        // Combining diacritical marks are removed:
+        '\u{0300}'..='\u{036F}' => Some(""),
+
+        // Latin-1 Supplement and Latin Extended-A letters:
+        '\u{00C0}'..='\u{017F}' => {
+            let replacement = LATIN[char as usize - 0x00C0];
+            (!replacement.is_empty()).then_some(replacement)
+        }, // Latin
+
+        _ => None,
+    } // match
+} // fn
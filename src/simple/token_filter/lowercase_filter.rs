@@ -0,0 +1,34 @@
+use crate::simple::TokenFilter;
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Converts keywords to lower case.
+///
+/// Search indexes that aren't case sensitive already convert keywords to lower
+/// case, so this filter is only useful for case sensitive search indexes. For
+/// example, it can be placed after a filter that depends on case.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{LowercaseFilter, TokenFilter};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// assert_eq!(LowercaseFilter.filter("Ætheling".into()), Some("ætheling".into()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LowercaseFilter;
+
+// -----------------------------------------------------------------------------
+
+impl TokenFilter for LowercaseFilter {
+    fn filter<'t>(&self, token: Cow<'t, str>) -> Option<Cow<'t, str>> {
+        // Avoid allocating if the token is already in lower case:
+        if token.chars().any(char::is_uppercase) {
+            Some(Cow::Owned(token.to_lowercase()))
+        } else {
+            Some(token)
+        } // if
+    } // fn
+} // impl
@@ -0,0 +1,21 @@
+//! Token filters transform keywords (or tokens) after a string has been split
+//! and normalized. A chain of `TokenFilter`s can be given to the
+//! `SearchIndexBuilder`.
+
+mod ascii_folding_filter;
+mod lowercase_filter;
+mod stop_word_filter;
+mod token_filter_trait;
+
+#[cfg(feature = "rust-stemmers")]
+mod stemmer_filter;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::simple::token_filter::ascii_folding_filter::AsciiFoldingFilter;
+pub use crate::simple::token_filter::lowercase_filter::LowercaseFilter;
+pub use crate::simple::token_filter::stop_word_filter::StopWordFilter;
+pub use crate::simple::token_filter::token_filter_trait::TokenFilter;
+
+#[cfg(feature = "rust-stemmers")]
+pub use crate::simple::token_filter::stemmer_filter::StemmerFilter;
@@ -0,0 +1,89 @@
+use crate::simple::{Language, TokenFilter};
+use rust_stemmers::{Algorithm, Stemmer};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Reduces words to their stems, using the [Snowball](https://snowballstem.org/)
+/// stemmer for a language. This allows a search for `running` to match
+/// `runs`, since both are reduced to `run`. This feature relies on the
+/// [rust-stemmers](https://crates.io/crates/rust-stemmers) crate.
+///
+/// Keywords are stored in their stemmed form, so autocompletion options will
+/// also be stems. Stemmers expect lower case input, so this filter should be
+/// used with a search index that isn't case sensitive, or after a
+/// `LowercaseFilter`.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{Language, StemmerFilter, TokenFilter};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let stemmer = StemmerFilter::new(Language::English);
+///
+/// assert_eq!(stemmer.filter("running".into()), Some("run".into()));
+/// assert_eq!(stemmer.filter("kings".into()), Some("king".into()));
+/// ```
+pub struct StemmerFilter {
+    /// The language of the stemmer.
+    language: Language,
+
+    /// The Snowball stemmer for the language.
+    stemmer: Stemmer,
+} // StemmerFilter
+
+// -----------------------------------------------------------------------------
+
+impl StemmerFilter {
+    /// Makes a stemmer for the given language.
+    #[must_use]
+    pub fn new(language: Language) -> Self {
+        let algorithm = match language {
+            Language::Arabic => Algorithm::Arabic,
+            Language::Danish => Algorithm::Danish,
+            Language::Dutch => Algorithm::Dutch,
+            Language::English => Algorithm::English,
+            Language::Finnish => Algorithm::Finnish,
+            Language::French => Algorithm::French,
+            Language::German => Algorithm::German,
+            Language::Greek => Algorithm::Greek,
+            Language::Hungarian => Algorithm::Hungarian,
+            Language::Italian => Algorithm::Italian,
+            Language::Norwegian => Algorithm::Norwegian,
+            Language::Portuguese => Algorithm::Portuguese,
+            Language::Romanian => Algorithm::Romanian,
+            Language::Russian => Algorithm::Russian,
+            Language::Spanish => Algorithm::Spanish,
+            Language::Swedish => Algorithm::Swedish,
+            Language::Tamil => Algorithm::Tamil,
+            Language::Turkish => Algorithm::Turkish,
+        }; // match
+
+        Self { language, stemmer: Stemmer::create(algorithm) }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for StemmerFilter {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("StemmerFilter")
+            .field("language", &self.language)
+            .finish_non_exhaustive()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl TokenFilter for StemmerFilter {
+    fn filter<'t>(&self, token: Cow<'t, str>) -> Option<Cow<'t, str>> {
+        // The stem borrows from the token, so it must be copied if the token
+        // itself is owned:
+        match token {
+            Cow::Borrowed(token) => Some(self.stemmer.stem(token)),
+            Cow::Owned(token) => Some(Cow::Owned(self.stemmer.stem(&token).into_owned())),
+        } // match
+    } // fn
+} // impl
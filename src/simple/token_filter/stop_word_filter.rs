@@ -0,0 +1,87 @@
+// Conditionally select hash map type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashSet<T> = std::collections::HashSet<T, gxhash::GxBuildHasher>;
+
+#[cfg(feature = "ahash")]
+use ahash::HashSet;
+
+#[cfg(feature = "rustc-hash")]
+use rustc_hash::FxHashSet as HashSet;
+
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash"), not(feature = "rustc-hash")))]
+use std::collections::HashSet;
+
+// Static dependencies:
+use crate::simple::TokenFilter;
+use kstring::KString;
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Removes stop words: common words such as `the` or `and` that aren't useful
+/// for searching. Stop words are kept in a hashed set, so each keyword is
+/// checked in constant time.
+///
+/// The filter runs after keywords are normalized, so stop words should be
+/// given in lower case unless the search index is case sensitive.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{StopWordFilter, TokenFilter};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let stop_words = StopWordFilter::new(["der", "die", "das"]);
+///
+/// assert_eq!(stop_words.filter("das".into()), None);
+/// assert_eq!(stop_words.filter("haus".into()), Some("haus".into()));
+/// ```
+#[derive(Clone, Default)]
+pub struct StopWordFilter {
+    /// The words to remove.
+    stop_words: HashSet<KString>,
+} // StopWordFilter
+
+// -----------------------------------------------------------------------------
+
+impl StopWordFilter {
+    /// Makes a filter that removes the given words.
+    pub fn new<S: AsRef<str>>(stop_words: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            stop_words: stop_words
+                .into_iter()
+                .map(|stop_word| KString::from_ref(stop_word.as_ref()))
+                .collect(),
+        } // StopWordFilter
+    } // fn
+
+    /// Returns `true` if the keyword is a stop word.
+    #[must_use]
+    pub fn contains(&self, keyword: &str) -> bool {
+        self.stop_words.contains(keyword)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for StopWordFilter {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The stop words are sorted, so that the output doesn't depend on
+        // the hash set's iteration order:
+        let mut stop_words: Vec<&str> = self.stop_words.iter().map(KString::as_str).collect();
+        stop_words.sort_unstable();
+
+        formatter
+            .debug_struct("StopWordFilter")
+            .field("stop_words", &stop_words)
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl TokenFilter for StopWordFilter {
+    fn filter<'t>(&self, token: Cow<'t, str>) -> Option<Cow<'t, str>> {
+        (!self.contains(&token)).then_some(token)
+    } // fn
+} // impl
@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// A `TokenFilter` transforms or removes a keyword (or token). Token filters
+/// are chained together on the `SearchIndexBuilder`, and run in order on every
+/// keyword after the string has been split and normalized. They run when
+/// records are indexed and when search strings are split, so that both are
+/// treated the same way.
+///
+/// Keywords are filtered by length and by the exclusion list after the token
+/// filters have run.
+///
+/// The built-in token filters are:
+///
+/// * [`AsciiFoldingFilter`] · Removes diacritics from Latin letters
+///   (`résumé` → `resume`.)
+///
+/// * [`LowercaseFilter`] · Converts keywords to lower case. This is only
+///   useful for case sensitive search indexes.
+///
+/// * [`StemmerFilter`] · Reduces words to their stems using the Snowball
+///   stemmers (`running` → `run`.) This requires the `rust-stemmers`
+///   feature.
+///
+/// * [`StopWordFilter`] · Removes common words, using a hashed set.
+///
+/// [`AsciiFoldingFilter`]: crate::simple::AsciiFoldingFilter
+/// [`LowercaseFilter`]: crate::simple::LowercaseFilter
+/// [`StemmerFilter`]: https://docs.rs/indicium/latest/indicium/simple/struct.StemmerFilter.html
+/// [`StopWordFilter`]: crate::simple::StopWordFilter
+///
+/// Custom token filters can be made by implementing this trait:
+///
+/// ```rust
+/// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType, TokenFilter};
+/// # use pretty_assertions::assert_eq;
+/// # use std::borrow::Cow;
+/// #
+/// /// Removes keywords that are only digits.
+/// #[derive(Debug)]
+/// struct NoNumbers;
+///
+/// impl TokenFilter for NoNumbers {
+///     fn filter<'t>(&self, token: Cow<'t, str>) -> Option<Cow<'t, str>> {
+///         (!token.chars().all(|char| char.is_ascii_digit())).then_some(token)
+///     }
+/// }
+///
+/// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+///     .search_type(SearchType::Or)
+///     .token_filter(NoNumbers)
+///     .build();
+///
+/// search_index.insert(&0, &"Harold Godwinson 1066");
+///
+/// assert_eq!(search_index.search("harold"), vec![&0]);
+/// assert_eq!(search_index.search("1066"), Vec::<&usize>::new());
+/// ```
+pub trait TokenFilter: std::fmt::Debug + Send + Sync {
+    /// Returns the transformed token, or `None` if the token should be
+    /// removed.
+    fn filter<'t>(&self, token: Cow<'t, str>) -> Option<Cow<'t, str>>;
+} // TokenFilter
@@ -0,0 +1,49 @@
+use crate::simple::Tokenizer;
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Splits strings on a list of characters. This is how the `split_pattern`
+/// setting splits strings, as a `Tokenizer`.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{CharTokenizer, Tokenizer};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let tokenizer = CharTokenizer::new(vec![' ', '-']);
+///
+/// assert_eq!(
+///     tokenizer.tokenize("Jean-Luc Picard"),
+///     vec!["Jean", "Luc", "Picard"],
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharTokenizer {
+    /// Characters used to split strings into tokens.
+    split_pattern: Vec<char>,
+} // CharTokenizer
+
+// -----------------------------------------------------------------------------
+
+impl CharTokenizer {
+    /// Makes a tokenizer that splits strings on the given characters.
+    #[must_use]
+    pub const fn new(split_pattern: Vec<char>) -> Self {
+        Self { split_pattern }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Tokenizer for CharTokenizer {
+    fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>> {
+        string
+            .split(self.split_pattern.as_slice())
+            // Skip the empty strings between adjacent split characters:
+            .filter(|token| !token.is_empty())
+            .map(Cow::Borrowed)
+            .collect()
+    } // fn
+} // impl
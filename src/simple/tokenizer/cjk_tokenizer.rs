@@ -0,0 +1,191 @@
+use crate::simple::{internal::case_folding::is_combining_mark, Tokenizer};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Splits text in scripts that aren't written with spaces between words into
+/// overlapping character bigrams, and splits all other text into words. The
+/// scripts are Chinese, Japanese (Hiragana & Katakana), Thai, Lao, Khmer, and
+/// Myanmar.
+///
+/// Without this, a Japanese or Chinese title is indexed as one long keyword,
+/// which is usually longer than the maximum keyword length. With bigrams,
+/// `東京都` is indexed as `東京` and `京都`. Since search strings are split the
+/// same way, a search for `京都` will find it. The `And` or `Live` search
+/// types are recommended, so that records must contain every bigram.
+///
+/// Runs of one or two characters are kept whole. Words in other scripts are
+/// split on any character that isn't a letter or a number, and are kept whole.
+///
+/// For dictionary-based segmentation into real words, see
+/// [`DictionaryTokenizer`].
+///
+/// [`DictionaryTokenizer`]: https://docs.rs/indicium/latest/indicium/simple/struct.DictionaryTokenizer.html
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{CjkTokenizer, Tokenizer};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// assert_eq!(
+///     CjkTokenizer.tokenize("東京都の iPhoneケース"),
+///     vec!["東京", "京都", "都の", "iPhone", "ケー", "ース"],
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CjkTokenizer;
+
+// -----------------------------------------------------------------------------
+//
+/// The kind of run that a character belongs to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Run {
+    /// Characters in a script without spaces between words, which are split
+    /// into bigrams.
+    Bigrams,
+    /// Letters and numbers in other scripts, which are kept as whole words.
+    Word,
+    /// Whitespace and punctuation, which are dropped.
+    Separator,
+} // Run
+
+// -----------------------------------------------------------------------------
+
+impl Tokenizer for CjkTokenizer {
+    fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>> {
+        let mut tokens: Vec<Cow<'s, str>> = Vec::new();
+
+        // The run being read, and the byte offset of each of its chars:
+        let mut run = Run::Separator;
+        let mut offsets: Vec<usize> = Vec::new();
+
+        for (offset, char) in string.char_indices() {
+            // A combining mark belongs to the run of the character before it:
+            let next = if is_combining_mark(char) && run != Run::Separator {
+                run
+            } else {
+                run_of(char)
+            }; // if
+
+            if next != run {
+                push_run(&mut tokens, string, run, &offsets, offset);
+                offsets.clear();
+                run = next;
+            } // if
+
+            offsets.push(offset);
+        } // for
+
+        push_run(&mut tokens, string, run, &offsets, string.len());
+
+        tokens
+    } // fn
+
+    /// Joins tokens with a space, except that overlapping bigrams are merged
+    /// back together. For example, `東京` and `京都` are joined as `東京都`.
+    fn join(&self, tokens: &[&str]) -> String {
+        let mut joined = String::new();
+        let mut previous: Option<&str> = None;
+
+        for token in tokens {
+            match previous {
+                Some(previous) if overlaps(previous, token) => {
+                    // Skip the first character, which is already joined:
+                    joined.extend(token.chars().skip(1));
+                }, // Some
+                Some(_) => {
+                    joined.push(' ');
+                    joined.push_str(token);
+                }, // Some
+                None => joined.push_str(token),
+            } // match
+            previous = Some(token);
+        } // for
+
+        joined
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if a token starts with the last character of the bigram
+/// before it, and that character is in a script without spaces.
+fn overlaps(previous: &str, token: &str) -> bool {
+    let mut chars = previous.chars();
+
+    match (chars.next(), chars.next(), chars.next(), token.chars().next()) {
+        (Some(first), Some(last), None, Some(next)) =>
+            is_unspaced(first) && is_unspaced(last) && last == next,
+        _ => false,
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if a character belongs to a script that isn't written with
+/// spaces between words, such as Chinese, Japanese, or Thai.
+pub(super) fn is_unspaced(char: char) -> bool {
+    run_of(char) == Run::Bigrams
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Pushes the tokens for a run of characters. `offsets` are the byte offsets
+/// of the run's chars, and `end` is the byte offset where the run ends.
+fn push_run<'s>(
+    tokens: &mut Vec<Cow<'s, str>>,
+    string: &'s str,
+    run: Run,
+    offsets: &[usize],
+    end: usize,
+) {
+    let Some(start) = offsets.first() else {
+        return;
+    }; // let
+
+    match run {
+        // Runs of one or two characters are kept whole:
+        Run::Bigrams if offsets.len() > 2 => tokens.extend(
+            offsets
+                .windows(3)
+                .map(|window| Cow::Borrowed(&string[window[0]..window[2]]))
+                .chain(std::iter::once(Cow::Borrowed(&string[offsets[offsets.len() - 2]..end])))
+        ), // Bigrams
+        Run::Bigrams | Run::Word => tokens.push(Cow::Borrowed(&string[*start..end])),
+        Run::Separator => {},
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the kind of run that a character belongs to.
+fn run_of(char: char) -> Run {
+    match char {
+        // Punctuation and symbols within the ranges below:
+        '\u{0E2F}' | '\u{0E3F}' | '\u{0E4F}' | '\u{0E5A}' | '\u{0E5B}' // Thai
+        | '\u{0EAF}'                                                   // Lao
+        | '\u{104A}' | '\u{104B}'                                      // Myanmar
+        | '\u{17D4}'..='\u{17DA}'                                      // Khmer
+        | '\u{30FB}'                                                   // Katakana middle dot
+            => Run::Separator,
+
+        '\u{0E00}'..='\u{0E7F}'       // Thai
+        | '\u{0E80}'..='\u{0EFF}'     // Lao
+        | '\u{1000}'..='\u{109F}'     // Myanmar
+        | '\u{1780}'..='\u{17FF}'     // Khmer
+        | '\u{3005}'..='\u{3007}'     // Ideographic iteration & closing marks
+        | '\u{3040}'..='\u{30FF}'     // Hiragana & Katakana
+        | '\u{31F0}'..='\u{31FF}'     // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4DBF}'     // CJK unified ideographs extension A
+        | '\u{4E00}'..='\u{9FFF}'     // CJK unified ideographs
+        | '\u{F900}'..='\u{FAFF}'     // CJK compatibility ideographs
+        | '\u{FF66}'..='\u{FF9F}'     // Halfwidth Katakana
+        | '\u{20000}'..='\u{3134F}'   // CJK unified ideographs extensions B–G
+            => Run::Bigrams,
+
+        _ if char.is_alphanumeric() => Run::Word,
+
+        _ => Run::Separator,
+    } // match
+} // fn
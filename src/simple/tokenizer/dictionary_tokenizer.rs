@@ -0,0 +1,77 @@
+use crate::simple::{tokenizer::cjk_tokenizer::is_unspaced, Tokenizer};
+use icu_segmenter::{options::WordBreakInvariantOptions, WordSegmenter, WordSegmenterBorrowed};
+use std::{borrow::Cow, sync::LazyLock};
+
+// -----------------------------------------------------------------------------
+//
+/// The word segmenter, with compiled dictionaries for Chinese, Japanese, Thai,
+/// Lao, Khmer, and Myanmar. It's only loaded once.
+static SEGMENTER: LazyLock<WordSegmenterBorrowed<'static>> =
+    LazyLock::new(|| WordSegmenter::new_dictionary(WordBreakInvariantOptions::default()));
+
+// -----------------------------------------------------------------------------
+//
+/// Splits strings into words using dictionaries for scripts that aren't
+/// written with spaces between words, such as Chinese, Japanese, and Thai.
+/// Other scripts are split on Unicode word boundaries. Punctuation and
+/// whitespace are dropped. This feature relies on the
+/// [icu_segmenter](https://crates.io/crates/icu_segmenter) crate.
+///
+/// Unlike the bigrams of the [`CjkTokenizer`], this indexes real words, so
+/// autocompletion suggests whole words. This is at the cost of a larger binary
+/// (for the dictionaries) and slower indexing.
+///
+/// [`CjkTokenizer`]: crate::simple::CjkTokenizer
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{DictionaryTokenizer, Tokenizer};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// assert_eq!(
+///     DictionaryTokenizer.tokenize("我们在北京, hello world!"),
+///     vec!["我们", "在", "北京", "hello", "world"],
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DictionaryTokenizer;
+
+// -----------------------------------------------------------------------------
+
+impl Tokenizer for DictionaryTokenizer {
+    fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>> {
+        let mut tokens: Vec<Cow<'s, str>> = Vec::new();
+        let mut start = 0;
+
+        // Each boundary ends the segment that began at the previous boundary.
+        // Only word-like segments (letters and numbers) are kept:
+        for (end, word_type) in SEGMENTER.segment_str(string).iter_with_word_type() {
+            if end > start && word_type.is_word_like() {
+                tokens.push(Cow::Borrowed(&string[start..end]));
+            } // if
+            start = end;
+        } // for
+
+        tokens
+    } // fn
+
+    /// Joins tokens with a space, except between two words in a script that
+    /// isn't written with spaces. For example, `我们` and `在` are joined as
+    /// `我们在`.
+    fn join(&self, tokens: &[&str]) -> String {
+        let mut joined = String::new();
+
+        for token in tokens {
+            let unspaced = joined.chars().next_back().is_some_and(is_unspaced)
+                && token.chars().next().is_some_and(is_unspaced);
+
+            if !joined.is_empty() && !unspaced {
+                joined.push(' ');
+            } // if
+            joined.push_str(token);
+        } // for
+
+        joined
+    } // fn
+} // impl
@@ -0,0 +1,116 @@
+use crate::simple::Tokenizer;
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Splits code identifiers, e-mail addresses, URLs, and hyphenated words into
+/// their parts, while also keeping them whole. This allows `getHttpResponse`
+/// to be found by searching for `http`, and by searching for
+/// `getHttpResponse`.
+///
+/// Strings are first split on whitespace. Each word is kept whole (without
+/// any leading or trailing punctuation), then split on punctuation (such as
+/// `_`, `-`, `.`, `@` and `/`), and then split on changes in case:
+///
+/// * A lower-case letter followed by an upper-case letter starts a new part
+///   (`getHttp` is split into `get` and `Http`.)
+///
+/// * The last upper-case letter of a run of upper-case letters starts a new
+///   part if it's followed by a lower-case letter (`HTTPResponse` is split
+///   into `HTTP` and `Response`.)
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{IdentifierTokenizer, Tokenizer};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// assert_eq!(
+///     IdentifierTokenizer.tokenize("parse_HTTPResponse"),
+///     vec!["parse_HTTPResponse", "parse", "HTTPResponse", "HTTP", "Response"],
+/// );
+///
+/// assert_eq!(
+///     IdentifierTokenizer.tokenize("(jean-luc@example.com)"),
+///     vec!["jean-luc@example.com", "jean", "luc", "example", "com"],
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IdentifierTokenizer;
+
+// -----------------------------------------------------------------------------
+
+impl Tokenizer for IdentifierTokenizer {
+    fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>> {
+        let mut tokens: Vec<Cow<'s, str>> = Vec::new();
+
+        // Split the string into whitespace-separated words, without their
+        // leading or trailing punctuation:
+        let words = string
+            .split_whitespace()
+            .map(|word| word.trim_matches(|char: char| !char.is_alphanumeric()))
+            .filter(|word| !word.is_empty());
+
+        for word in words {
+            let segments: Vec<&str> = word
+                .split(|char: char| !char.is_alphanumeric())
+                .filter(|segment| !segment.is_empty())
+                .collect();
+
+            // Keep the word whole, if it will be split into parts:
+            if segments.len() > 1 || segments.first().is_some_and(|segment| has_case_boundary(segment)) {
+                tokens.push(Cow::Borrowed(word));
+            } // if
+
+            for segment in segments {
+                let parts = case_parts(segment);
+
+                // Keep the segment whole, if it will be split into parts:
+                if parts.len() > 1 && segment != word {
+                    tokens.push(Cow::Borrowed(segment));
+                } // if
+
+                tokens.extend(parts.into_iter().map(Cow::Borrowed));
+            } // for
+        } // for
+
+        tokens
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the segment would be split on a change in case.
+fn has_case_boundary(segment: &str) -> bool {
+    case_parts(segment).len() > 1
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Splits an alphanumeric segment on changes in case.
+fn case_parts(segment: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = segment.char_indices().collect();
+    let mut parts: Vec<&str> = Vec::new();
+    let mut start: usize = 0;
+
+    for index in 1..chars.len() {
+        let (offset, current) = chars[index];
+        let previous = chars[index - 1].1;
+        let next = chars.get(index + 1).map(|(_offset, char)| *char);
+
+        // `getHttp` → `get` and `Http`, `HTTPResponse` → `HTTP` and
+        // `Response`:
+        let boundary = (previous.is_lowercase() && current.is_uppercase())
+            || (previous.is_uppercase()
+                && current.is_uppercase()
+                && next.is_some_and(char::is_lowercase));
+
+        if boundary {
+            parts.push(&segment[start..offset]);
+            start = offset;
+        } // if
+    } // for
+
+    parts.push(&segment[start..]);
+    parts
+} // fn
@@ -0,0 +1,28 @@
+//! Tokenizers split strings into keywords. A `Tokenizer` can be given to the
+//! `SearchIndexBuilder` to replace the default `split_pattern`.
+
+mod char_tokenizer;
+mod cjk_tokenizer;
+mod identifier_tokenizer;
+mod ngram_tokenizer;
+mod tokenizer_trait;
+
+#[cfg(feature = "icu_segmenter")]
+mod dictionary_tokenizer;
+
+#[cfg(feature = "unicode-segmentation")]
+mod word_tokenizer;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::simple::tokenizer::char_tokenizer::CharTokenizer;
+pub use crate::simple::tokenizer::cjk_tokenizer::CjkTokenizer;
+pub use crate::simple::tokenizer::identifier_tokenizer::IdentifierTokenizer;
+pub use crate::simple::tokenizer::ngram_tokenizer::NgramTokenizer;
+pub use crate::simple::tokenizer::tokenizer_trait::Tokenizer;
+
+#[cfg(feature = "icu_segmenter")]
+pub use crate::simple::tokenizer::dictionary_tokenizer::DictionaryTokenizer;
+
+#[cfg(feature = "unicode-segmentation")]
+pub use crate::simple::tokenizer::word_tokenizer::WordTokenizer;
@@ -0,0 +1,99 @@
+use crate::simple::Tokenizer;
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Splits words into character _n_-grams, from `minimum` to `maximum`
+/// characters long. This is useful for text that isn't separated by spaces,
+/// such as Chinese or Japanese, and for matching the middle of words.
+///
+/// Strings are first split into words on any character that isn't a letter
+/// or a number. Words that are no longer than `minimum` characters are kept
+/// whole.
+///
+/// Since search strings are split the same way, a search for `東京都` will
+/// look for `東京` and `京都` with bigrams. The `And` search type is
+/// recommended, so that records must contain every _n_-gram.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{NgramTokenizer, Tokenizer};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// assert_eq!(
+///     NgramTokenizer::new(2, 3).tokenize("東京都 rust"),
+///     vec!["東京", "東京都", "京都", "ru", "rus", "us", "ust", "st"],
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NgramTokenizer {
+    /// The length of the shortest _n_-grams, in chars.
+    minimum: usize,
+
+    /// The length of the longest _n_-grams, in chars.
+    maximum: usize,
+} // NgramTokenizer
+
+// -----------------------------------------------------------------------------
+
+impl NgramTokenizer {
+    /// Makes a tokenizer that splits words into _n_-grams of `minimum` to
+    /// `maximum` chars (inclusive.) The `minimum` is at least `1`, and the
+    /// `maximum` is at least the `minimum`.
+    #[must_use]
+    pub fn new(minimum: usize, maximum: usize) -> Self {
+        let minimum = minimum.max(1);
+        Self { minimum, maximum: maximum.max(minimum) }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Default for NgramTokenizer {
+    /// Makes a bigram tokenizer.
+    fn default() -> Self {
+        Self::new(2, 2)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Tokenizer for NgramTokenizer {
+    fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>> {
+        let mut tokens: Vec<Cow<'s, str>> = Vec::new();
+
+        let words = string
+            .split(|char: char| !char.is_alphanumeric())
+            .filter(|word| !word.is_empty());
+
+        for word in words {
+            // The byte offset of each char, and of the end of the word:
+            let offsets: Vec<usize> = word
+                .char_indices()
+                .map(|(offset, _char)| offset)
+                .chain(std::iter::once(word.len()))
+                .collect();
+
+            let chars = offsets.len() - 1;
+
+            // Short words are kept whole:
+            if chars <= self.minimum {
+                tokens.push(Cow::Borrowed(word));
+                continue;
+            } // if
+
+            // Each _n_-gram, in order of where it starts in the word:
+            for start in 0..chars {
+                for length in self.minimum..=self.maximum {
+                    if start + length > chars {
+                        break;
+                    } // if
+                    tokens.push(Cow::Borrowed(&word[offsets[start]..offsets[start + length]]));
+                } // for
+            } // for
+        } // for
+
+        tokens
+    } // fn
+} // impl
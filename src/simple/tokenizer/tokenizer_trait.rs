@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// A `Tokenizer` splits a string into keywords (or tokens). It's used to split
+/// records when they're indexed, and to split search strings when searching,
+/// so that both are split the same way.
+///
+/// Tokenizers are given strings before they're normalized. This allows a
+/// tokenizer to make use of case (i.e. to split `camelCase` identifiers.) Each
+/// token is then normalized, and filtered by keyword length and the exclusion
+/// list, according to the search index's settings.
+///
+/// The built-in tokenizers are:
+///
+/// * [`CharTokenizer`] · Splits on a list of characters. This is how the
+///   `split_pattern` setting splits strings.
+///
+/// * [`WordTokenizer`] · Splits on Unicode word boundaries (UAX #29.) This
+///   requires the `unicode-segmentation` feature.
+///
+/// * [`IdentifierTokenizer`] · Splits code identifiers, e-mail addresses,
+///   URLs, and hyphenated words into their parts, and also keeps them whole.
+///
+/// * [`NgramTokenizer`] · Splits words into character _n_-grams. This is
+///   useful for matching the middle of words.
+///
+/// * [`CjkTokenizer`] · Splits Chinese, Japanese, Thai, and other text that
+///   isn't separated by spaces into bigrams, and other text into words.
+///
+/// * [`DictionaryTokenizer`] · Splits text into words using dictionaries for
+///   Chinese, Japanese, Thai, and other text that isn't separated by spaces.
+///   This requires the `icu_segmenter` feature.
+///
+/// [`CharTokenizer`]: crate::simple::CharTokenizer
+/// [`WordTokenizer`]: https://docs.rs/indicium/latest/indicium/simple/struct.WordTokenizer.html
+/// [`IdentifierTokenizer`]: crate::simple::IdentifierTokenizer
+/// [`NgramTokenizer`]: crate::simple::NgramTokenizer
+/// [`CjkTokenizer`]: crate::simple::CjkTokenizer
+/// [`DictionaryTokenizer`]: https://docs.rs/indicium/latest/indicium/simple/struct.DictionaryTokenizer.html
+///
+/// Custom tokenizers can be made by implementing this trait:
+///
+/// ```rust
+/// # use indicium::simple::{SearchIndex, SearchIndexBuilder, Tokenizer};
+/// # use pretty_assertions::assert_eq;
+/// # use std::borrow::Cow;
+/// #
+/// /// Splits strings on commas only.
+/// #[derive(Debug)]
+/// struct CommaTokenizer;
+///
+/// impl Tokenizer for CommaTokenizer {
+///     fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>> {
+///         string.split(',').map(str::trim).map(Cow::Borrowed).collect()
+///     }
+/// }
+///
+/// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+///     .tokenizer(CommaTokenizer)
+///     .build();
+///
+/// search_index.insert(&0, &"New York, Los Angeles");
+///
+/// assert_eq!(search_index.search("los angeles"), vec![&0]);
+/// assert_eq!(search_index.search("angeles"), Vec::<&usize>::new());
+/// ```
+pub trait Tokenizer: std::fmt::Debug + Send + Sync {
+    /// Splits a string into tokens, in the order they appear in the string.
+    /// Tokens may borrow from the string, or be newly allocated.
+    fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>>;
+
+    /// Joins tokens back into a string. This is used to build autocompleted
+    /// search strings from the search string's keywords and an autocompleted
+    /// keyword.
+    ///
+    /// By default, tokens are separated by a space.
+    fn join(&self, tokens: &[&str]) -> String {
+        tokens.join(" ")
+    } // fn
+} // Tokenizer
@@ -0,0 +1,37 @@
+use crate::simple::Tokenizer;
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+// -----------------------------------------------------------------------------
+//
+/// Splits strings on Unicode word boundaries, as defined by [Unicode Standard
+/// Annex #29](https://www.unicode.org/reports/tr29/). Punctuation and
+/// whitespace are dropped, while words such as `can't` and numbers such as
+/// `3.14` are kept whole. This feature relies on the
+/// [unicode-segmentation](https://crates.io/crates/unicode-segmentation)
+/// crate.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{Tokenizer, WordTokenizer};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// assert_eq!(
+///     WordTokenizer.tokenize("Don't panic, it's only 3.14!"),
+///     vec!["Don't", "panic", "it's", "only", "3.14"],
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct WordTokenizer;
+
+// -----------------------------------------------------------------------------
+
+impl Tokenizer for WordTokenizer {
+    fn tokenize<'s>(&self, string: &'s str) -> Vec<Cow<'s, str>> {
+        string
+            .unicode_words()
+            .map(Cow::Borrowed)
+            .collect()
+    } // fn
+} // impl